@@ -68,8 +68,14 @@ export interface ActionView {
   funcRunId?: FuncRunId;
 }
 
+export interface ActionRunFailure {
+  reason: string;
+  message?: string | null;
+}
+
 export interface ActionProposedView extends ActionView {
   state: ActionState;
+  failure?: ActionRunFailure | null;
   myDependencies: ActionId[];
   dependentOn: ActionId[];
   holdStatusInfluencedBy: ActionId[];
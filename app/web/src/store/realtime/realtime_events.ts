@@ -104,6 +104,10 @@ export type WsEventPayloadMap = {
     changeSetId: ChangeSetId;
     toRebaseChangeSetId: ChangeSetId;
     userPk: UserId;
+    summary?: {
+      componentsAffected: number;
+      actionsEnqueued: number;
+    };
   };
   ChangeSetBeginApprovalProcess: {
     changeSetId: ChangeSetId;
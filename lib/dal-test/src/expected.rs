@@ -734,6 +734,32 @@ impl ExpectComponentProp {
         self.attribute_value(ctx).await.children(ctx).await
     }
 
+    /// Removes the array element (or map entry) at the given position, keeping the order of the
+    /// remaining elements.
+    pub async fn remove_at(self, ctx: &DalContext, index: usize) {
+        let children = self.children(ctx).await;
+        children
+            .get(index)
+            .copied()
+            .expect("no element at index")
+            .remove(ctx)
+            .await
+    }
+
+    pub async fn rename_key(
+        self,
+        ctx: &DalContext,
+        old_key: impl Into<String>,
+        new_key: impl Into<String>,
+    ) -> ExpectAttributeValue {
+        let map_attribute_value_id = self.attribute_value(ctx).await.id();
+        let renamed_id =
+            dal::AttributeValue::rename_map_key(ctx, map_attribute_value_id, old_key, new_key)
+                .await
+                .expect("rename map key failed");
+        ExpectAttributeValue(renamed_id)
+    }
+
     // The value of the prop, or its default value.
     pub async fn view(self, ctx: &DalContext) -> Option<Value> {
         self.attribute_value(ctx).await.view(ctx).await
@@ -748,6 +774,10 @@ impl ExpectComponentProp {
         self.attribute_value(ctx).await.update(ctx, value).await
     }
 
+    pub async fn unset(self, ctx: &DalContext) {
+        self.attribute_value(ctx).await.unset(ctx).await
+    }
+
     pub async fn insert(
         self,
         ctx: &DalContext,
@@ -965,6 +995,12 @@ impl ExpectAttributeValue {
             .expect("update prop value failed")
     }
 
+    pub async fn unset(self, ctx: &DalContext) {
+        dal::AttributeValue::unset(ctx, self.0)
+            .await
+            .expect("unset prop value failed")
+    }
+
     pub async fn insert(
         self,
         ctx: &DalContext,
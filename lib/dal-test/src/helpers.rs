@@ -91,6 +91,23 @@ pub async fn create_component_for_default_schema_name_in_default_view(
     create_component_for_default_schema_name(ctx, schema_name, name, view_id).await
 }
 
+/// Creates a [`Component`] for each `(name, schema name)` pair, using the default
+/// [`SchemaVariant`] for each [`Schema`], in the default view. The components are returned in
+/// the order they were requested.
+pub async fn create_components_for_default_schema_names_in_default_view(
+    ctx: &DalContext,
+    components: &[(&str, &str)],
+) -> Result<Vec<Component>> {
+    let mut created = Vec::with_capacity(components.len());
+    for (name, schema_name) in components {
+        created.push(
+            create_component_for_default_schema_name_in_default_view(ctx, schema_name, name)
+                .await?,
+        );
+    }
+    Ok(created)
+}
+
 /// Creates a [`Component`] from the default [`SchemaVariant`] corresponding to a provided
 /// [`Schema`] name in the provided [dal::diagram::view::View]
 pub async fn create_component_for_default_schema_name(
@@ -3,7 +3,7 @@ use std::collections::{HashSet, VecDeque};
 use petgraph::prelude::*;
 use postgres_types::{FromSql, ToSql};
 use serde::{Deserialize, Serialize};
-use si_events::ulid::Ulid;
+use si_events::{ulid::Ulid, FuncRunId};
 use si_layer_cache::LayerDbError;
 use strum::{AsRefStr, Display, EnumDiscriminants, EnumIter, EnumString};
 use telemetry::prelude::*;
@@ -43,6 +43,12 @@ pub enum ActionError {
     Component(#[from] ComponentError),
     #[error("component not found for action: {0}")]
     ComponentNotFoundForAction(ActionId),
+    #[error("component {1} for func run {0} no longer exists")]
+    ComponentNotFoundForFuncRun(FuncRunId, ComponentId),
+    #[error("func run {0} did not run an action")]
+    FuncRunNotForAction(FuncRunId),
+    #[error("func run not found: {0}")]
+    FuncRunNotFound(FuncRunId),
     #[error("Helper error: {0}")]
     Helper(#[from] HelperError),
     #[error("InferredConnectionGraph error: {0}")]
@@ -51,6 +57,8 @@ pub enum ActionError {
     LayerDb(#[from] LayerDbError),
     #[error("Node Weight error: {0}")]
     NodeWeight(#[from] NodeWeightError),
+    #[error("action prototype {0} no longer applies to component {1}")]
+    PrototypeNoLongerApplies(ActionPrototypeId, ComponentId),
     #[error("prototype not found for action: {0}")]
     PrototypeNotFoundForAction(ActionId),
     #[error("Transactions error: {0}")]
@@ -68,7 +76,18 @@ pub type ActionResult<T> = Result<T, ActionError>;
 pub use si_id::ActionId;
 pub use si_id::ActionPrototypeId;
 
-#[derive(Debug, Copy, Clone, Deserialize, Serialize, EnumDiscriminants, PartialEq, Eq, Display)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Deserialize,
+    Serialize,
+    EnumDiscriminants,
+    PartialEq,
+    Eq,
+    Display,
+    EnumString,
+)]
 #[strum_discriminants(derive(strum::Display, Serialize, Deserialize))]
 pub enum ActionState {
     /// Action has been determined to be eligible to run, and has had its job sent to the job
@@ -373,6 +392,67 @@ impl Action {
         Ok(new_action)
     }
 
+    /// Enqueues the action recorded by a previous [`FuncRun`](si_events::FuncRun) again, returning
+    /// the id of the queued action. If an equivalent action is already on the graph, its id is
+    /// returned instead of enqueuing a duplicate, and it is queued again if it had failed or was
+    /// on hold.
+    ///
+    /// The rerun is rejected if the component no longer exists or if its schema variant no longer
+    /// has the action prototype that was run.
+    #[instrument(level = "info", skip(ctx))]
+    pub async fn rerun_from_func_run(
+        ctx: &DalContext,
+        func_run_id: FuncRunId,
+    ) -> ActionResult<ActionId> {
+        let func_run = ctx
+            .layer_db()
+            .func_run()
+            .read(func_run_id)
+            .await?
+            .ok_or(ActionError::FuncRunNotFound(func_run_id))?;
+        let (Some(action_prototype_id), Some(component_id)) =
+            (func_run.action_prototype_id(), func_run.component_id())
+        else {
+            return Err(ActionError::FuncRunNotForAction(func_run_id));
+        };
+
+        if Component::try_get_by_id(ctx, component_id).await?.is_none() {
+            return Err(ActionError::ComponentNotFoundForFuncRun(
+                func_run_id,
+                component_id,
+            ));
+        }
+        let schema_variant_id = Component::schema_variant_id(ctx, component_id).await?;
+        if !ActionPrototype::for_variant(ctx, schema_variant_id)
+            .await?
+            .iter()
+            .any(|prototype| prototype.id() == action_prototype_id)
+        {
+            return Err(ActionError::PrototypeNoLongerApplies(
+                action_prototype_id,
+                component_id,
+            ));
+        }
+
+        if let Some(action_id) =
+            Self::find_equivalent(ctx, action_prototype_id, Some(component_id)).await?
+        {
+            // A failed or held action is still on the graph, so it is queued again (as a retry
+            // would) rather than left as is.
+            match Self::get_by_id(ctx, action_id).await?.state() {
+                ActionState::Failed | ActionState::OnHold => {
+                    Self::set_state(ctx, action_id, ActionState::Queued).await?;
+                }
+                ActionState::Dispatched | ActionState::Queued | ActionState::Running => {}
+            }
+            return Ok(action_id);
+        }
+
+        Ok(Self::new(ctx, action_prototype_id, Some(component_id))
+            .await?
+            .id())
+    }
+
     pub async fn remove_by_id(ctx: &DalContext, action_id: ActionId) -> ActionResult<()> {
         ctx.workspace_snapshot()?
             .remove_node_by_id(action_id)
@@ -426,6 +506,22 @@ impl Action {
         Ok(result)
     }
 
+    /// Like [`Self::list_topologically`], but only lists the [`Actions`][Action] for the given
+    /// [`Component`].
+    #[instrument(level = "info", skip(ctx))]
+    pub async fn list_topologically_for_component_id(
+        ctx: &DalContext,
+        component_id: ComponentId,
+    ) -> ActionResult<Vec<ActionId>> {
+        let mut result = Vec::new();
+        for action_id in Self::list_topologically(ctx).await? {
+            if Self::component_id(ctx, action_id).await? == Some(component_id) {
+                result.push(action_id);
+            }
+        }
+        Ok(result)
+    }
+
     pub async fn prototype_id(
         ctx: &DalContext,
         action_id: ActionId,
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use petgraph::{Direction::Incoming, Outgoing};
 use serde::{Deserialize, Serialize};
 use si_events::{ActionResultState, FuncRunId};
-use si_layer_cache::LayerDbError;
+use si_layer_cache::{db::func_run::FuncRunActionFailure, LayerDbError};
 use si_pkg::ActionFuncSpecKind;
 use strum::Display;
 use thiserror::Error;
@@ -25,9 +25,9 @@ use crate::{
         },
     },
     ActionPrototypeId, ChangeSetError, Component, ComponentError, ComponentId, DalContext,
-    EdgeWeightKind, EdgeWeightKindDiscriminants, HelperError, SchemaVariant, SchemaVariantError,
-    SchemaVariantId, TransactionsError, WorkspaceSnapshotError, WsEvent, WsEventError,
-    WsEventResult, WsPayload,
+    EdgeWeightKind, EdgeWeightKindDiscriminants, Func, FuncError, HelperError, SchemaVariant,
+    SchemaVariantError, SchemaVariantId, TransactionsError, WorkspaceSnapshotError, WsEvent,
+    WsEventError, WsEventResult, WsPayload,
 };
 use si_frontend_types::DiagramComponentView;
 
@@ -40,6 +40,8 @@ pub enum ActionPrototypeError {
     Component(#[from] ComponentError),
     #[error("diagram error: {0}")]
     Diagram(#[from] DiagramError),
+    #[error("func error: {0}")]
+    Func(#[from] FuncError),
     #[error("func not found for prototype: {0}")]
     FuncNotFoundForPrototype(ActionPrototypeId),
     #[error("func runner error: {0}")]
@@ -141,6 +143,90 @@ pub struct ActionPrototype {
     pub description: Option<String>,
 }
 
+/// What running an [`ActionPrototype`] for a [`Component`] would do, as reported by
+/// [`ActionPrototype::dry_run`]. Nothing is executed to produce it.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionDryRun {
+    pub prototype_id: ActionPrototypeId,
+    pub kind: ActionKind,
+    pub component_id: ComponentId,
+    pub func_id: FuncId,
+    pub func_name: String,
+    /// The arguments the action func would be called with.
+    pub args: serde_json::Value,
+}
+
+/// Why an action run failed, derived from its [`ActionRunResultSuccess`] with
+/// [`ActionRunFailure::from_run_result`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionRunFailure {
+    /// The text to show as the failure.
+    pub reason: String,
+    /// The message returned by the action func, if it was not already used as the reason.
+    pub message: Option<String>,
+}
+
+impl ActionRunFailure {
+    /// Returns the failure for a run result, or `None` if the run succeeded. An error thrown by
+    /// the action func always fails the run and takes precedence over its message, even if it
+    /// returned an [`Ok`](ResourceStatus::Ok) status. Without a thrown error, the run fails when
+    /// its status is not [`Ok`](ResourceStatus::Ok) and the message becomes the reason.
+    pub fn from_run_result(run_result: &ActionRunResultSuccess) -> Option<Self> {
+        match (&run_result.error, run_result.status) {
+            (Some(error), _) => Some(Self {
+                reason: error.clone(),
+                message: run_result.message.clone(),
+            }),
+            (None, ResourceStatus::Ok) => None,
+            (None, status) => Some(Self {
+                reason: run_result
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| format!("action returned a status of {status:?}")),
+                message: None,
+            }),
+        }
+    }
+
+    /// Records this failure against the [`FuncRun`](si_events::FuncRun) of the action run.
+    pub async fn record(
+        &self,
+        ctx: &DalContext,
+        func_run_id: FuncRunId,
+    ) -> ActionPrototypeResult<()> {
+        ctx.layer_db()
+            .func_run()
+            .record_action_failure(
+                func_run_id,
+                ctx.events_tenancy().workspace_pk,
+                &FuncRunActionFailure {
+                    reason: self.reason.clone(),
+                    message: self.message.clone(),
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the failure recorded for an action run with [`ActionRunFailure::record`], if any.
+    pub async fn for_func_run(
+        ctx: &DalContext,
+        func_run_id: FuncRunId,
+    ) -> ActionPrototypeResult<Option<Self>> {
+        Ok(ctx
+            .layer_db()
+            .func_run()
+            .get_action_failure(func_run_id)
+            .await?
+            .map(|failure| Self {
+                reason: failure.reason,
+                message: failure.message,
+            }))
+    }
+}
+
 impl From<ActionPrototypeNodeWeight> for ActionPrototype {
     fn from(value: ActionPrototypeNodeWeight) -> Self {
         Self {
@@ -292,23 +378,45 @@ impl ActionPrototype {
         Err(ActionPrototypeError::SchemaVariantNotFoundForPrototype(id))
     }
 
-    pub async fn run(
+    /// Reports what [`Self::run`] would do for the given [`Component`] without executing the
+    /// action func, so nothing in the outside world is touched.
+    pub async fn dry_run(
         ctx: &DalContext,
         id: ActionPrototypeId,
         component_id: ComponentId,
-    ) -> ActionPrototypeResult<(Option<ActionRunResultSuccess>, FuncRunId)> {
-        let component = Component::get_by_id(ctx, component_id).await?;
-        let component_view = component.view(ctx).await?;
+    ) -> ActionPrototypeResult<ActionDryRun> {
+        let prototype = Self::get_by_id(ctx, id).await?;
         let func_id = Self::func_id(ctx, id).await?;
+        let func = Func::get_by_id_or_error(ctx, func_id).await?;
 
-        let result_channel = FuncRunner::run_action(
-            ctx,
-            id,
+        Ok(ActionDryRun {
+            prototype_id: id,
+            kind: prototype.kind,
             component_id,
             func_id,
-            serde_json::json!({ "properties" : component_view }),
-        )
-        .await?;
+            func_name: func.name,
+            args: Self::func_args(ctx, component_id).await?,
+        })
+    }
+
+    async fn func_args(
+        ctx: &DalContext,
+        component_id: ComponentId,
+    ) -> ActionPrototypeResult<serde_json::Value> {
+        let component = Component::get_by_id(ctx, component_id).await?;
+        let component_view = component.view(ctx).await?;
+        Ok(serde_json::json!({ "properties" : component_view }))
+    }
+
+    pub async fn run(
+        ctx: &DalContext,
+        id: ActionPrototypeId,
+        component_id: ComponentId,
+    ) -> ActionPrototypeResult<(Option<ActionRunResultSuccess>, FuncRunId)> {
+        let func_id = Self::func_id(ctx, id).await?;
+        let args = Self::func_args(ctx, component_id).await?;
+
+        let result_channel = FuncRunner::run_action(ctx, id, component_id, func_id, args).await?;
 
         let func_run_value = result_channel
             .await
@@ -367,9 +475,12 @@ impl ActionPrototype {
             None => None,
         };
 
-        match maybe_run_result.as_ref().map(|r| r.status) {
-            // If we have a resource and an ok status
-            Some(ResourceStatus::Ok) => {
+        match maybe_run_result
+            .as_ref()
+            .map(ActionRunFailure::from_run_result)
+        {
+            // If we have a resource and no failure
+            Some(None) => {
                 // Set the `FuncRun`'s action-specific metadata to successful
                 ctx.layer_db()
                     .func_run()
@@ -382,7 +493,7 @@ impl ActionPrototype {
                     .await?;
             }
             // In all other cases
-            Some(_) | None => {
+            Some(Some(_)) | None => {
                 // Set the `FuncRun`'s action-specific metadata to falure
                 ctx.layer_db()
                     .func_run()
@@ -501,3 +612,63 @@ impl WsEvent {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_result(
+        status: ResourceStatus,
+        message: Option<&str>,
+        error: Option<&str>,
+    ) -> ActionRunResultSuccess {
+        ActionRunResultSuccess {
+            execution_id: "execution".to_string(),
+            resource_id: None,
+            payload: None,
+            status,
+            message: message.map(str::to_string),
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn error_takes_precedence_over_message() {
+        for status in [ResourceStatus::Ok, ResourceStatus::Error] {
+            assert_eq!(
+                Some(ActionRunFailure {
+                    reason: "TypeError: cannot read properties of undefined".to_string(),
+                    message: Some("creating the bucket".to_string()),
+                }),
+                ActionRunFailure::from_run_result(&run_result(
+                    status,
+                    Some("creating the bucket"),
+                    Some("TypeError: cannot read properties of undefined"),
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn message_is_the_reason_without_an_error() {
+        assert_eq!(
+            Some(ActionRunFailure {
+                reason: "bucket already exists".to_string(),
+                message: None,
+            }),
+            ActionRunFailure::from_run_result(&run_result(
+                ResourceStatus::Warning,
+                Some("bucket already exists"),
+                None,
+            ))
+        );
+        assert_eq!(
+            None,
+            ActionRunFailure::from_run_result(&run_result(
+                ResourceStatus::Ok,
+                Some("created"),
+                None,
+            ))
+        );
+    }
+}
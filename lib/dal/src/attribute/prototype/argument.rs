@@ -25,8 +25,8 @@ use crate::{
         },
         WorkspaceSnapshotError,
     },
-    AttributePrototype, AttributePrototypeId, AttributeValue, ComponentId, DalContext, HelperError,
-    OutputSocketId, PropId, SecretId, Timestamp, TransactionsError,
+    AttributePrototype, AttributePrototypeId, AttributeValue, AttributeValueId, ComponentId,
+    DalContext, HelperError, OutputSocketId, PropId, SecretId, Timestamp, TransactionsError,
 };
 
 use self::{
@@ -641,6 +641,32 @@ impl AttributePrototypeArgument {
         Ok(())
     }
 
+    /// Removes all [`AttributePrototypeArguments`](AttributePrototypeArgument) corresponding to
+    /// the provided IDs, enqueueing a single dependent values update for all impacted
+    /// destination attribute values rather than one per argument.
+    pub async fn remove_many(
+        ctx: &DalContext,
+        attribute_prototype_argument_ids: impl IntoIterator<Item = AttributePrototypeArgumentId>,
+    ) -> AttributePrototypeArgumentResult<()> {
+        let mut avs_to_update = HashSet::new();
+        for attribute_prototype_argument_id in attribute_prototype_argument_ids {
+            let attribute_prototype_argument =
+                Self::get_by_id(ctx, attribute_prototype_argument_id).await?;
+            avs_to_update.extend(
+                attribute_prototype_argument
+                    .remove_without_enqueue(ctx)
+                    .await?,
+            );
+        }
+
+        if !avs_to_update.is_empty() {
+            ctx.add_dependent_values_and_enqueue(avs_to_update.into_iter().collect::<Vec<_>>())
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Removes the [`AttributePrototypeArgument`] corresponding to the provided ID, but is a
     /// "no-op" if it cannot be found before removal.
     pub async fn remove_or_no_op(
@@ -665,6 +691,21 @@ impl AttributePrototypeArgument {
     /// A _private_ method that consumes self and removes the corresponding
     /// [`AttributePrototypeArgument`].
     async fn remove_inner(self, ctx: &DalContext) -> AttributePrototypeArgumentResult<()> {
+        let avs_to_update = self.remove_without_enqueue(ctx).await?;
+
+        // Enqueue a dependent values update with the destination attribute values
+        ctx.add_dependent_values_and_enqueue(avs_to_update).await?;
+
+        Ok(())
+    }
+
+    /// A _private_ method that consumes self, removes the corresponding
+    /// [`AttributePrototypeArgument`] and returns the destination attribute values that need a
+    /// dependent values update.
+    async fn remove_without_enqueue(
+        self,
+        ctx: &DalContext,
+    ) -> AttributePrototypeArgumentResult<Vec<AttributeValueId>> {
         let prototype_id = self.prototype_id(ctx).await?;
         // Find all of the "destination" attribute values.
         let mut avs_to_update = AttributePrototype::attribute_value_ids(ctx, prototype_id).await?;
@@ -686,9 +727,6 @@ impl AttributePrototypeArgument {
         // Remove the argument
         ctx.workspace_snapshot()?.remove_node_by_id(self.id).await?;
 
-        // Enqueue a dependent values update with the destination attribute values
-        ctx.add_dependent_values_and_enqueue(avs_to_update).await?;
-
-        Ok(())
+        Ok(avs_to_update)
     }
 }
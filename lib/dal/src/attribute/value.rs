@@ -160,6 +160,10 @@ pub enum AttributeValueError {
     InsertionForInvalidPropKind(PropKind),
     #[error("layer db error: {0}")]
     LayerDb(#[from] si_layer_cache::LayerDbError),
+    #[error("map attribute value {0} already has an entry with key: {1}")]
+    MapKeyAlreadyExists(AttributeValueId, String),
+    #[error("map attribute value {0} has no entry with key: {1}")]
+    MapKeyNotFound(AttributeValueId, String),
     #[error("missing attribute prototype argument source: {0}")]
     MissingAttributePrototypeArgumentSource(AttributePrototypeArgumentId),
     #[error("missing attribute value with id: {0}")]
@@ -1325,6 +1329,8 @@ impl AttributeValue {
     #[async_recursion]
     pub async fn view(&self, ctx: &DalContext) -> AttributeValueResult<Option<serde_json::Value>> {
         let attribute_value_id = self.id;
+        ctx.assert_consistent_read("attribute value", attribute_value_id)
+            .await?;
 
         match AttributeValue::is_for(ctx, attribute_value_id).await? {
             ValueIsFor::Prop(prop_id) => {
@@ -1857,6 +1863,25 @@ impl AttributeValue {
         Ok(())
     }
 
+    /// Unsets the [`AttributeValue`], reverting it to whatever its [`Prop`] default or
+    /// schema-level prototype would produce. This differs from calling [`Self::update`] with
+    /// [`Value::Null`], which explicitly _sets_ the value to JSON null.
+    ///
+    /// Unlike [`Self::use_default_prototype`], this is a no-op if the value was never set.
+    pub async fn unset(
+        ctx: &DalContext,
+        attribute_value_id: AttributeValueId,
+    ) -> AttributeValueResult<()> {
+        if Self::component_prototype_id(ctx, attribute_value_id)
+            .await?
+            .is_none()
+        {
+            return Ok(());
+        }
+
+        Self::use_default_prototype(ctx, attribute_value_id).await
+    }
+
     #[instrument(
         name = "attribute_value.set_value",
         level = "info",
@@ -2561,6 +2586,65 @@ impl AttributeValue {
         Ok(())
     }
 
+    /// Moves the entry of the map [`AttributeValue`] at `old_key` to `new_key`, keeping the
+    /// entry's [`AttributeValue`] (and everything beneath it) as well as its position in the map.
+    pub async fn rename_map_key(
+        ctx: &DalContext,
+        map_attribute_value_id: AttributeValueId,
+        old_key: impl Into<String>,
+        new_key: impl Into<String>,
+    ) -> AttributeValueResult<AttributeValueId> {
+        let old_key = old_key.into();
+        let new_key = new_key.into();
+
+        let children = Self::map_children(ctx, map_attribute_value_id).await?;
+        let entry_id = *children.get(&old_key).ok_or_else(|| {
+            AttributeValueError::MapKeyNotFound(map_attribute_value_id, old_key.clone())
+        })?;
+        if old_key == new_key {
+            return Ok(entry_id);
+        }
+        if children.contains_key(&new_key) {
+            return Err(AttributeValueError::MapKeyAlreadyExists(
+                map_attribute_value_id,
+                new_key,
+            ));
+        }
+
+        let workspace_snapshot = ctx.workspace_snapshot()?;
+        let order = workspace_snapshot
+            .ordered_children_for_node(map_attribute_value_id)
+            .await?
+            .ok_or(AttributeValueError::NoOrderingNodeForAttributeValue(
+                map_attribute_value_id,
+            ))?;
+
+        // Swapping the edge drops the entry from the ordering and appends it again, so we restore
+        // the original order afterwards.
+        workspace_snapshot
+            .remove_edge_for_ulids(
+                map_attribute_value_id,
+                entry_id,
+                EdgeWeightKindDiscriminants::Contain,
+            )
+            .await?;
+        Self::add_edge_to_attribute_value_ordered(
+            ctx,
+            map_attribute_value_id,
+            entry_id,
+            EdgeWeightKind::Contain(Some(new_key)),
+        )
+        .await?;
+        workspace_snapshot
+            .update_order(map_attribute_value_id, order)
+            .await?;
+
+        ctx.add_dependent_values_and_enqueue(vec![map_attribute_value_id])
+            .await?;
+
+        Ok(entry_id)
+    }
+
     pub async fn list_input_socket_sources_for_id(
         ctx: &DalContext,
         av_id: AttributeValueId,
@@ -14,6 +14,7 @@ use thiserror::Error;
 use tokio::time;
 
 use crate::billing_publish::BillingPublishError;
+use crate::change_set::event::ChangeSetAppliedSummary;
 use crate::slow_rt::SlowRuntimeError;
 use crate::workspace_snapshot::graph::RebaseBatch;
 use crate::{
@@ -28,6 +29,7 @@ use crate::{
 };
 
 pub mod approval;
+pub mod blast_radius;
 pub mod event;
 pub mod status;
 pub mod view;
@@ -318,6 +320,11 @@ impl ChangeSet {
         Ok(self.workspace(ctx).await?.default_change_set_id() == self.id)
     }
 
+    /// Returns whether or not the [`ChangeSet`] has already been applied to its base.
+    pub fn is_applied(&self) -> bool {
+        self.status == ChangeSetStatus::Applied
+    }
+
     #[instrument(name = "change_set.update_pointer", level = "info", skip_all)]
     pub async fn update_pointer(
         &mut self,
@@ -433,6 +440,14 @@ impl ChangeSet {
         ctx: &DalContext,
         dangerous_skip_status_check: bool,
     ) -> ChangeSetResult<()> {
+        // Preparing an already applied change set is a no-op so that retried applies succeed.
+        if ChangeSet::get_by_id(ctx, ctx.change_set_id())
+            .await?
+            .is_applied()
+        {
+            return Ok(());
+        }
+
         // Ensure that DVU roots are empty before continuing.
         if !ctx
             .workspace_snapshot()
@@ -752,10 +767,17 @@ impl ChangeSet {
     /// Applies the current [`ChangeSet`] in the provided [`DalContext`]. [`Actions`](Action)
     /// are enqueued as needed and only done so if the base [`ChangeSet`] is "HEAD" (i.e.
     /// the default [`ChangeSet`] of the [`Workspace`]).
+    ///
+    /// Applying a [`ChangeSet`] that has already been applied is a no-op: nothing is rebased and
+    /// no [`Actions`](Action) are enqueued again, so retried requests are safe.
     #[instrument(level = "info", skip_all)]
     pub async fn apply_to_base_change_set(ctx: &mut DalContext) -> ChangeSetApplyResult<ChangeSet> {
         // Apply to the base change with the current change set (non-editing) and commit.
         let mut change_set_to_be_applied = Self::get_by_id(ctx, ctx.change_set_id()).await?;
+        if change_set_to_be_applied.is_applied() {
+            info!(change_set_id = %change_set_to_be_applied.id, "change set already applied, skipping apply");
+            return Ok(change_set_to_be_applied);
+        }
         ctx.update_visibility_and_snapshot_to_visibility(ctx.change_set_id())
             .await?;
         change_set_to_be_applied
@@ -809,7 +831,11 @@ impl ChangeSet {
             .base_change_set_id
             .ok_or(ChangeSetError::NoBaseChangeSet(self.id))?;
 
+        let mut summary = None;
         if let Some(rebase_batch) = self.detect_updates_that_will_be_applied(ctx).await? {
+            // Summarize the batch before it is applied, so that clients can update without
+            // refetching.
+            summary = Some(ChangeSetAppliedSummary::from_rebase_batch(ctx, &rebase_batch).await);
             let updates_address = ctx.write_rebase_batch(rebase_batch).await?;
 
             let (request_id, reply_fut) = ctx
@@ -838,7 +864,7 @@ impl ChangeSet {
 
         self.update_status(ctx, ChangeSetStatus::Applied).await?;
         let user = Self::extract_userid_from_context(ctx).await;
-        WsEvent::change_set_applied(ctx, self.id, base_change_set_id, user)
+        WsEvent::change_set_applied(ctx, self.id, base_change_set_id, user, summary)
             .await?
             .publish_on_commit(ctx)
             .await?;
@@ -0,0 +1,110 @@
+//! This module contains the ability to preview what applying a [`ChangeSet`](crate::ChangeSet)
+//! to its base would affect, without applying it.
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use telemetry::prelude::*;
+use thiserror::Error;
+
+use crate::{
+    action::{Action, ActionError, ActionId},
+    Component, ComponentError, ComponentId, DalContext, TransactionsError,
+};
+
+#[remain::sorted]
+#[derive(Debug, Error)]
+pub enum BlastRadiusError {
+    #[error("action error: {0}")]
+    Action(#[from] Box<ActionError>),
+    #[error("component error: {0}")]
+    Component(#[from] Box<ComponentError>),
+    #[error("transactions error: {0}")]
+    Transactions(#[from] TransactionsError),
+}
+
+impl From<ActionError> for BlastRadiusError {
+    fn from(value: ActionError) -> Self {
+        Self::Action(Box::new(value))
+    }
+}
+
+impl From<ComponentError> for BlastRadiusError {
+    fn from(value: ComponentError) -> Self {
+        Self::Component(Box::new(value))
+    }
+}
+
+pub type BlastRadiusResult<T> = Result<T, BlastRadiusError>;
+
+/// The [`Components`](Component) and [`Actions`](Action) that applying the current change set to
+/// head would affect.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeSetBlastRadius {
+    /// Components that were added, removed or had their values changed in the change set.
+    pub changed_component_ids: Vec<ComponentId>,
+    /// Components that are not changed themselves, but are connected downstream of a changed
+    /// component and so will have their values recomputed.
+    pub downstream_component_ids: Vec<ComponentId>,
+    /// Actions queued in the change set that will be dispatched on head once it is applied.
+    pub action_ids: Vec<ActionId>,
+}
+
+impl ChangeSetBlastRadius {
+    /// Computes the blast radius of the change set in the provided [`DalContext`] by comparing
+    /// it to head. Nothing is applied or written.
+    #[instrument(name = "change_set.blast_radius.compute", level = "info", skip_all)]
+    pub async fn compute(ctx: &DalContext) -> BlastRadiusResult<Self> {
+        let head_ctx = ctx.clone_with_head().await?;
+
+        let component_ids: HashSet<ComponentId> =
+            Component::list_ids(ctx).await?.into_iter().collect();
+        let head_component_ids: HashSet<ComponentId> =
+            Component::list_ids(&head_ctx).await?.into_iter().collect();
+
+        let mut changed_component_ids: BTreeSet<ComponentId> = head_component_ids
+            .difference(&component_ids)
+            .copied()
+            .collect();
+        for &component_id in &component_ids {
+            if !head_component_ids.contains(&component_id)
+                || Component::view_by_id(ctx, component_id).await?
+                    != Component::view_by_id(&head_ctx, component_id).await?
+            {
+                changed_component_ids.insert(component_id);
+            }
+        }
+
+        let mut downstream_component_ids = BTreeSet::new();
+        let mut work_queue: VecDeque<ComponentId> = changed_component_ids
+            .iter()
+            .copied()
+            .filter(|component_id| component_ids.contains(component_id))
+            .collect();
+        while let Some(component_id) = work_queue.pop_front() {
+            for connection in Component::outgoing_connections_for_id(ctx, component_id).await? {
+                let to_component_id = connection.to_component_id;
+                if !changed_component_ids.contains(&to_component_id)
+                    && downstream_component_ids.insert(to_component_id)
+                {
+                    work_queue.push_back(to_component_id);
+                }
+            }
+        }
+
+        let head_action_ids: HashSet<ActionId> =
+            Action::all_ids(&head_ctx).await?.into_iter().collect();
+        let action_ids: BTreeSet<ActionId> = Action::all_ids(ctx)
+            .await?
+            .into_iter()
+            .filter(|action_id| !head_action_ids.contains(action_id))
+            .collect();
+
+        Ok(Self {
+            changed_component_ids: changed_component_ids.into_iter().collect(),
+            downstream_component_ids: downstream_component_ids.into_iter().collect(),
+            action_ids: action_ids.into_iter().collect(),
+        })
+    }
+}
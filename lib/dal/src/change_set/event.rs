@@ -1,6 +1,17 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
+use si_events::ulid::Ulid;
+use telemetry::prelude::*;
 
-use crate::{ChangeSetId, ChangeSetStatus, DalContext, UserPk, WsEvent, WsEventResult, WsPayload};
+use crate::{
+    workspace_snapshot::{
+        graph::{detector::Update, RebaseBatch},
+        node_weight::{NodeWeight, NodeWeightDiscriminants},
+    },
+    AttributeValue, ChangeSetId, ChangeSetStatus, ComponentId, DalContext, UserPk, WsEvent,
+    WsEventResult, WsPayload,
+};
 
 impl WsEvent {
     pub async fn change_set_written(
@@ -63,6 +74,7 @@ impl WsEvent {
         change_set_id: ChangeSetId,
         to_rebase_change_set_id: ChangeSetId,
         user_pk: Option<UserPk>,
+        summary: Option<ChangeSetAppliedSummary>,
     ) -> WsEventResult<Self> {
         WsEvent::new(
             ctx,
@@ -70,6 +82,7 @@ impl WsEvent {
                 change_set_id,
                 to_rebase_change_set_id,
                 user_pk,
+                summary,
             }),
         )
         .await
@@ -164,6 +177,68 @@ pub struct ChangeSetAppliedPayload {
     change_set_id: ChangeSetId,
     to_rebase_change_set_id: ChangeSetId,
     user_pk: Option<UserPk>,
+    /// What the apply affected, when it is known by the publisher of the event.
+    summary: Option<ChangeSetAppliedSummary>,
+}
+
+/// The counts of what applying a change set affected, so clients can update without refetching.
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeSetAppliedSummary {
+    /// The number of components that were changed by the apply.
+    pub components_affected: usize,
+    /// The number of actions from the change set that were enqueued on the base change set.
+    pub actions_enqueued: usize,
+}
+
+impl ChangeSetAppliedSummary {
+    /// Summarizes the rebase batch being applied. This only looks at the nodes in the batch (not
+    /// at every component), so it is cheap enough to run on every apply. Components are counted
+    /// when their own node changes, when they are removed, or when one of their attribute values
+    /// changes. Components whose values are only recomputed downstream are not counted.
+    pub async fn from_rebase_batch(ctx: &DalContext, rebase_batch: &RebaseBatch) -> Self {
+        let mut component_ids = HashSet::new();
+        let mut actions_enqueued = 0;
+
+        for update in rebase_batch.updates() {
+            match update {
+                Update::NewNode { node_weight } | Update::ReplaceNode { node_weight } => {
+                    match node_weight {
+                        NodeWeight::Action(_) if matches!(update, Update::NewNode { .. }) => {
+                            actions_enqueued += 1;
+                        }
+                        NodeWeight::Component(component) => {
+                            component_ids.insert(ComponentId::from(component.id()));
+                        }
+                        NodeWeight::AttributeValue(attribute_value) => {
+                            match AttributeValue::component_id(ctx, attribute_value.id().into())
+                                .await
+                            {
+                                Ok(component_id) => {
+                                    component_ids.insert(component_id);
+                                }
+                                Err(err) => {
+                                    debug!(?err, "could not find component for attribute value");
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Update::RemoveEdge { destination, .. }
+                    if destination.node_weight_kind == NodeWeightDiscriminants::Component =>
+                {
+                    component_ids.insert(ComponentId::from(Ulid::from(destination.id)));
+                }
+                Update::NewEdge { .. } | Update::RemoveEdge { .. } => {}
+            }
+        }
+
+        Self {
+            components_affected: component_ids.len(),
+            actions_enqueued,
+        }
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
@@ -78,6 +78,7 @@ pub mod frame;
 pub mod inferred_connection_graph;
 pub mod properties;
 pub mod qualification;
+pub mod refresh;
 pub mod resource;
 pub mod socket;
 
@@ -301,6 +302,16 @@ impl From<Component> for ComponentContentV2 {
     }
 }
 
+/// The outcome of [`Component::migrate_to_variant`].
+#[derive(Clone, Debug)]
+pub struct ComponentMigration {
+    /// The [`Component`] as it exists on the target [`SchemaVariant`].
+    pub component: Component,
+    /// Paths (e.g. `/root/domain/count`) of the explicitly set values that could not be carried
+    /// over to the target [`SchemaVariant`], because their prop was removed or changed kind.
+    pub uncarried_values: Vec<String>,
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct ControllingFuncData {
     pub func_id: FuncId,
@@ -357,6 +368,8 @@ impl Component {
         ctx: &DalContext,
         id: ComponentId,
     ) -> ComponentResult<Option<serde_json::Value>> {
+        ctx.assert_consistent_read("component", id).await?;
+
         let schema_variant_id = Self::schema_variant_id(ctx, id).await?;
         let root_prop_id =
             Prop::find_prop_id_by_path(ctx, schema_variant_id, &PropPath::new(["root"])).await?;
@@ -618,14 +631,19 @@ impl Component {
     /// Component::new, so that we can make certain assumptions (for example, we
     /// can assume that the prototypes are correct, and that arrays and maps are
     /// empty)
+    ///
+    /// Returns the paths of the explicitly set values in other that could not
+    /// be carried over, either because their prop no longer exists or because
+    /// its kind changed.
     async fn merge_from_component_with_different_schema_variant(
         &self,
         ctx: &DalContext,
         old_component_id: ComponentId,
-    ) -> ComponentResult<()> {
+    ) -> ComponentResult<Vec<String>> {
         let old_root_id = Component::root_attribute_value_id(ctx, old_component_id).await?;
         let self_schema_variant_id = Component::schema_variant_id(ctx, self.id).await?;
         let mut dvu_roots = vec![];
+        let mut uncarried_values = vec![];
 
         // Gather a bunch of data about the current schema variant
         let mut new_input_sockets = HashMap::new();
@@ -671,6 +689,9 @@ impl Component {
             // is no matching prop do nothing (this means the prop was removed
             // from self, so can't get values from other)
             let Some(&new_prop_id) = new_props.get(&prop_path) else {
+                if maybe_old_component_prototype_id.is_some() {
+                    uncarried_values.extend(AttributeValue::get_path_for_id(ctx, old_av_id).await?);
+                }
                 continue;
             };
 
@@ -683,6 +704,9 @@ impl Component {
             // in other, we don't want to copy it over, since the kind has
             // changed.
             if new_prop.kind != old_prop.kind {
+                if maybe_old_component_prototype_id.is_some() {
+                    uncarried_values.extend(AttributeValue::get_path_for_id(ctx, old_av_id).await?);
+                }
                 continue;
             }
 
@@ -690,6 +714,9 @@ impl Component {
             // changed if this is a secret prop. If it has changed, leave
             // the prop alone (effectively emptying the secret)
             if new_prop.secret_kind_widget_option() != old_prop.secret_kind_widget_option() {
+                if maybe_old_component_prototype_id.is_some() {
+                    uncarried_values.extend(AttributeValue::get_path_for_id(ctx, old_av_id).await?);
+                }
                 continue;
             }
 
@@ -895,7 +922,11 @@ impl Component {
         let leaf_value_ids = component_graph.independent_values();
         ctx.add_dependent_values_and_enqueue(leaf_value_ids).await?;
 
-        Ok(())
+        // Report paths rooted at "/", matching SchemaVariant::diff_versions
+        Ok(uncarried_values
+            .into_iter()
+            .map(|path| format!("/{path}"))
+            .collect())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1545,6 +1576,8 @@ impl Component {
         ctx: &DalContext,
         resource: ResourceData,
     ) -> ComponentResult<()> {
+        resource.write_oversized_payload(ctx)?;
+
         let av_for_resource = self
             .attribute_value_for_prop(ctx, &["root", "resource"])
             .await?;
@@ -3400,6 +3433,28 @@ impl Component {
         ctx: &DalContext,
         schema_variant_id: SchemaVariantId,
     ) -> ComponentResult<Component> {
+        Ok(self.migrate(ctx, schema_variant_id).await?.component)
+    }
+
+    /// Moves the [`Component`] onto another version of its [`SchemaVariant`], carrying over every
+    /// value that is still compatible and reporting the ones that could not be carried.
+    #[instrument(level = "debug", skip(ctx))]
+    pub async fn migrate_to_variant(
+        ctx: &DalContext,
+        component_id: ComponentId,
+        target_schema_variant_id: SchemaVariantId,
+    ) -> ComponentResult<ComponentMigration> {
+        Self::get_by_id(ctx, component_id)
+            .await?
+            .migrate(ctx, target_schema_variant_id)
+            .await
+    }
+
+    async fn migrate(
+        &self,
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+    ) -> ComponentResult<ComponentMigration> {
         let original_component = Self::get_by_id(ctx, self.id).await?;
 
         // ================================================================================
@@ -3458,7 +3513,7 @@ impl Component {
             ));
         }
 
-        new_component_with_temp_id
+        let uncarried_values = new_component_with_temp_id
             .merge_from_component_with_different_schema_variant(ctx, original_component.id())
             .await?;
 
@@ -3668,7 +3723,10 @@ impl Component {
             }
         }
 
-        Ok(finalized_new_component)
+        Ok(ComponentMigration {
+            component: finalized_new_component,
+            uncarried_values,
+        })
     }
 
     async fn requeue_actions_for_upgraded_component(
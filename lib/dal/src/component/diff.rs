@@ -1,6 +1,6 @@
-//! This module contains [`ComponentDiff`].
+//! This module contains [`ComponentDiff`] and [`ComponentPropertyDiff`].
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 
 use crate::code_view::{CodeLanguage, CodeView};
 use crate::component::properties::ComponentProperties;
@@ -26,6 +26,71 @@ pub struct ComponentDiff {
     pub diffs: Vec<CodeView>,
 }
 
+/// Contains the paths of the properties that differ for a given [`Component`](crate::Component)
+/// between _head_ and the current [`Visibility`](crate::Visibility). Generated by
+/// [`Component::get_property_diff()`].
+///
+/// Paths are rooted at "/root" (e.g. "/root/domain/name"). If the
+/// [`Component`](crate::Component) only exists on one side, the diff contains a single path for
+/// the entire tree.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentPropertyDiff {
+    pub component_id: ComponentId,
+    /// Paths found in the current [`Visibility`](crate::Visibility), but not on _head_.
+    pub added: Vec<String>,
+    /// Paths found on _head_, but not in the current [`Visibility`](crate::Visibility).
+    pub removed: Vec<String>,
+    /// Paths found on both sides whose values differ.
+    pub changed: Vec<String>,
+}
+
+impl ComponentPropertyDiff {
+    fn new(component_id: ComponentId) -> Self {
+        Self {
+            component_id,
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+
+    /// Returns true if no properties differ.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn collect(&mut self, path: String, head: Option<&Value>, current: Option<&Value>) {
+        match (head, current) {
+            (None, None) => {}
+            (None, Some(_)) => self.added.push(path),
+            (Some(_), None) => self.removed.push(path),
+            (Some(Value::Object(head)), Some(Value::Object(current))) => {
+                let mut keys: Vec<&String> = head.keys().chain(current.keys()).collect();
+                keys.sort();
+                keys.dedup();
+                for key in keys {
+                    self.collect(format!("{path}/{key}"), head.get(key), current.get(key));
+                }
+            }
+            (Some(Value::Array(head)), Some(Value::Array(current))) => {
+                for index in 0..head.len().max(current.len()) {
+                    self.collect(
+                        format!("{path}/{index}"),
+                        head.get(index),
+                        current.get(index),
+                    );
+                }
+            }
+            (Some(head), Some(current)) => {
+                if head != current {
+                    self.changed.push(path);
+                }
+            }
+        }
+    }
+}
+
 impl Component {
     pub async fn get_diff(
         ctx: &DalContext,
@@ -100,6 +165,46 @@ impl Component {
         })
     }
 
+    /// Compares the [`Component's`](Component) properties on _head_ with those found in the
+    /// current [`Visibility`](crate::Visibility), returning the paths that were added, removed or
+    /// changed. Private trees (e.g. "/root/resource") are not compared.
+    pub async fn get_property_diff(
+        ctx: &DalContext,
+        component_id: ComponentId,
+    ) -> ComponentResult<ComponentPropertyDiff> {
+        let mut diff = ComponentPropertyDiff::new(component_id);
+        if ctx.change_set_id() == ctx.get_workspace_default_change_set_id().await? {
+            return Ok(diff);
+        }
+
+        let head_ctx = ctx.clone_with_head().await?;
+        let head = Self::public_properties(&head_ctx, component_id).await?;
+        let current = Self::public_properties(ctx, component_id).await?;
+        diff.collect("/root".to_string(), head.as_ref(), current.as_ref());
+
+        Ok(diff)
+    }
+
+    /// Returns the [`Component's`](Component) properties without private trees, or [`None`] if
+    /// the [`Component`] does not exist.
+    async fn public_properties(
+        ctx: &DalContext,
+        component_id: ComponentId,
+    ) -> ComponentResult<Option<Value>> {
+        let Some(component) = Self::try_get_by_id(ctx, component_id).await? else {
+            return Ok(None);
+        };
+        let properties = match component.view(ctx).await? {
+            Some(view) => {
+                let mut properties = ComponentProperties::try_from(view)?;
+                properties.drop_private();
+                serde_json::to_value(properties)?
+            }
+            None => Value::Null,
+        };
+        Ok(Some(properties))
+    }
+
     pub async fn get_json_representation(
         ctx: &DalContext,
         component_id: ComponentId,
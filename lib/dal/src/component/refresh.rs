@@ -0,0 +1,332 @@
+//! This module contains the ability to schedule recurring refreshes of
+//! [`Components`](crate::Component) via the [`RefreshJob`](crate::job::definition::RefreshJob)
+//! and to record what each refresh changed.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use si_data_pg::{PgError, PgRow};
+use si_events::{ContentHash, FuncRunId};
+use si_id::{ChangeSetId, ComponentId, RefreshScheduleId, RefreshSummaryId, WorkspacePk};
+use telemetry::prelude::*;
+use thiserror::Error;
+
+use crate::{DalContext, TransactionsError};
+
+#[remain::sorted]
+#[derive(Debug, Error)]
+pub enum ComponentRefreshError {
+    #[error("refresh interval must be at least one second, got: {0:?}")]
+    IntervalTooShort(Duration),
+    #[error("pg error: {0}")]
+    Pg(#[from] PgError),
+    #[error("serde json error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+    #[error("transactions error: {0}")]
+    Transactions(#[from] TransactionsError),
+}
+
+pub type ComponentRefreshResult<T> = Result<T, ComponentRefreshError>;
+
+/// A cadence on which a recurring [`RefreshJob`](crate::job::definition::RefreshJob) is enqueued.
+/// Pinga polls for schedules whose next run is due and enqueues a run for each of them, so no job
+/// ever waits out the interval itself. Cancelling the schedule stops any further runs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RefreshSchedule {
+    id: RefreshScheduleId,
+    created_at: DateTime<Utc>,
+    workspace_pk: WorkspacePk,
+    change_set_id: ChangeSetId,
+    component_ids: Vec<ComponentId>,
+    interval_seconds: i64,
+    next_run_at: DateTime<Utc>,
+    cancelled_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<PgRow> for RefreshSchedule {
+    type Error = ComponentRefreshError;
+
+    fn try_from(row: PgRow) -> Result<Self, Self::Error> {
+        let component_ids: serde_json::Value = row.try_get("component_ids")?;
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            workspace_pk: row.try_get("workspace_pk")?,
+            change_set_id: row.try_get("change_set_id")?,
+            component_ids: serde_json::from_value(component_ids)?,
+            interval_seconds: row.try_get("interval_seconds")?,
+            next_run_at: row.try_get("next_run_at")?,
+            cancelled_at: row.try_get("cancelled_at")?,
+        })
+    }
+}
+
+impl RefreshSchedule {
+    /// Creates a schedule to refresh the provided [`Components`](crate::Component) every
+    /// `interval`. The first run is due immediately, so it is enqueued the next time due schedules
+    /// are polled.
+    #[instrument(name = "component.refresh_schedule.new", level = "info", skip(ctx))]
+    pub async fn new(
+        ctx: &DalContext,
+        mut component_ids: Vec<ComponentId>,
+        interval: Duration,
+    ) -> ComponentRefreshResult<Self> {
+        component_ids.sort();
+        component_ids.dedup();
+        if interval.as_secs() == 0 {
+            return Err(ComponentRefreshError::IntervalTooShort(interval));
+        }
+        let interval_seconds = i64::try_from(interval.as_secs()).unwrap_or(i64::MAX);
+        let workspace_pk = ctx.workspace_pk()?;
+        let change_set_id = ctx.change_set_id();
+        let component_ids = serde_json::to_value(component_ids)?;
+
+        let row = ctx
+            .txns()
+            .await?
+            .pg()
+            .query_one(
+                "INSERT INTO refresh_schedules (workspace_pk, change_set_id, component_ids, interval_seconds) VALUES ($1, $2, $3, $4) RETURNING *",
+                &[&workspace_pk, &change_set_id, &component_ids, &interval_seconds],
+            )
+            .await?;
+        Self::try_from(row)
+    }
+
+    pub async fn get_by_id(
+        ctx: &DalContext,
+        id: RefreshScheduleId,
+    ) -> ComponentRefreshResult<Option<Self>> {
+        let maybe_row = ctx
+            .txns()
+            .await?
+            .pg()
+            .query_opt("SELECT * FROM refresh_schedules WHERE id = $1", &[&id])
+            .await?;
+        maybe_row.map(Self::try_from).transpose()
+    }
+
+    /// Claims every uncancelled schedule, across all workspaces, whose next run is due.
+    ///
+    /// Claiming pushes each schedule's next run out by its interval so that concurrent pollers
+    /// do not claim the same run twice. The run itself pushes it out again once it completes (see
+    /// [`Self::push_next_run`]), so a slow run is never overlapped by the next one.
+    #[instrument(
+        name = "component.refresh_schedule.claim_due",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn claim_due(ctx: &DalContext) -> ComponentRefreshResult<Vec<Self>> {
+        let rows = ctx
+            .txns()
+            .await?
+            .pg()
+            .query(
+                "UPDATE refresh_schedules SET next_run_at = CLOCK_TIMESTAMP() + interval_seconds * INTERVAL '1 second' WHERE cancelled_at IS NULL AND next_run_at <= CLOCK_TIMESTAMP() RETURNING *",
+                &[],
+            )
+            .await?;
+        rows.into_iter().map(Self::try_from).collect()
+    }
+
+    /// Sets the next run to one interval from now. Does nothing if the schedule was cancelled.
+    #[instrument(
+        name = "component.refresh_schedule.push_next_run",
+        level = "debug",
+        skip(ctx)
+    )]
+    pub async fn push_next_run(&mut self, ctx: &DalContext) -> ComponentRefreshResult<()> {
+        let maybe_row = ctx
+            .txns()
+            .await?
+            .pg()
+            .query_opt(
+                "UPDATE refresh_schedules SET next_run_at = CLOCK_TIMESTAMP() + interval_seconds * INTERVAL '1 second' WHERE id = $1 AND cancelled_at IS NULL RETURNING next_run_at",
+                &[&self.id],
+            )
+            .await?;
+        if let Some(row) = maybe_row {
+            self.next_run_at = row.try_get("next_run_at")?;
+        }
+        Ok(())
+    }
+
+    /// Cancels the schedule. A [`RefreshJob`](crate::job::definition::RefreshJob) that is already
+    /// in flight will finish its current run, but no further runs will be enqueued.
+    #[instrument(name = "component.refresh_schedule.cancel", level = "info", skip(ctx))]
+    pub async fn cancel(&mut self, ctx: &DalContext) -> ComponentRefreshResult<()> {
+        let row = ctx
+            .txns()
+            .await?
+            .pg()
+            .query_one(
+                "UPDATE refresh_schedules SET cancelled_at = COALESCE(cancelled_at, CLOCK_TIMESTAMP()) WHERE id = $1 RETURNING cancelled_at",
+                &[&self.id],
+            )
+            .await?;
+        self.cancelled_at = row.try_get("cancelled_at")?;
+        Ok(())
+    }
+
+    pub fn id(&self) -> RefreshScheduleId {
+        self.id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn workspace_pk(&self) -> WorkspacePk {
+        self.workspace_pk
+    }
+
+    pub fn change_set_id(&self) -> ChangeSetId {
+        self.change_set_id
+    }
+
+    pub fn component_ids(&self) -> &[ComponentId] {
+        &self.component_ids
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_seconds.max(0) as u64)
+    }
+
+    pub fn next_run_at(&self) -> DateTime<Utc> {
+        self.next_run_at
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled_at.is_some()
+    }
+}
+
+/// A record of a single refresh of a [`Component`](crate::Component)'s resource, noting whether
+/// or not the resource drifted from what was previously known.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshSummary {
+    id: RefreshSummaryId,
+    created_at: DateTime<Utc>,
+    change_set_id: ChangeSetId,
+    component_id: ComponentId,
+    func_run_id: FuncRunId,
+    drifted: bool,
+    previous_resource_hash: Option<String>,
+    new_resource_hash: Option<String>,
+}
+
+impl TryFrom<PgRow> for RefreshSummary {
+    type Error = ComponentRefreshError;
+
+    fn try_from(row: PgRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            created_at: row.try_get("created_at")?,
+            change_set_id: row.try_get("change_set_id")?,
+            component_id: row.try_get("component_id")?,
+            func_run_id: row.try_get("func_run_id")?,
+            drifted: row.try_get("drifted")?,
+            previous_resource_hash: row.try_get("previous_resource_hash")?,
+            new_resource_hash: row.try_get("new_resource_hash")?,
+        })
+    }
+}
+
+impl RefreshSummary {
+    /// Records the outcome of a refresh by comparing the hashes of the previous and new resource
+    /// payloads.
+    #[instrument(name = "component.refresh_summary.new", level = "info", skip_all)]
+    pub async fn new(
+        ctx: &DalContext,
+        component_id: ComponentId,
+        func_run_id: FuncRunId,
+        previous_payload: Option<&serde_json::Value>,
+        new_payload: Option<&serde_json::Value>,
+    ) -> ComponentRefreshResult<Self> {
+        let previous_resource_hash = previous_payload.map(hash_payload).transpose()?;
+        let new_resource_hash = new_payload.map(hash_payload).transpose()?;
+        let drifted = previous_resource_hash != new_resource_hash;
+
+        let workspace_pk = ctx.workspace_pk()?;
+        let change_set_id = ctx.change_set_id();
+
+        let row = ctx
+            .txns()
+            .await?
+            .pg()
+            .query_one(
+                "INSERT INTO refresh_summaries (workspace_pk, change_set_id, component_id, func_run_id, drifted, previous_resource_hash, new_resource_hash) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
+                &[
+                    &workspace_pk,
+                    &change_set_id,
+                    &component_id,
+                    &func_run_id,
+                    &drifted,
+                    &previous_resource_hash,
+                    &new_resource_hash,
+                ],
+            )
+            .await?;
+        Self::try_from(row)
+    }
+
+    /// Lists all [`RefreshSummaries`](RefreshSummary) for a [`Component`](crate::Component)
+    /// recorded in the current change set, most recent first. Refresh actions run on HEAD, so
+    /// that is where the summaries of applied refreshes are found.
+    pub async fn list_for_component(
+        ctx: &DalContext,
+        component_id: ComponentId,
+    ) -> ComponentRefreshResult<Vec<Self>> {
+        let workspace_pk = ctx.workspace_pk()?;
+        let change_set_id = ctx.change_set_id();
+
+        let rows = ctx
+            .txns()
+            .await?
+            .pg()
+            .query(
+                "SELECT * FROM refresh_summaries WHERE workspace_pk = $1 AND change_set_id = $2 AND component_id = $3 ORDER BY created_at DESC",
+                &[&workspace_pk, &change_set_id, &component_id],
+            )
+            .await?;
+        rows.into_iter().map(Self::try_from).collect()
+    }
+
+    pub fn id(&self) -> RefreshSummaryId {
+        self.id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn change_set_id(&self) -> ChangeSetId {
+        self.change_set_id
+    }
+
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+
+    pub fn func_run_id(&self) -> FuncRunId {
+        self.func_run_id
+    }
+
+    pub fn drifted(&self) -> bool {
+        self.drifted
+    }
+
+    pub fn previous_resource_hash(&self) -> Option<&str> {
+        self.previous_resource_hash.as_deref()
+    }
+
+    pub fn new_resource_hash(&self) -> Option<&str> {
+        self.new_resource_hash.as_deref()
+    }
+}
+
+fn hash_payload(payload: &serde_json::Value) -> ComponentRefreshResult<String> {
+    Ok(ContentHash::new(&serde_json::to_vec(payload)?).to_string())
+}
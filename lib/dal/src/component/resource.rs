@@ -1,13 +1,17 @@
 //! This module contains the ability to work with "resources" for [`Components`](crate::Component).
 
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use si_events::{ActionResultState, CasValue, ContentHash};
 
 use veritech_client::{ActionRunResultSuccess, ResourceStatus};
 
 use crate::component::ComponentResult;
-use crate::{Component, ComponentId, DalContext};
+use crate::layer_db_types::ContentTypes;
+use crate::{ChangeSetId, Component, ComponentId, DalContext};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct ResourceData {
@@ -28,6 +32,30 @@ impl ResourceData {
     pub fn set_status(&mut self, status: ResourceStatus) {
         self.status = status;
     }
+
+    /// Writes the payload to the layer db when it is too large for a [`ResourceView`] to return
+    /// inline, so that the view can reference it instead.
+    pub(crate) fn write_oversized_payload(&self, ctx: &DalContext) -> ComponentResult<()> {
+        if let Some(payload) = &self.payload {
+            if is_oversized(payload)? {
+                ctx.layer_db().cas().write(
+                    Arc::new(payload_content(payload.clone())),
+                    None,
+                    ctx.events_tenancy(),
+                    ctx.events_actor(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_oversized(payload: &Value) -> ComponentResult<bool> {
+    Ok(serde_json::to_vec(payload)?.len() > RESOURCE_VIEW_PAYLOAD_SIZE_CAP)
+}
+
+fn payload_content(payload: Value) -> ContentTypes {
+    CasValue::from(payload).into()
 }
 
 impl From<&ActionRunResultSuccess> for ResourceData {
@@ -36,6 +64,9 @@ impl From<&ActionRunResultSuccess> for ResourceData {
     }
 }
 
+/// The largest serialized resource payload, in bytes, that a [`ResourceView`] returns inline.
+pub const RESOURCE_VIEW_PAYLOAD_SIZE_CAP: usize = 256 * 1024;
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ResourceView {
@@ -43,19 +74,51 @@ pub struct ResourceView {
     pub message: Option<String>,
     pub payload: Option<Value>,
     pub last_synced: Option<String>,
+    /// Set when the payload was larger than [`RESOURCE_VIEW_PAYLOAD_SIZE_CAP`] and left out.
+    #[serde(default)]
+    pub truncated: bool,
+    /// Where the full payload can be fetched from with [`ResourceView::get_full_payload`] when
+    /// the view is [`truncated`](Self::truncated).
+    #[serde(default)]
+    pub full_value_ref: Option<ContentHash>,
 }
 
 impl ResourceView {
+    /// Assembles the [`ResourceView`] for a [`Component`]. Payloads over
+    /// [`RESOURCE_VIEW_PAYLOAD_SIZE_CAP`] are replaced with a reference to the copy that
+    /// [`Component::set_resource`] wrote to the layer db, so that large resources do not have to
+    /// be sent in full every time. The reference is computed from the payload; nothing is written.
     pub async fn get_by_component_id(
         ctx: &DalContext,
         component_id: ComponentId,
     ) -> ComponentResult<Self> {
         let component = Component::get_by_id(ctx, component_id).await?;
 
-        let resource = Self::assemble(component.resource(ctx).await?);
+        let mut resource = Self::assemble(component.resource(ctx).await?);
+        if let Some(payload) = resource.payload.take() {
+            if is_oversized(&payload)? {
+                let (bytes, _) = si_layer_cache::db::serialize::to_vec(&payload_content(payload))?;
+                resource.truncated = true;
+                resource.full_value_ref = Some(ContentHash::new(&bytes));
+            } else {
+                resource.payload = Some(payload);
+            }
+        }
         Ok(resource)
     }
 
+    /// Fetches the full payload of a [`truncated`](Self::truncated) [`ResourceView`] by its
+    /// [`full_value_ref`](Self::full_value_ref). Returns [`None`] if the resource was not stored
+    /// through [`Component::set_resource`].
+    pub async fn get_full_payload(
+        ctx: &DalContext,
+        full_value_ref: ContentHash,
+    ) -> ComponentResult<Option<Value>> {
+        let maybe_value: Option<CasValue> =
+            ctx.layer_db().cas().try_read_as(&full_value_ref).await?;
+        Ok(maybe_value.map(Into::into))
+    }
+
     pub fn assemble(maybe_result: Option<ResourceData>) -> Self {
         match maybe_result {
             Some(result) => Self {
@@ -63,13 +126,100 @@ impl ResourceView {
                 message: None,
                 status: Some(result.status),
                 last_synced: Some(result.last_synced.to_string()),
+                truncated: false,
+                full_value_ref: None,
             },
             None => Self {
                 payload: None,
                 message: None,
                 status: None,
                 last_synced: None,
+                truncated: false,
+                full_value_ref: None,
             },
         }
     }
 }
+
+impl Component {
+    /// Rolls the results of the [`Actions`](crate::action::Action) run for a [`Component`] up into
+    /// a single [`ResourceStatus`]. Only actions originating in the given change set are
+    /// considered, and only the latest run of each of them counts. See
+    /// [`aggregate_action_result_states`] for the rollup rules.
+    pub async fn aggregate_action_status(
+        ctx: &DalContext,
+        component_id: ComponentId,
+        change_set_id: ChangeSetId,
+    ) -> ComponentResult<ResourceStatus> {
+        let latest_runs = ctx
+            .layer_db()
+            .func_run()
+            .list_latest_action_runs_for_component(
+                ctx.events_tenancy().workspace_pk,
+                component_id,
+                change_set_id,
+            )
+            .await?;
+
+        Ok(aggregate_action_result_states(
+            latest_runs
+                .iter()
+                .map(|func_run| func_run.action_result_state()),
+        ))
+    }
+}
+
+/// Rolls the results of a set of action runs up into a single [`ResourceStatus`]: an
+/// [`Error`](ResourceStatus::Error) if any run failed, [`Ok`](ResourceStatus::Ok) if every run
+/// succeeded (or there were none), and a [`Warning`](ResourceStatus::Warning) otherwise.
+pub fn aggregate_action_result_states(
+    states: impl IntoIterator<Item = Option<ActionResultState>>,
+) -> ResourceStatus {
+    let mut status = ResourceStatus::Ok;
+    for state in states {
+        match state {
+            Some(ActionResultState::Failure) => return ResourceStatus::Error,
+            Some(ActionResultState::Success) => {}
+            Some(ActionResultState::Unknown) | None => status = ResourceStatus::Warning,
+        }
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_failure_is_an_error() {
+        assert_eq!(
+            ResourceStatus::Error,
+            aggregate_action_result_states([
+                Some(ActionResultState::Success),
+                Some(ActionResultState::Unknown),
+                Some(ActionResultState::Failure),
+                Some(ActionResultState::Success),
+            ])
+        );
+    }
+
+    #[test]
+    fn all_successes_are_ok() {
+        assert_eq!(
+            ResourceStatus::Ok,
+            aggregate_action_result_states([
+                Some(ActionResultState::Success),
+                Some(ActionResultState::Success),
+            ])
+        );
+        assert_eq!(ResourceStatus::Ok, aggregate_action_result_states([]));
+    }
+
+    #[test]
+    fn unknown_results_are_a_warning() {
+        assert_eq!(
+            ResourceStatus::Warning,
+            aggregate_action_result_states([Some(ActionResultState::Success), None])
+        );
+    }
+}
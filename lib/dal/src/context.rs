@@ -41,7 +41,7 @@ use crate::{audit_logging, slow_rt, ChangeSetError, EncryptedSecret, Workspace,
 use crate::{
     change_set::{ChangeSet, ChangeSetId},
     job::{
-        definition::ActionJob,
+        definition::{ActionJob, RefreshJob},
         processor::{JobQueueProcessor, JobQueueProcessorError},
         producer::{BlockingJobError, BlockingJobResult, JobProducer},
         queue::JobQueue,
@@ -352,6 +352,9 @@ pub struct DalContext {
     /// Determines if we should not enqueue dependent value update jobs for attribute updates in
     /// this context. Useful for builtin migrations, since we don't care about attribute values propagation then.
     no_dependent_values: bool,
+    /// Determines if view reads in this context should panic when prior writes have not been
+    /// propagated yet. Only meant to be enabled in tests.
+    assert_consistent_reads: bool,
     /// The workspace snapshot for this context
     workspace_snapshot: Option<Arc<WorkspaceSnapshot>>,
     /// The change set for this context
@@ -362,6 +365,9 @@ pub struct DalContext {
     request_ulid: Option<ulid::Ulid>,
     /// The authentication method used
     authentication_method: AuthenticationMethod,
+    /// Set on the contexts handed to [`Self::with_savepoint`] blocks, which must not commit or
+    /// roll back the transactions they share with the outer context.
+    in_savepoint: bool,
 }
 
 impl DalContext {
@@ -490,6 +496,14 @@ impl DalContext {
             .map_err(Into::into)
     }
 
+    /// Returns an error if this context was handed to a [`Self::with_savepoint`] block.
+    fn ensure_not_in_savepoint(&self) -> TransactionsResult<()> {
+        if self.in_savepoint {
+            return Err(TransactionsError::InSavepoint);
+        }
+        Ok(())
+    }
+
     async fn commit_internal(
         &self,
         maybe_rebase: DelayedRebaseWithReply<'_>,
@@ -564,6 +578,7 @@ impl DalContext {
     /// Consumes all inner transactions and committing all changes made within them.
     #[instrument(name = "context.commit", level = "info", skip_all)]
     pub async fn commit(&self) -> TransactionsResult<()> {
+        self.ensure_not_in_savepoint()?;
         let maybe_rebase = match self.write_current_rebase_batch().await? {
             Some(updates_address) => DelayedRebaseWithReply::WithUpdates {
                 rebaser: self.rebaser(),
@@ -590,6 +605,7 @@ impl DalContext {
 
     #[instrument(name = "context.commit_no_rebase", level = "info", skip_all)]
     pub async fn commit_no_rebase(&self) -> TransactionsResult<()> {
+        self.ensure_not_in_savepoint()?;
         // Since we are not rebasing, we need to write the final message and flush all
         // pending audit logs.
         self.write_audit_log_final_message().await?;
@@ -668,6 +684,39 @@ impl DalContext {
         self.no_dependent_values
     }
 
+    pub fn assert_consistent_reads(&self) -> bool {
+        self.assert_consistent_reads
+    }
+
+    /// Makes view reads in this context panic if they would observe values that prior writes
+    /// have not been propagated to yet. Only meant to be used in tests.
+    pub fn set_assert_consistent_reads(&mut self, assert_consistent_reads: bool) {
+        self.assert_consistent_reads = assert_consistent_reads;
+    }
+
+    /// Panics if consistent reads are being asserted (see [`Self::set_assert_consistent_reads`])
+    /// and the snapshot still has dependent values from prior writes waiting to be processed,
+    /// meaning a read of the given object would not reflect those writes.
+    pub async fn assert_consistent_read(
+        &self,
+        kind: &'static str,
+        id: impl fmt::Display,
+    ) -> Result<(), WorkspaceSnapshotError> {
+        if self.assert_consistent_reads
+            && self
+                .workspace_snapshot()?
+                .has_dependent_value_roots()
+                .await?
+        {
+            panic!(
+                "stale read of {kind} {id}: dependent values from prior writes in this context have \
+                not been processed yet; commit (blocking) and update the snapshot to visibility \
+                before reading"
+            );
+        }
+        Ok(())
+    }
+
     pub fn services_context(&self) -> ServicesContext {
         self.services_context.clone()
     }
@@ -679,6 +728,7 @@ impl DalContext {
     /// Consumes all inner transactions, committing all changes made within them, and
     /// blocks until all queued jobs have reported as finishing.
     pub async fn blocking_commit(&self) -> TransactionsResult<()> {
+        self.ensure_not_in_savepoint()?;
         let maybe_rebase = match self.write_current_rebase_batch().await? {
             Some(updates_address) => DelayedRebaseWithReply::WithUpdates {
                 rebaser: self.rebaser(),
@@ -700,6 +750,7 @@ impl DalContext {
     }
 
     pub async fn blocking_commit_no_rebase(&self) -> TransactionsResult<()> {
+        self.ensure_not_in_savepoint()?;
         self.blocking_commit_internal(DelayedRebaseWithReply::NoUpdates)
             .await?;
         Ok(())
@@ -710,6 +761,7 @@ impl DalContext {
     /// This is equivalent to the transaction's `Drop` implementations, but provides any error
     /// encountered to the caller.
     pub async fn rollback(&self) -> TransactionsResult<()> {
+        self.ensure_not_in_savepoint()?;
         let mut guard = self.conns_state.lock().await;
 
         *guard = guard.take().rollback().await?;
@@ -735,6 +787,12 @@ impl DalContext {
         self.history_actor = access_builder.history_actor;
     }
 
+    /// Updates this context with the id of the request it is serving, which is stamped onto the
+    /// [`FuncRuns`](si_events::FuncRun) it creates.
+    pub fn update_request_ulid(&mut self, request_ulid: Option<ulid::Ulid>) {
+        self.request_ulid = request_ulid;
+    }
+
     /// Runs a block of code with a custom [`Visibility`] DalContext using the same transactions
     pub async fn run_with_visibility<F, Fut, R>(&self, visibility: Visibility, fun: F) -> R
     where
@@ -747,6 +805,69 @@ impl DalContext {
         fun(ctx).await
     }
 
+    /// Runs a block of code inside a PostgreSQL savepoint, using the same transactions. If the
+    /// block returns an error, both the database writes and the [`WorkspaceSnapshot`] changes it
+    /// made are rolled back to the savepoint, leaving everything done before the call intact.
+    ///
+    /// The block must not commit or roll back: that would end the transactions the savepoint lives
+    /// in. The context it is given (and any clone of it) returns
+    /// [`TransactionsError::InSavepoint`] if it tries.
+    ///
+    /// Restoring the snapshot needs a copy of its working copy. Before the first change to the
+    /// snapshot there is no working copy, so nothing is copied and restoring simply reverts to the
+    /// fetched snapshot. Once the snapshot has been changed, the whole working copy graph is
+    /// copied on every call, so avoid calling this in a loop over an edited snapshot.
+    ///
+    /// NATS messages and jobs enqueued by the block are not rolled back.
+    pub async fn with_savepoint<F, Fut, T, E>(&self, fun: F) -> Result<T, E>
+    where
+        F: FnOnce(DalContext) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<TransactionsError>,
+    {
+        let name = format!("dal_savepoint_{}", ulid::Ulid::new()).to_lowercase();
+        let checkpoint = match &self.workspace_snapshot {
+            Some(workspace_snapshot) => Some(workspace_snapshot.checkpoint().await),
+            None => None,
+        };
+
+        self.txns()
+            .await?
+            .pg()
+            .batch_execute(&format!("SAVEPOINT {name}"))
+            .await
+            .map_err(TransactionsError::from)?;
+
+        let mut savepoint_ctx = self.clone();
+        savepoint_ctx.in_savepoint = true;
+
+        match fun(savepoint_ctx).await {
+            Ok(value) => {
+                self.txns()
+                    .await?
+                    .pg()
+                    .batch_execute(&format!("RELEASE SAVEPOINT {name}"))
+                    .await
+                    .map_err(TransactionsError::from)?;
+                Ok(value)
+            }
+            Err(err) => {
+                self.txns()
+                    .await?
+                    .pg()
+                    .batch_execute(&format!("ROLLBACK TO SAVEPOINT {name}"))
+                    .await
+                    .map_err(TransactionsError::from)?;
+                if let (Some(workspace_snapshot), Some(checkpoint)) =
+                    (&self.workspace_snapshot, checkpoint)
+                {
+                    workspace_snapshot.restore_checkpoint(checkpoint).await;
+                }
+                Err(err)
+            }
+        }
+    }
+
     /// Updates this context with a new [`Visibility`].
     pub fn update_visibility_deprecated(&mut self, visibility: Visibility) {
         self.visibility = visibility;
@@ -823,6 +944,11 @@ impl DalContext {
         Ok(())
     }
 
+    pub async fn enqueue_refresh(&self, job: Box<RefreshJob>) -> TransactionsResult<()> {
+        self.txns().await?.job_queue.enqueue_job(job).await;
+        Ok(())
+    }
+
     /// Add the node ids to the workspace snapshot graph and enqueue a dependent values update.
     /// This update will only be run on commit if blocking_commit is used. If commit is used, the
     /// DVU debouncer will run the job. Note that the DVU debouncer might still pick up the job
@@ -1187,6 +1313,8 @@ impl DalContextBuilder {
             history_actor: HistoryActor::SystemInit,
             request_ulid,
             no_dependent_values: self.no_dependent_values,
+            assert_consistent_reads: false,
+            in_savepoint: false,
             workspace_snapshot: None,
             change_set: None,
             event_session_id: EventSessionId::new(),
@@ -1213,6 +1341,8 @@ impl DalContextBuilder {
             history_actor,
             request_ulid,
             no_dependent_values: self.no_dependent_values,
+            assert_consistent_reads: false,
+            in_savepoint: false,
             workspace_snapshot: None,
             change_set: None,
             event_session_id: EventSessionId::new(),
@@ -1239,6 +1369,8 @@ impl DalContextBuilder {
             history_actor: HistoryActor::SystemInit,
             request_ulid,
             no_dependent_values: self.no_dependent_values,
+            assert_consistent_reads: false,
+            in_savepoint: false,
             workspace_snapshot: None,
             change_set: None,
             event_session_id: EventSessionId::new(),
@@ -1266,6 +1398,8 @@ impl DalContextBuilder {
             request_ulid: access_builder.request_ulid,
             visibility: Visibility::new_head_fake(),
             no_dependent_values: self.no_dependent_values,
+            assert_consistent_reads: false,
+            in_savepoint: false,
             workspace_snapshot: None,
             change_set: None,
             event_session_id: EventSessionId::new(),
@@ -1296,6 +1430,8 @@ impl DalContextBuilder {
             history_actor: request_context.history_actor,
             request_ulid: request_context.request_ulid,
             no_dependent_values: self.no_dependent_values,
+            assert_consistent_reads: false,
+            in_savepoint: false,
             workspace_snapshot: None,
             change_set: None,
             event_session_id: EventSessionId::new(),
@@ -1383,6 +1519,8 @@ pub enum TransactionsError {
     ChangeSet(#[from] Box<ChangeSetError>),
     #[error("change set not set on DalContext")]
     ChangeSetNotSet,
+    #[error("cannot commit or roll back inside DalContext::with_savepoint")]
+    InSavepoint,
     #[error("job queue processor error: {0}")]
     JobQueueProcessor(#[from] JobQueueProcessorError),
     #[error("tokio join error: {0}")]
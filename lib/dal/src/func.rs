@@ -1,14 +1,18 @@
 use argument::{FuncArgument, FuncArgumentError};
 use authoring::{FuncAuthoringClient, FuncAuthoringError};
 use base64::{engine::general_purpose, Engine};
-use binding::{FuncBinding, FuncBindingError};
+use binding::{
+    action::ActionBinding, attribute::AttributeBinding, authentication::AuthBinding,
+    leaf::LeafBinding, management::ManagementBinding, EventualParent, FuncBinding,
+    FuncBindingError,
+};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use si_events::CasValue;
 use si_events::{ulid::Ulid, ContentHash};
 use si_frontend_types::FuncSummary;
 use si_pkg::SpecError;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::string::FromUtf8Error;
 use std::sync::Arc;
 use strum::IntoEnumIterator;
@@ -19,15 +23,16 @@ use ulid::Ulid as CoreUlid;
 use crate::change_set::ChangeSetError;
 use crate::func::argument::FuncArgumentId;
 use crate::func::intrinsics::IntrinsicFunc;
-use crate::layer_db_types::{FuncContent, FuncContentV2};
+use crate::layer_db_types::{ContentTypes, FuncContent, FuncContentV2};
 use crate::workspace_snapshot::edge_weight::{EdgeWeightKind, EdgeWeightKindDiscriminants};
 use crate::workspace_snapshot::graph::WorkspaceSnapshotGraphError;
 use crate::workspace_snapshot::node_weight::category_node_weight::CategoryNodeKind;
 use crate::workspace_snapshot::node_weight::{FuncNodeWeight, NodeWeight, NodeWeightError};
 use crate::workspace_snapshot::WorkspaceSnapshotError;
 use crate::{
-    implement_add_edge_to, pkg, ChangeSetId, DalContext, HelperError, Timestamp, TransactionsError,
-    WsEvent, WsEventResult, WsPayload,
+    implement_add_edge_to, pkg, ChangeSetId, ComponentId, DalContext, HelperError, SchemaVariant,
+    SchemaVariantError, SchemaVariantId, Timestamp, TransactionsError, WsEvent, WsEventResult,
+    WsPayload,
 };
 
 use self::backend::{FuncBackendKind, FuncBackendResponseType};
@@ -65,8 +70,8 @@ pub enum FuncError {
     FuncLocked(FuncId),
     #[error("func name already in use {0}")]
     FuncNameInUse(String),
-    #[error("func to be deleted has bindings: {0}")]
-    FuncToBeDeletedHasBindings(FuncId),
+    #[error("func to be deleted has bindings: {0} (referenced by schema variants: {1:?})")]
+    FuncToBeDeletedHasBindings(FuncId, Vec<SchemaVariantId>),
     #[error("helper error: {0}")]
     Helper(#[from] HelperError),
     #[error("cannot find intrinsic func {0}")]
@@ -79,6 +84,8 @@ pub enum FuncError {
     NodeWeight(#[from] NodeWeightError),
     #[error("si pkg error: {0}")]
     Pkg(#[from] Box<pkg::PkgError>),
+    #[error("schema variant error: {0}")]
+    SchemaVariant(#[from] Box<SchemaVariantError>),
     #[error("pkg error: {0}")]
     SiPkg(#[from] si_pkg::SiPkgError),
     #[error("pkg spec error: {0}")]
@@ -212,20 +219,7 @@ impl Func {
         let _finalized_once = false;
 
         let code_base64: Option<String> = code_base64.map(Into::into);
-        let code_blake3 = if let Some(code) = code_base64.as_ref() {
-            let code_json_value: serde_json::Value = code.clone().into();
-            let code_cas_value: CasValue = code_json_value.into();
-            let (hash, _) = ctx.layer_db().cas().write(
-                Arc::new(code_cas_value.into()),
-                None,
-                ctx.events_tenancy(),
-                ctx.events_actor(),
-            )?;
-            hash
-        } else {
-            // Why are we doing this? Because the struct gods demand it. I have feelings.
-            ContentHash::new("".as_bytes())
-        };
+        let code_blake3 = Self::write_code(ctx, code_base64.as_deref())?;
 
         let content = FuncContentV2 {
             timestamp,
@@ -280,6 +274,42 @@ impl Func {
         .await
     }
 
+    /// The layer db content that `code_blake3` addresses for the given code.
+    fn code_content(code_base64: &str) -> ContentTypes {
+        let code_cas_value: CasValue = serde_json::Value::from(code_base64).into();
+        code_cas_value.into()
+    }
+
+    fn write_code(ctx: &DalContext, code_base64: Option<&str>) -> FuncResult<ContentHash> {
+        Ok(match code_base64 {
+            Some(code) => {
+                ctx.layer_db()
+                    .cas()
+                    .write(
+                        Arc::new(Self::code_content(code)),
+                        None,
+                        ctx.events_tenancy(),
+                        ctx.events_actor(),
+                    )?
+                    .0
+            }
+            // Why are we doing this? Because the struct gods demand it. I have feelings.
+            None => ContentHash::new("".as_bytes()),
+        })
+    }
+
+    /// Recomputes the hash of `code_base64` and checks that it matches the stored `code_blake3`.
+    pub fn verify_code_integrity(&self) -> FuncResult<bool> {
+        let expected = match &self.code_base64 {
+            Some(code) => {
+                let (bytes, _) = si_layer_cache::db::serialize::to_vec(&Self::code_content(code))?;
+                ContentHash::new(&bytes)
+            }
+            None => ContentHash::new("".as_bytes()),
+        };
+        Ok(expected == self.code_blake3)
+    }
+
     pub fn metadata_view(&self) -> FuncMetadataView {
         FuncMetadataView {
             display_name: self
@@ -288,7 +318,7 @@ impl Func {
                 .unwrap_or(self.name.as_str())
                 .into(),
             description: self.description.as_deref().map(Into::into),
-            link: None,
+            link: self.link.as_deref().map(Into::into),
         }
     }
 
@@ -531,7 +561,11 @@ impl Func {
         let mut func = self;
         func.error_if_locked()?;
         let before = FuncContent::from(func.clone());
+        let code_before = func.code_base64.clone();
         lambda(&mut func)?;
+        if func.code_base64 != code_before {
+            func.code_blake3 = Self::write_code(ctx, func.code_base64.as_deref())?;
+        }
 
         let (mut node_weight, _) =
             Self::get_node_weight_and_content_hash_or_error(ctx, func.id).await?;
@@ -564,16 +598,68 @@ impl Func {
         Ok(Self::assemble(&node_weight, updated.extract()))
     }
 
+    /// Lists the [`Components`](crate::Component) affected by a change to the [`Func`]: those
+    /// using a [`SchemaVariant`] the func is bound to, and those the func is bound to directly.
+    pub async fn affected_components(
+        ctx: &DalContext,
+        func_id: FuncId,
+    ) -> FuncResult<Vec<ComponentId>> {
+        let mut component_ids = BTreeSet::new();
+        for binding in FuncBinding::for_func_id(ctx, func_id)
+            .await
+            .map_err(Box::new)?
+        {
+            // Matching every kind keeps a new binding kind from being silently skipped.
+            let eventual_parent = match binding {
+                FuncBinding::Attribute(AttributeBinding {
+                    eventual_parent, ..
+                })
+                | FuncBinding::CodeGeneration(LeafBinding {
+                    eventual_parent, ..
+                })
+                | FuncBinding::Qualification(LeafBinding {
+                    eventual_parent, ..
+                }) => eventual_parent,
+                FuncBinding::Action(ActionBinding {
+                    schema_variant_id, ..
+                })
+                | FuncBinding::Authentication(AuthBinding {
+                    schema_variant_id, ..
+                })
+                | FuncBinding::Management(ManagementBinding {
+                    schema_variant_id, ..
+                }) => EventualParent::SchemaVariant(schema_variant_id),
+            };
+            match eventual_parent {
+                EventualParent::SchemaVariant(schema_variant_id) => component_ids.extend(
+                    SchemaVariant::list_component_ids(ctx, schema_variant_id)
+                        .await
+                        .map_err(Box::new)?,
+                ),
+                EventualParent::Component(component_id) => {
+                    component_ids.insert(component_id);
+                }
+            }
+        }
+        Ok(component_ids.into_iter().collect())
+    }
+
     /// Deletes the [`Func`] and returns the name.
     pub async fn delete_by_id(ctx: &DalContext, id: FuncId) -> FuncResult<String> {
         let func = Self::get_by_id_or_error(ctx, id).await?;
         // Check that we can remove the func.
-        if !FuncBinding::for_func_id(ctx, id)
-            .await
-            .map_err(Box::new)?
-            .is_empty()
-        {
-            return Err(FuncError::FuncToBeDeletedHasBindings(id));
+        let bindings = FuncBinding::for_func_id(ctx, id).await.map_err(Box::new)?;
+        if !bindings.is_empty() {
+            let mut referencing_schema_variant_ids: Vec<SchemaVariantId> = bindings
+                .iter()
+                .filter_map(FuncBinding::get_schema_variant)
+                .collect();
+            referencing_schema_variant_ids.sort();
+            referencing_schema_variant_ids.dedup();
+            return Err(FuncError::FuncToBeDeletedHasBindings(
+                id,
+                referencing_schema_variant_ids,
+            ));
         }
 
         // Now, we can remove the func.
@@ -650,7 +736,8 @@ impl Func {
         Ok(pruned_funcs)
     }
 
-    /// List all [`Funcs`](Func) corresponding to the provided [`FuncIds`](Func).
+    /// List all [`Funcs`](Func) corresponding to the provided [`FuncIds`](Func), in the same
+    /// order, with a single read from the layer db.
     pub async fn list_from_ids(ctx: &DalContext, func_ids: &[FuncId]) -> FuncResult<Vec<Self>> {
         let workspace_snapshot = ctx.workspace_snapshot()?;
 
@@ -714,19 +801,33 @@ impl Func {
         )
         .await?;
 
+        self.copy_arguments_to(ctx, new_func.id).await?;
+        Ok(new_func)
+    }
+
+    /// Like [`Self::clone_func_with_new_name`], but also recreates every [`FuncArgument`] on the
+    /// new func so that it keeps the same argument definitions.
+    pub async fn duplicate_with_arguments(
+        &self,
+        ctx: &DalContext,
+        new_name: String,
+    ) -> FuncResult<Self> {
+        let duplicated_func = self.clone_func_with_new_name(ctx, new_name).await?;
+        self.copy_arguments_to(ctx, duplicated_func.id).await?;
+        Ok(duplicated_func)
+    }
+
+    async fn copy_arguments_to(&self, ctx: &DalContext, new_func_id: FuncId) -> FuncResult<()> {
         for arg in FuncArgument::list_for_func(ctx, self.id)
             .await
             .map_err(Box::new)?
         {
             // create new func args for the new func
-            FuncArgument::new(ctx, arg.name, arg.kind, arg.element_kind, new_func.id)
+            FuncArgument::new(ctx, arg.name, arg.kind, arg.element_kind, new_func_id)
                 .await
                 .map_err(Box::new)?;
         }
-        FuncArgument::list_for_func(ctx, new_func.id)
-            .await
-            .map_err(Box::new)?;
-        Ok(new_func)
+        Ok(())
     }
 
     pub async fn clone_func_with_new_name(
@@ -31,6 +31,10 @@ pub enum FuncArgumentError {
     AttributePrototypeArgument(#[from] Box<AttributePrototypeArgumentError>),
     #[error("change set error: {0}")]
     ChangeSet(#[from] ChangeSetError),
+    #[error("func {func_id} already has an argument named {name}")]
+    DuplicateName { func_id: FuncId, name: String },
+    #[error("func argument element kind {1} is only valid for array or map kinds, not {0}")]
+    ElementKindRequiresCollection(FuncArgumentKind, FuncArgumentKind),
     #[error("unable to create func argument with empty name")]
     EmptyNameDuringCreation,
     #[error("func error: {0}")]
@@ -232,6 +236,13 @@ impl FuncArgument {
         if name.is_empty() {
             return Err(FuncArgumentError::EmptyNameDuringCreation);
         }
+        Self::validate_element_kind(kind, element_kind)?;
+        if Self::find_by_name_for_func(ctx, &name, func_id)
+            .await?
+            .is_some()
+        {
+            return Err(FuncArgumentError::DuplicateName { func_id, name });
+        }
 
         let timestamp = Timestamp::now();
 
@@ -263,6 +274,21 @@ impl FuncArgument {
         Ok(FuncArgument::assemble(&func_argument_node_weight, &content))
     }
 
+    /// Ensures an element kind is only provided for kinds that have elements, i.e.
+    /// [`Array`](FuncArgumentKind::Array) and [`Map`](FuncArgumentKind::Map).
+    fn validate_element_kind(
+        kind: FuncArgumentKind,
+        element_kind: Option<FuncArgumentKind>,
+    ) -> FuncArgumentResult<()> {
+        match (kind, element_kind) {
+            (FuncArgumentKind::Array | FuncArgumentKind::Map, _) | (_, None) => Ok(()),
+            (kind, Some(element_kind)) => Err(FuncArgumentError::ElementKindRequiresCollection(
+                kind,
+                element_kind,
+            )),
+        }
+    }
+
     pub async fn get_by_id(
         ctx: &DalContext,
         id: FuncArgumentId,
@@ -400,6 +426,17 @@ impl FuncArgument {
         Ok(func_args)
     }
 
+    /// Like [`Self::list_for_func`], but sorted by name so that listings are the same across
+    /// calls. Argument names are unique for a given [`Func`], so no two arguments compare equal.
+    pub async fn list_for_func_ordered(
+        ctx: &DalContext,
+        func_id: FuncId,
+    ) -> FuncArgumentResult<Vec<Self>> {
+        let mut func_args = Self::list_for_func(ctx, func_id).await?;
+        func_args.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(func_args)
+    }
+
     /// Find the [`FuncArgument`] by its name for a given [`Func`]. For a given [`Func`], all argument names must be
     /// unique. This method returns `None` if no argument was found.
     pub async fn find_by_name_for_func(
@@ -439,6 +476,7 @@ impl FuncArgument {
 
         let before = FuncArgumentContentV1::from(func_argument.clone());
         lambda(&mut func_argument)?;
+        Self::validate_element_kind(func_argument.kind, func_argument.element_kind)?;
 
         let (mut node_weight, _) =
             FuncArgument::get_node_weight_and_content_hash_or_error(ctx, func_argument.id).await?;
@@ -509,13 +547,12 @@ impl FuncArgument {
     pub async fn remove(ctx: &DalContext, id: FuncArgumentId) -> FuncArgumentResult<()> {
         // If a func argument is to be deleted, we need to remove all attribute prototype
         // arguments that use it first.
-        for attribute_prototype_argument_id in
-            Self::list_attribute_prototype_argument_ids(ctx, id).await?
-        {
-            AttributePrototypeArgument::remove(ctx, attribute_prototype_argument_id)
-                .await
-                .map_err(Box::new)?;
-        }
+        AttributePrototypeArgument::remove_many(
+            ctx,
+            Self::list_attribute_prototype_argument_ids(ctx, id).await?,
+        )
+        .await
+        .map_err(Box::new)?;
 
         // Now, we can remove the argument.
         ctx.workspace_snapshot()?.remove_node_by_id(id).await?;
@@ -344,7 +344,7 @@ impl FuncAssociations {
         ctx: &DalContext,
         func_id: FuncId,
     ) -> FuncAssociationsResult<Vec<LeafInputLocation>> {
-        Ok(FuncArgument::list_for_func(ctx, func_id)
+        Ok(FuncArgument::list_for_func_ordered(ctx, func_id)
             .await?
             .iter()
             .filter_map(|arg| LeafInputLocation::maybe_from_arg_name(&arg.name))
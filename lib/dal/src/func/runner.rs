@@ -1,6 +1,8 @@
 use serde_json;
 use std::collections::VecDeque;
-use std::sync::Arc;
+use std::fmt::Display;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use ulid::Ulid;
 
 use chrono::Utc;
@@ -11,8 +13,9 @@ use si_events::{
 };
 use si_layer_cache::LayerDbError;
 use telemetry::prelude::*;
+use telemetry_utils::metric;
 use thiserror::Error;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, AcquireError, OwnedSemaphorePermit, Semaphore};
 use veritech_client::{
     encrypt_value_tree, BeforeFunction, FunctionResult, FunctionResultFailure,
     FunctionResultFailureErrorKind, KillExecutionRequest, OutputStream, ResolverFunctionComponent,
@@ -93,6 +96,8 @@ pub enum FuncRunnerError {
     EmptyValueSource(AttributePrototypeArgumentId),
     #[error("empty widget options for secret prop id: {0}")]
     EmptyWidgetOptionsForSecretProp(PropId),
+    #[error("unable to acquire func execution permit: {0}")]
+    ExecutionPermit(#[from] AcquireError),
     #[error("func error: {0}")]
     Func(#[from] FuncError),
     #[error("function backend error: {0}")]
@@ -154,6 +159,68 @@ pub type FuncRunnerResult<T> = Result<T, FuncRunnerError>;
 
 pub type FuncRunnerValueChannel = oneshot::Receiver<FuncRunnerResult<FuncRunValue>>;
 
+/// The number of funcs a process may have executing at once unless
+/// [`set_max_concurrent_func_executions`] is called first.
+pub const DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS: usize = 256;
+
+static FUNC_EXECUTION_LIMITER: OnceLock<FuncExecutionLimiter> = OnceLock::new();
+
+/// Sets how many funcs this process may have executing at once; funcs beyond the limit wait for
+/// a running one to finish. This must be called before the first func runs and returns `false`
+/// if the limit has already been set.
+pub fn set_max_concurrent_func_executions(limit: usize) -> bool {
+    FUNC_EXECUTION_LIMITER
+        .set(FuncExecutionLimiter::new(limit))
+        .is_ok()
+}
+
+/// Returns how many funcs this process may have executing at once.
+pub fn max_concurrent_func_executions() -> usize {
+    func_execution_limiter().limit
+}
+
+/// Returns how many funcs this process currently has executing.
+pub fn func_executions_in_flight() -> usize {
+    let limiter = func_execution_limiter();
+    limiter.limit - limiter.permits.available_permits()
+}
+
+fn func_execution_limiter() -> &'static FuncExecutionLimiter {
+    FUNC_EXECUTION_LIMITER
+        .get_or_init(|| FuncExecutionLimiter::new(DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS))
+}
+
+/// Bounds the number of funcs dispatched to veritech at once.
+#[derive(Clone, Debug)]
+struct FuncExecutionLimiter {
+    limit: usize,
+    permits: Arc<Semaphore>,
+}
+
+impl FuncExecutionLimiter {
+    fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            limit,
+            permits: Arc::new(Semaphore::new(limit)),
+        }
+    }
+
+    /// Waits for an execution slot, which is released when the returned permit is dropped.
+    async fn acquire(&self) -> FuncRunnerResult<OwnedSemaphorePermit> {
+        let wait_started_at = Instant::now();
+        metric!(counter.func_runner.execution.queued = 1);
+        let permit = self.permits.clone().acquire_owned().await;
+        metric!(counter.func_runner.execution.queued = -1);
+        metric!(
+            histogram.func_runner.execution.permit_wait_ms =
+                wait_started_at.elapsed().as_millis() as u64
+        );
+
+        Ok(permit?)
+    }
+}
+
 pub struct FuncRunner {
     func_run: Arc<FuncRun>,
 
@@ -269,15 +336,7 @@ impl FuncRunner {
 
             let func_run = Arc::new(func_run_inner);
 
-            ctx.layer_db()
-                .func_run()
-                .write(
-                    func_run.clone(),
-                    None,
-                    ctx.events_tenancy(),
-                    ctx.events_actor(),
-                )
-                .await?;
+            FuncRunner::write_new_func_run(ctx, func_run.clone()).await?;
 
             Ok(FuncRunner {
                 func_run,
@@ -326,7 +385,7 @@ impl FuncRunner {
     pub async fn run_asset_definition_func(
         ctx: &DalContext,
         func: &Func,
-    ) -> FuncRunnerResult<FuncRunnerValueChannel> {
+    ) -> FuncRunnerResult<(FuncRunId, FuncRunnerValueChannel)> {
         let span = current_span_for_instrument_at!("debug");
 
         // Prepares the function for execution.
@@ -416,15 +475,7 @@ impl FuncRunner {
 
             let func_run = Arc::new(func_run_inner);
 
-            ctx.layer_db()
-                .func_run()
-                .write(
-                    func_run.clone(),
-                    None,
-                    ctx.events_tenancy(),
-                    ctx.events_actor(),
-                )
-                .await?;
+            FuncRunner::write_new_func_run(ctx, func_run.clone()).await?;
 
             Ok(FuncRunner {
                 func_run,
@@ -438,9 +489,10 @@ impl FuncRunner {
             .await
             .map_err(|err| span.record_err(err))?;
 
+        let func_run_id = runner.id();
         let result_channel = runner.execute(ctx.clone(), span).await;
 
-        Ok(result_channel)
+        Ok((func_run_id, result_channel))
     }
 
     #[instrument(
@@ -591,15 +643,7 @@ impl FuncRunner {
 
             let func_run = Arc::new(func_run_inner);
 
-            ctx.layer_db()
-                .func_run()
-                .write(
-                    func_run.clone(),
-                    None,
-                    ctx.events_tenancy(),
-                    ctx.events_actor(),
-                )
-                .await?;
+            FuncRunner::write_new_func_run(ctx, func_run.clone()).await?;
 
             Ok(FuncRunner {
                 func_run,
@@ -759,15 +803,7 @@ impl FuncRunner {
             let func_run = Arc::new(func_run_inner);
 
             if !func.is_intrinsic() {
-                ctx.layer_db()
-                    .func_run()
-                    .write(
-                        func_run.clone(),
-                        None,
-                        ctx.events_tenancy(),
-                        ctx.events_actor(),
-                    )
-                    .await?;
+                FuncRunner::write_new_func_run(ctx, func_run.clone()).await?;
             }
 
             Ok(FuncRunner {
@@ -938,15 +974,7 @@ impl FuncRunner {
 
             let func_run = Arc::new(func_run_inner);
 
-            ctx.layer_db()
-                .func_run()
-                .write(
-                    func_run.clone(),
-                    None,
-                    ctx.events_tenancy(),
-                    ctx.events_actor(),
-                )
-                .await?;
+            FuncRunner::write_new_func_run(ctx, func_run.clone()).await?;
 
             Ok(FuncRunner {
                 func_run,
@@ -1155,15 +1183,7 @@ impl FuncRunner {
 
             let func_run = Arc::new(func_run_inner);
 
-            ctx.layer_db()
-                .func_run()
-                .write(
-                    func_run.clone(),
-                    None,
-                    ctx.events_tenancy(),
-                    ctx.events_actor(),
-                )
-                .await?;
+            FuncRunner::write_new_func_run(ctx, func_run.clone()).await?;
 
             Ok(FuncRunner {
                 func_run,
@@ -1203,6 +1223,33 @@ impl FuncRunner {
             return Err(FuncRunnerError::DoNotHavePermissionToKillExecution);
         }
 
+        Self::request_kill_execution(ctx, func_run_id).await?;
+        info!(%func_run_id, "kill execution success");
+
+        // NOTE(nick): why are we doing this here? Why aren't we returning a result channel? Well, Victor and I
+        // did that originally, but we learned that most other func runner methods are abstracted out by
+        // another dal entity. Those entities are responsible for "stamping" the func run to a terminating
+        // state. For cancellation, there is no other entity. We need to do that here. Because of that, we also
+        // need to know what the result of the cancellation request was. Therefore, this entire operation is
+        // blocking and we do not return a result channel.
+        ctx.layer_db()
+            .func_run()
+            .set_state_to_killed(func_run_id, ctx.events_tenancy(), ctx.events_actor())
+            .await?;
+
+        // NOTE(nick): we may need to consider action result state as well as other fields on the func run
+        // struct. This will require more testing and investigation. For now, I think what we have will
+        // suffice... oh words, do not haunt me.
+        Ok(())
+    }
+
+    /// Asks veritech to kill the execution without checking permissions or stamping the
+    /// [`FuncRun`]. Callers that abandon an execution on their own (e.g. on a timeout) use this to
+    /// free up the execution and are responsible for the func run's final state.
+    pub(crate) async fn request_kill_execution(
+        ctx: &DalContext,
+        func_run_id: FuncRunId,
+    ) -> FuncRunnerResult<()> {
         let result = ctx
             .veritech()
             .kill_execution(&KillExecutionRequest {
@@ -1211,29 +1258,31 @@ impl FuncRunner {
             .await;
 
         match result? {
-            FunctionResult::Success(_) => {
-                info!(%func_run_id, "kill execution success");
-
-                // NOTE(nick): why are we doing this here? Why aren't we returning a result channel? Well, Victor and I
-                // did that originally, but we learned that most other func runner methods are abstracted out by
-                // another dal entity. Those entities are responsible for "stamping" the func run to a terminating
-                // state. For cancellation, there is no other entity. We need to do that here. Because of that, we also
-                // need to know what the result of the cancellation request was. Therefore, this entire operation is
-                // blocking and we do not return a result channel.
-                ctx.layer_db()
-                    .func_run()
-                    .set_state_to_killed(func_run_id, ctx.events_tenancy(), ctx.events_actor())
-                    .await?;
-
-                // NOTE(nick): we may need to consider action result state as well as other fields on the func run
-                // struct. This will require more testing and investigation. For now, I think what we have will
-                // suffice... oh words, do not haunt me.
-                Ok(())
-            }
+            FunctionResult::Success(_) => Ok(()),
             FunctionResult::Failure(err) => Err(FuncRunnerError::KillExecutionFailure(err)),
         }
     }
 
+    /// Writes a newly built [`FuncRun`] and records the id of the request that caused it (if the
+    /// context is serving one).
+    async fn write_new_func_run(ctx: &DalContext, func_run: Arc<FuncRun>) -> FuncRunnerResult<()> {
+        let func_run_id = func_run.id();
+        let workspace_pk = func_run.tenancy().workspace_pk;
+        ctx.layer_db()
+            .func_run()
+            .write(func_run, None, ctx.events_tenancy(), ctx.events_actor())
+            .await?;
+
+        if let Some(request_ulid) = ctx.request_ulid() {
+            ctx.layer_db()
+                .func_run()
+                .record_request_ulid(func_run_id, workspace_pk, request_ulid)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     fn id(&self) -> FuncRunId {
         self.func_run.id()
     }
@@ -1662,6 +1711,14 @@ impl FuncRunnerExecutionTask {
                 .await?;
         }
 
+        // Intrinsics run in-process, so only funcs that go out to veritech need a slot.
+        let _execution_permit = if self.func.is_intrinsic() {
+            None
+        } else {
+            Some(func_execution_limiter().acquire().await?)
+        };
+
+        let execution_started_at = Instant::now();
         let execution_result = match self.func_run.backend_kind().into() {
             FuncBackendKind::JsAction => {
                 FuncBackendJsAction::create_and_execute(
@@ -1781,6 +1838,13 @@ impl FuncRunnerExecutionTask {
             }
         };
 
+        ExecutionMetrics::new(
+            self.func_run.backend_kind(),
+            &execution_result,
+            execution_started_at.elapsed(),
+        )
+        .record();
+
         match execution_result {
             Ok((mut unprocessed_value, mut value)) => {
                 // We so sorry - this is the way that the old code
@@ -1883,6 +1947,56 @@ impl FuncRunnerExecutionTask {
     }
 }
 
+/// Whether a func execution succeeded, as reported by the func runner execution metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExecutionOutcome {
+    Failure,
+    Success,
+}
+
+/// The metrics recorded for a single func execution. Each is labelled with the func backend kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExecutionMetrics {
+    label: String,
+    outcome: ExecutionOutcome,
+    duration_ms: u64,
+}
+
+impl ExecutionMetrics {
+    fn new<T, E>(backend_kind: impl Display, result: &Result<T, E>, elapsed: Duration) -> Self {
+        Self {
+            label: backend_kind.to_string(),
+            outcome: match result {
+                Ok(_) => ExecutionOutcome::Success,
+                Err(_) => ExecutionOutcome::Failure,
+            },
+            duration_ms: elapsed.as_millis() as u64,
+        }
+    }
+
+    fn record(&self) {
+        let label = self.label.as_str();
+        match self.outcome {
+            ExecutionOutcome::Success => {
+                metric!(
+                    monotonic_counter.func_runner.execution.success = 1,
+                    label = label
+                );
+            }
+            ExecutionOutcome::Failure => {
+                metric!(
+                    monotonic_counter.func_runner.execution.failure = 1,
+                    label = label
+                );
+            }
+        }
+        metric!(
+            histogram.func_runner.execution.duration_ms = self.duration_ms,
+            label = label
+        );
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct FuncRunLogUpdatedPayload {
@@ -1909,3 +2023,171 @@ impl WsEvent {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use si_events::FuncBackendKind;
+    use telemetry::tracing::{
+        self,
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use super::{ExecutionMetrics, ExecutionOutcome, FuncExecutionLimiter};
+
+    type RecordedMetric = BTreeMap<String, String>;
+
+    /// A subscriber that keeps the fields of every metric event.
+    #[derive(Clone, Default)]
+    struct MetricRecorder(Arc<Mutex<Vec<RecordedMetric>>>);
+
+    impl Subscriber for MetricRecorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = FieldRecorder::default();
+            event.record(&mut fields);
+            if fields.0.get("metrics").map(String::as_str) == Some("true") {
+                self.0.lock().expect("poisoned lock").push(fields.0);
+            }
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    #[derive(Default)]
+    struct FieldRecorder(RecordedMetric);
+
+    impl Visit for FieldRecorder {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_owned(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_owned(), format!("{value:?}"));
+        }
+    }
+
+    fn recorded_metrics(metrics: &ExecutionMetrics) -> Vec<RecordedMetric> {
+        let recorder = MetricRecorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || metrics.record());
+        let recorded = recorder.0.lock().expect("poisoned lock").clone();
+        recorded
+    }
+
+    fn metric(name: &str, value: &str, label: &str) -> RecordedMetric {
+        [("metrics", "true"), (name, value), ("label", label)]
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn execution_metrics_are_labelled_with_the_backend_kind() {
+        let metrics = ExecutionMetrics::new(
+            FuncBackendKind::JsAction,
+            &Ok::<_, ()>(()),
+            Duration::from_millis(1500),
+        );
+
+        assert_eq!(
+            ExecutionMetrics {
+                label: "JsAction".to_string(),
+                outcome: ExecutionOutcome::Success,
+                duration_ms: 1500,
+            },
+            metrics
+        );
+        assert_eq!(
+            vec![
+                metric(
+                    "monotonic_counter.func_runner.execution.success",
+                    "1",
+                    "JsAction"
+                ),
+                metric(
+                    "histogram.func_runner.execution.duration_ms",
+                    "1500",
+                    "JsAction"
+                ),
+            ],
+            recorded_metrics(&metrics)
+        );
+    }
+
+    #[test]
+    fn failed_executions_are_recorded_as_failures() {
+        let metrics = ExecutionMetrics::new(
+            FuncBackendKind::JsAttribute,
+            &Err::<(), _>("boom"),
+            Duration::from_micros(2500),
+        );
+
+        assert_eq!(ExecutionOutcome::Failure, metrics.outcome);
+        assert_eq!("JsAttribute", metrics.label);
+        assert_eq!(2, metrics.duration_ms);
+        assert_eq!(
+            vec![
+                metric(
+                    "monotonic_counter.func_runner.execution.failure",
+                    "1",
+                    "JsAttribute"
+                ),
+                metric(
+                    "histogram.func_runner.execution.duration_ms",
+                    "2",
+                    "JsAttribute"
+                ),
+            ],
+            recorded_metrics(&metrics)
+        );
+    }
+
+    #[tokio::test]
+    async fn limiter_bounds_concurrent_executions() {
+        let limit = 3;
+        let limiter = FuncExecutionLimiter::new(limit);
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let completed = Arc::new(AtomicUsize::new(0));
+
+        let mut handles = Vec::new();
+        for _ in 0..20 {
+            let limiter = limiter.clone();
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            let completed = completed.clone();
+            handles.push(tokio::spawn(async move {
+                let _permit = limiter.acquire().await.expect("could not acquire permit");
+                let now_in_flight = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now_in_flight, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                completed.fetch_add(1, Ordering::SeqCst);
+            }));
+        }
+        for handle in handles {
+            handle.await.expect("task panicked");
+        }
+
+        assert!(max_in_flight.load(Ordering::SeqCst) <= limit);
+        assert_eq!(20, completed.load(Ordering::SeqCst));
+    }
+}
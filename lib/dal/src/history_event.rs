@@ -13,6 +13,10 @@ use crate::{Tenancy, TransactionsError};
 const SYSTEMINIT_EMAIL_SUFFIX: &str = "@systeminit.com";
 const TEST_SYSTEMINIT_EMAIL_SUFFIX: &str = "@test.systeminit.com";
 
+/// The email recorded for work done by [`HistoryActor::SystemInit`], such as the `created_by` of
+/// packages built while authoring a variant outside of a user request.
+pub const SYSTEM_INIT_ACTOR_EMAIL: &str = "sally@systeminit.com";
+
 #[remain::sorted]
 #[derive(Error, Debug)]
 pub enum HistoryEventError {
@@ -49,7 +53,7 @@ impl HistoryActor {
 
     pub async fn email(&self, ctx: &DalContext) -> HistoryEventResult<String> {
         Ok(match self {
-            HistoryActor::SystemInit => "sally@systeminit.com".to_string(),
+            HistoryActor::SystemInit => SYSTEM_INIT_ACTOR_EMAIL.to_string(),
             HistoryActor::User(user_pk) => User::get_by_pk_or_error(ctx, *user_pk)
                 .await
                 .map_err(|e| HistoryEventError::User(e.to_string()))?
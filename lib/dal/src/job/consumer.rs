@@ -12,6 +12,7 @@ use thiserror::Error;
 use tokio::task::JoinError;
 
 use crate::billing_publish::BillingPublishError;
+use crate::component::refresh::ComponentRefreshError;
 use crate::diagram::DiagramError;
 use crate::prop::PropError;
 use crate::validation::ValidationError;
@@ -49,6 +50,8 @@ pub enum JobConsumerError {
     Component(#[from] ComponentError),
     #[error("component {0} is destroyed")]
     ComponentIsDestroyed(ComponentId),
+    #[error("component refresh error: {0}")]
+    ComponentRefresh(#[from] ComponentRefreshError),
     #[error("dependent value update error: {0}")]
     DependentValueUpdate(#[from] DependentValueUpdateError),
     #[error("diagram error: {0}")]
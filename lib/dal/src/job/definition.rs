@@ -1,9 +1,11 @@
 mod action;
 pub mod compute_validation;
 pub mod dependent_values_update;
+pub mod refresh;
 
 pub use action::ActionJob;
 pub use dependent_values_update::DependentValuesUpdate;
+pub use refresh::RefreshJob;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum AttributeValueBasedJobIdentifier {
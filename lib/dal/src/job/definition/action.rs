@@ -8,15 +8,16 @@ use serde::{Deserialize, Serialize};
 use si_events::{audit_log::AuditLogKind, ActionResultState, FuncRunId};
 use telemetry::prelude::*;
 use telemetry_utils::metric;
-use veritech_client::{ActionRunResultSuccess, ResourceStatus};
+use veritech_client::ActionRunResultSuccess;
 
 use crate::{
     action::{
-        prototype::{ActionKind, ActionPrototype},
+        prototype::{ActionKind, ActionPrototype, ActionRunFailure},
         Action, ActionError, ActionId, ActionState,
     },
     billing_publish,
     change_status::ChangeStatus,
+    component::refresh::RefreshSummary,
     job::{
         consumer::{
             JobCompletionState, JobConsumer, JobConsumerError, JobConsumerMetadata,
@@ -222,6 +223,20 @@ async fn process_execution(
     let component = Component::get_by_id(ctx, component_id).await?;
     let mut success = false;
     if let Some(run_result) = action_run_result {
+        if prototype.kind == ActionKind::Refresh {
+            let previous_resource = component.resource(ctx).await?;
+            RefreshSummary::new(
+                ctx,
+                component_id,
+                func_run_id,
+                previous_resource
+                    .as_ref()
+                    .and_then(|resource| resource.payload.as_ref()),
+                run_result.payload.as_ref(),
+            )
+            .await?;
+        }
+
         // Set the resource if we have a payload, regardless of status *and* assemble a
         // summary
         if run_result.payload.is_some() {
@@ -238,7 +253,16 @@ async fn process_execution(
             component.set_resource_id(ctx, resource_id.as_str()).await?;
         }
 
-        if run_result.status == ResourceStatus::Ok {
+        if let Some(failure) = ActionRunFailure::from_run_result(run_result) {
+            warn!(
+                si.action.id = %action_id,
+                si.error.message = %failure.reason,
+                action_message = ?failure.message,
+                "action run failed"
+            );
+            failure.record(ctx, func_run_id).await?;
+            Action::set_state(ctx, action_id, ActionState::Failed).await?;
+        } else {
             success = true;
 
             // Remove `ActionId` from graph as the execution succeeded
@@ -291,9 +315,6 @@ async fn process_execution(
             for dependency_prototype_id in triggered_prototypes {
                 Action::new(ctx, dependency_prototype_id, Some(component_id)).await?;
             }
-        } else {
-            // If status is not ok, set action state to failed
-            Action::set_state(ctx, action_id, ActionState::Failed).await?;
         }
     } else {
         // If the maybe_resource is none, set action state to failed
@@ -0,0 +1,242 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use si_events::AuthenticationMethod;
+use si_id::RefreshScheduleId;
+use telemetry::prelude::*;
+
+use crate::{
+    action::{
+        prototype::{ActionKind, ActionPrototype},
+        Action,
+    },
+    component::refresh::RefreshSchedule,
+    job::{
+        consumer::{
+            JobCompletionState, JobConsumer, JobConsumerError, JobConsumerMetadata,
+            JobConsumerResult, JobInfo,
+        },
+        producer::{JobProducer, JobProducerResult},
+    },
+    AccessBuilder, ChangeSet, ChangeSetStatus, Component, ComponentId, DalContext, HistoryActor,
+    Tenancy, Visibility,
+};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RefreshJobArgs {
+    component_ids: Vec<ComponentId>,
+    #[serde(default)]
+    schedule_id: Option<RefreshScheduleId>,
+}
+
+impl From<RefreshJob> for RefreshJobArgs {
+    fn from(value: RefreshJob) -> Self {
+        Self {
+            component_ids: value.component_ids,
+            schedule_id: value.schedule_id,
+        }
+    }
+}
+
+/// Enqueues a [`Refresh`](ActionKind::Refresh) [`Action`] for each of the provided
+/// [`Components`](Component) that has a refresh [`ActionPrototype`].
+///
+/// Recurring refreshes are created with [`RefreshSchedule::new`]. Pinga enqueues a run whenever
+/// the [`RefreshSchedule`] is due (see [`RefreshJob::enqueue_due`]) and each run pushes the next
+/// one out by the schedule's interval when it completes, until the schedule is cancelled.
+#[derive(Clone, Debug, Serialize)]
+pub struct RefreshJob {
+    component_ids: Vec<ComponentId>,
+    schedule_id: Option<RefreshScheduleId>,
+    access_builder: AccessBuilder,
+    visibility: Visibility,
+    job: Option<JobInfo>,
+}
+
+impl RefreshJob {
+    pub fn new(
+        access_builder: AccessBuilder,
+        visibility: Visibility,
+        mut component_ids: Vec<ComponentId>,
+    ) -> Box<Self> {
+        component_ids.sort();
+        component_ids.dedup();
+
+        Box::new(Self {
+            component_ids,
+            schedule_id: None,
+            access_builder,
+            visibility,
+            job: None,
+        })
+    }
+
+    /// Creates the [`RefreshJob`] for a single run of the provided [`RefreshSchedule`].
+    pub fn for_schedule(schedule: &RefreshSchedule) -> Box<Self> {
+        let access_builder = AccessBuilder::new(
+            Tenancy::new(schedule.workspace_pk()),
+            HistoryActor::SystemInit,
+            None,
+            AuthenticationMethod::System,
+        );
+
+        let mut job = Self::new(
+            access_builder,
+            Visibility::new(schedule.change_set_id()),
+            schedule.component_ids().to_vec(),
+        );
+        job.schedule_id = Some(schedule.id());
+        job
+    }
+
+    /// Claims every [`RefreshSchedule`] that is due and enqueues a run for each of them, returning
+    /// the ids of the schedules that were enqueued. The jobs are dispatched when `ctx` commits.
+    ///
+    /// This does not need `ctx` to have a workspace since each job carries its own tenancy.
+    pub async fn enqueue_due(ctx: &DalContext) -> JobConsumerResult<Vec<RefreshScheduleId>> {
+        let mut enqueued = Vec::new();
+        for schedule in RefreshSchedule::claim_due(ctx).await? {
+            ctx.enqueue_refresh(Self::for_schedule(&schedule)).await?;
+            enqueued.push(schedule.id());
+        }
+        Ok(enqueued)
+    }
+
+    /// Creates a [`RefreshJob`] for every [`Component`] in the workspace, skipping those that are
+    /// marked for deletion.
+    pub async fn new_for_all_components(ctx: &DalContext) -> JobConsumerResult<Box<Self>> {
+        let component_ids = Component::list(ctx)
+            .await?
+            .into_iter()
+            .filter(|component| !component.to_delete())
+            .map(|component| component.id())
+            .collect();
+
+        Ok(Self::new(
+            ctx.access_builder(),
+            *ctx.visibility(),
+            component_ids,
+        ))
+    }
+
+    pub fn component_ids(&self) -> &[ComponentId] {
+        &self.component_ids
+    }
+
+    pub fn schedule_id(&self) -> Option<RefreshScheduleId> {
+        self.schedule_id
+    }
+
+    /// Pushes the next run of a recurring [`RefreshJob`] out by its schedule's interval, returning
+    /// whether or not there will be a next run. There is none for one-off jobs or when the
+    /// [`RefreshSchedule`] has been cancelled.
+    pub async fn reschedule(&self, ctx: &DalContext) -> JobConsumerResult<bool> {
+        let Some(mut schedule) = self.active_schedule(ctx).await? else {
+            return Ok(false);
+        };
+
+        schedule.push_next_run(ctx).await?;
+        Ok(true)
+    }
+
+    async fn active_schedule(
+        &self,
+        ctx: &DalContext,
+    ) -> JobConsumerResult<Option<RefreshSchedule>> {
+        let Some(schedule_id) = self.schedule_id else {
+            return Ok(None);
+        };
+
+        Ok(RefreshSchedule::get_by_id(ctx, schedule_id)
+            .await?
+            .filter(|schedule| !schedule.is_cancelled()))
+    }
+}
+
+impl JobProducer for RefreshJob {
+    fn arg(&self) -> JobProducerResult<serde_json::Value> {
+        Ok(serde_json::to_value(RefreshJobArgs::from(self.clone()))?)
+    }
+}
+
+impl JobConsumerMetadata for RefreshJob {
+    fn type_name(&self) -> String {
+        "RefreshJob".to_string()
+    }
+
+    fn access_builder(&self) -> AccessBuilder {
+        self.access_builder
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+}
+
+#[async_trait]
+impl JobConsumer for RefreshJob {
+    #[instrument(
+        name = "refresh_job.run",
+        skip_all,
+        level = "info",
+        fields(
+            component_ids = ?self.component_ids,
+        )
+    )]
+    async fn run(&self, ctx: &mut DalContext) -> JobConsumerResult<JobCompletionState> {
+        if self.schedule_id.is_some() {
+            let change_set = ChangeSet::get_by_id(ctx, ctx.change_set_id()).await?;
+            if change_set.status == ChangeSetStatus::Abandoned
+                || self.active_schedule(ctx).await?.is_none()
+            {
+                info!("Recurring refresh cancelled or change set abandoned. Returning early");
+                return Ok(JobCompletionState::Done);
+            }
+        }
+
+        for &component_id in &self.component_ids {
+            // Components may have been removed between when the job was created and now.
+            if Component::try_get_by_id(ctx, component_id).await?.is_none() {
+                debug!(%component_id, "component missing, skipping it in RefreshJob");
+                continue;
+            }
+
+            let schema_variant_id = Component::schema_variant_id(ctx, component_id).await?;
+            for prototype in ActionPrototype::for_variant(ctx, schema_variant_id).await? {
+                if prototype.kind != ActionKind::Refresh {
+                    continue;
+                }
+                // An equivalent action means the prior run has not finished yet, so we skip it
+                // rather than overlap.
+                if Action::find_equivalent(ctx, prototype.id(), Some(component_id))
+                    .await?
+                    .is_none()
+                {
+                    Action::new(ctx, prototype.id(), Some(component_id)).await?;
+                }
+            }
+        }
+
+        self.reschedule(ctx).await?;
+
+        ctx.commit().await?;
+        Ok(JobCompletionState::Done)
+    }
+}
+
+impl TryFrom<JobInfo> for RefreshJob {
+    type Error = JobConsumerError;
+
+    fn try_from(job: JobInfo) -> Result<Self, Self::Error> {
+        let args = RefreshJobArgs::deserialize(&job.arg)?;
+
+        Ok(Self {
+            component_ids: args.component_ids,
+            schedule_id: args.schedule_id,
+            access_builder: job.access_builder,
+            visibility: job.visibility,
+            job: Some(job),
+        })
+    }
+}
@@ -0,0 +1,12 @@
+CREATE TABLE refresh_schedules
+(
+    id               ident primary key NOT NULL DEFAULT ident_create_v1(),
+    created_at       timestamp with time zone NOT NULL DEFAULT CLOCK_TIMESTAMP(),
+    workspace_pk     ident NOT NULL,
+    change_set_id    ident NOT NULL,
+    component_ids    jsonb NOT NULL,
+    interval_seconds bigint NOT NULL,
+    next_run_at      timestamp with time zone NOT NULL DEFAULT CLOCK_TIMESTAMP(),
+    cancelled_at     timestamp with time zone NULL
+);
+CREATE INDEX ON refresh_schedules (next_run_at) WHERE cancelled_at IS NULL;
@@ -0,0 +1,13 @@
+CREATE TABLE refresh_summaries
+(
+    id                     ident primary key NOT NULL DEFAULT ident_create_v1(),
+    created_at             timestamp with time zone NOT NULL DEFAULT CLOCK_TIMESTAMP(),
+    workspace_pk           ident NOT NULL,
+    change_set_id          ident NOT NULL,
+    component_id           ident NOT NULL,
+    func_run_id            ident NOT NULL,
+    drifted                boolean NOT NULL,
+    previous_resource_hash text NULL,
+    new_resource_hash      text NULL
+);
+CREATE INDEX ON refresh_summaries (workspace_pk, change_set_id, component_id, created_at);
@@ -0,0 +1 @@
+ALTER TABLE workspace_integrations ADD COLUMN version bigint NOT NULL DEFAULT 0;
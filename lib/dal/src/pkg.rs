@@ -28,7 +28,9 @@ use crate::{AttributePrototypeId, FuncId, HistoryEventError, PropId, PropKind};
 
 use crate::module::ModuleError;
 use crate::socket::connection_annotation::ConnectionAnnotationError;
-pub use import::{import_pkg, import_pkg_from_pkg, ImportOptions};
+pub use import::{
+    import_pkg, import_pkg_from_pkg, ImportOptions, ImportProgress, ImportProgressCallback,
+};
 
 pub mod export;
 pub mod import;
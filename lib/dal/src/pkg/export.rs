@@ -102,51 +102,67 @@ impl PkgExporter {
         ctx: &DalContext,
         schema: &Schema,
     ) -> PkgResult<(SchemaSpec, Vec<FuncSpec>)> {
-        let mut funcs = vec![];
-        let schema_is_builtin = schema.is_builtin();
-
         let default_variant = SchemaVariant::default_for_schema(ctx, schema.id()).await?;
 
-        let mut schema_spec_builder = SchemaSpec::builder();
-        schema_spec_builder.name(schema.name());
-        schema_spec_builder.unique_id(schema.id().to_string());
-
-        let mut default_variant_unique_id = None;
+        let (variant_spec, funcs) = self.export_variant_and_funcs(ctx, &default_variant).await?;
+        let default_variant_unique_id = variant_spec.unique_id.clone();
+        // A variant without a unique id cannot be referenced, so it is left out.
+        let variant_specs = default_variant_unique_id.is_some().then_some(variant_spec);
+        let schema_spec = Self::build_schema_spec(
+            schema,
+            default_variant.category(),
+            default_variant_unique_id,
+            variant_specs,
+        )?;
 
-        let variant_is_builtin = default_variant.is_builtin();
-        let variant_category = default_variant.clone().category().to_owned();
+        Ok((schema_spec, funcs))
+    }
 
-        let variant_funcs = self
-            .export_funcs_for_variant(ctx, default_variant.id(), None)
+    /// Exports a variant along with the funcs it uses and records it in the variant map.
+    async fn export_variant_and_funcs(
+        &mut self,
+        ctx: &DalContext,
+        variant: &SchemaVariant,
+    ) -> PkgResult<(SchemaVariantSpec, Vec<FuncSpec>)> {
+        let funcs = self
+            .export_funcs_for_variant(ctx, variant.id(), None)
             .await?;
-        funcs.extend(variant_funcs);
-
         let variant_spec = self
-            .export_variant(ctx, &default_variant, variant_is_builtin, None)
+            .export_variant(ctx, variant, variant.is_builtin(), None)
             .await?;
         self.variant_map
-            .insert(default_variant.id(), variant_spec.to_owned());
-        if variant_spec.unique_id.is_some() {
-            variant_spec
-                .unique_id
-                .clone_into(&mut default_variant_unique_id);
+            .insert(variant.id(), variant_spec.to_owned());
 
-            schema_spec_builder.variant(variant_spec);
-        }
+        Ok((variant_spec, funcs))
+    }
+
+    /// Builds the [`SchemaSpec`] for a schema from its exported variants.
+    fn build_schema_spec(
+        schema: &Schema,
+        category: impl Into<String>,
+        default_variant_unique_id: Option<String>,
+        variant_specs: impl IntoIterator<Item = SchemaVariantSpec>,
+    ) -> PkgResult<SchemaSpec> {
+        let mut schema_spec_builder = SchemaSpec::builder();
+        schema_spec_builder.name(schema.name());
+        schema_spec_builder.unique_id(schema.id().to_string());
+        schema_spec_builder.is_builtin(schema.is_builtin());
 
         let mut data_builder = SchemaSpecData::builder();
         data_builder.name(schema.name());
         data_builder.ui_hidden(schema.ui_hidden());
-        data_builder.category(variant_category.clone());
+        data_builder.category(category);
+
         if let Some(default_unique_id) = default_variant_unique_id {
             data_builder.default_schema_variant(default_unique_id);
         }
-        schema_spec_builder.data(data_builder.build()?);
-        schema_spec_builder.is_builtin(schema_is_builtin);
+        for variant_spec in variant_specs {
+            schema_spec_builder.variant(variant_spec);
+        }
 
-        let schema_spec = schema_spec_builder.build()?;
+        schema_spec_builder.data(data_builder.build()?);
 
-        Ok((schema_spec, funcs))
+        Ok(schema_spec_builder.build()?)
     }
 
     /// Exports just a single schema variant and the functions connected to it.
@@ -179,6 +195,86 @@ impl PkgExporter {
         Ok((variant_spec, funcs))
     }
 
+    /// Exports a set of schema variants into a single [`SiPkg`]. Variants are grouped under their
+    /// schemas and every func they use, including the intrinsics, appears once in the package.
+    pub async fn export_variants(
+        ctx: &DalContext,
+        name: impl Into<String>,
+        schema_variant_ids: &[SchemaVariantId],
+    ) -> PkgResult<SiPkg> {
+        let mut exporter = Self::new(
+            name,
+            SchemaVariant::generate_version_string(),
+            None::<String>,
+            "",
+            vec![],
+        );
+        exporter.created_by = ctx.history_actor().email(ctx).await?;
+
+        exporter.export_intrinsics(ctx).await?;
+
+        let mut variants_by_schema: Vec<(SchemaId, Vec<SchemaVariant>)> = Vec::new();
+        for &schema_variant_id in schema_variant_ids {
+            let variant = SchemaVariant::get_by_id_or_error(ctx, schema_variant_id).await?;
+            let schema_id =
+                SchemaVariant::schema_id_for_schema_variant_id(ctx, schema_variant_id).await?;
+            match variants_by_schema
+                .iter_mut()
+                .find(|(existing_schema_id, _)| *existing_schema_id == schema_id)
+            {
+                Some((_, variants)) => {
+                    if !variants.iter().any(|v| v.id() == schema_variant_id) {
+                        variants.push(variant);
+                    }
+                }
+                None => variants_by_schema.push((schema_id, vec![variant])),
+            }
+        }
+
+        let mut schema_specs = Vec::with_capacity(variants_by_schema.len());
+        for (schema_id, variants) in variants_by_schema {
+            let schema = Schema::get_by_id_or_error(ctx, schema_id).await?;
+            let default_variant_id = SchemaVariant::default_id_for_schema(ctx, schema_id).await?;
+
+            // Prefer the schema's default variant, falling back to the first one requested.
+            let default_variant = variants
+                .iter()
+                .find(|variant| variant.id() == default_variant_id)
+                .unwrap_or(&variants[0]);
+
+            let mut variant_specs = Vec::with_capacity(variants.len());
+            for variant in &variants {
+                let (variant_spec, _) = exporter.export_variant_and_funcs(ctx, variant).await?;
+                variant_specs.push(variant_spec);
+            }
+
+            schema_specs.push(Self::build_schema_spec(
+                &schema,
+                default_variant.category(),
+                Some(default_variant.id().to_string()),
+                variant_specs,
+            )?);
+        }
+
+        let funcs: Vec<FuncSpec> = exporter
+            .func_map
+            .inner
+            .values()
+            .map(ToOwned::to_owned)
+            .collect();
+
+        let spec = PkgSpec::builder()
+            .name(&exporter.name)
+            .kind(exporter.kind)
+            .version(&exporter.version)
+            .created_by(&exporter.created_by)
+            .funcs(funcs)
+            .schemas(schema_specs)
+            .build()?;
+
+        Ok(SiPkg::load_from_spec(spec)?)
+    }
+
     async fn export_variant(
         &mut self,
         ctx: &DalContext,
@@ -9,6 +9,7 @@ use si_pkg::{
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::{collections::HashMap, path::Path};
 use telemetry::prelude::*;
 use tokio::sync::Mutex;
@@ -26,7 +27,7 @@ use crate::socket::connection_annotation::ConnectionAnnotation;
 use crate::SocketKind;
 use crate::{
     action::prototype::ActionPrototype,
-    func::argument::FuncArgument,
+    func::argument::{FuncArgument, FuncArgumentKind},
     prop::PropPath,
     schema::variant::leaves::{LeafInputLocation, LeafKind},
     DalContext, EdgeWeightKind, Func, FuncId, InputSocket, OutputSocket, OutputSocketId, Prop,
@@ -73,6 +74,37 @@ pub struct ImportOptions {
     /// A list of "past hashes" for this module, used to find the existing
     /// schema if a schema_id is not provided
     pub past_module_hashes: Option<Vec<String>>,
+    /// Called after each schema in the package has been installed.
+    pub progress: Option<ImportProgressCallback>,
+}
+
+/// Reported to an [`ImportProgressCallback`] after a schema has been installed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportProgress {
+    pub schema_name: String,
+    /// The 1-based position of this schema among those being installed.
+    pub index: usize,
+    pub total: usize,
+}
+
+/// A callback invoked with [`ImportProgress`] as [`import_pkg_from_pkg`] installs schemas.
+#[derive(Clone)]
+pub struct ImportProgressCallback(Arc<dyn Fn(ImportProgress) + Send + Sync>);
+
+impl ImportProgressCallback {
+    pub fn new(callback: impl Fn(ImportProgress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    fn report(&self, progress: ImportProgress) {
+        (self.0)(progress)
+    }
+}
+
+impl Debug for ImportProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ImportProgressCallback").finish()
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -204,17 +236,17 @@ async fn import_change_set(
         .iter()
         .cloned()
         .collect();
-    for schema_spec in schemas {
-        let normalized_name = &schema_spec.name().to_string().to_lowercase();
+    let schemas_to_install: Vec<&SiPkgSchema<'_>> = schemas
+        .iter()
+        .filter(|schema_spec| match &options.schemas {
+            None => true,
+            Some(schemas) => schemas.contains(&schema_spec.name().to_lowercase()),
+        })
+        .collect();
+    let total = schemas_to_install.len();
 
-        match &options.schemas {
-            None => {}
-            Some(schemas) => {
-                if !schemas.contains(normalized_name) {
-                    continue;
-                }
-            }
-        }
+    for (index, schema_spec) in schemas_to_install.into_iter().enumerate() {
+        let normalized_name = &schema_spec.name().to_string().to_lowercase();
 
         unseen.remove(normalized_name);
 
@@ -235,6 +267,14 @@ async fn import_change_set(
         .await?;
 
         installed_schema_variant_ids.extend(schema_variant_ids);
+
+        if let Some(progress) = &options.progress {
+            progress.report(ImportProgress {
+                schema_name: schema_spec.name().to_owned(),
+                index: index + 1,
+                total,
+            });
+        }
     }
 
     for schema_name in unseen {
@@ -428,14 +468,26 @@ async fn create_func_argument(
     func_id: FuncId,
     func_arg: &SiPkgFuncArgument<'_>,
 ) -> PkgResult<FuncArgument> {
-    Ok(FuncArgument::new(
-        ctx,
-        func_arg.name(),
-        func_arg.kind().into(),
-        func_arg.element_kind().to_owned().map(|&kind| kind.into()),
-        func_id,
-    )
-    .await?)
+    let kind: FuncArgumentKind = func_arg.kind().into();
+    // Packages built before element kinds were validated may carry one on any kind. Only
+    // collections have elements, so it is dropped for the others rather than failing the import
+    // with `FuncArgumentError::ElementKindRequiresCollection`.
+    let element_kind = match kind {
+        FuncArgumentKind::Array | FuncArgumentKind::Map => {
+            func_arg.element_kind().map(|&kind| kind.into())
+        }
+        _ => {
+            if let Some(element_kind) = func_arg.element_kind() {
+                warn!(
+                    "dropping element kind {element_kind:?} of func argument {} with kind {kind}",
+                    func_arg.name()
+                );
+            }
+            None
+        }
+    };
+
+    Ok(FuncArgument::new(ctx, func_arg.name(), kind, element_kind, func_id).await?)
 }
 
 async fn import_func_arguments(
@@ -531,6 +531,24 @@ impl Schema {
         Ok(Self::list(ctx).await?.iter().any(|s| s.name.eq(name)))
     }
 
+    /// Returns the provided name if no [`Schema`] uses it yet, otherwise the first name of the
+    /// form `{name}-{n}` that is free.
+    pub async fn find_available_name(ctx: &DalContext, name: &str) -> SchemaResult<String> {
+        let taken: HashSet<String> = Self::list(ctx).await?.into_iter().map(|s| s.name).collect();
+        if !taken.contains(name) {
+            return Ok(name.to_owned());
+        }
+
+        let mut suffix = 1;
+        loop {
+            let candidate = format!("{name}-{suffix}");
+            if !taken.contains(&candidate) {
+                return Ok(candidate);
+            }
+            suffix += 1;
+        }
+    }
+
     /// Returns the default [`SchemaVariantId`] for the provided [`SchemaId`]
     /// *if* this schema is installed. If this schema is not installed, it looks
     /// for it in the local module cache, and if it exists there, it installs it, then
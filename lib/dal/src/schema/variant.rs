@@ -76,6 +76,7 @@ pub mod leaves;
 mod metadata_view;
 pub mod root_prop;
 mod value_from;
+pub mod version;
 
 // FIXME(nick,theo): colors should be required for all schema variants.
 // There should be no default in the backend as there should always be a color.
@@ -118,6 +119,8 @@ pub enum SchemaVariantError {
     InputSocket(#[from] InputSocketError),
     #[error("InputSocketNodeWeight error: {0}")]
     InputSocketNodeWeight(#[from] InputSocketNodeWeightError),
+    #[error("invalid doc link for prop {0}: {1:?} ({2})")]
+    InvalidPropDocLink(String, String, ParseError),
     #[error("layer db error: {0}")]
     LayerDb(#[from] LayerDbError),
     #[error("Func {0} of response type {1} cannot set leaf {2:?}")]
@@ -178,6 +181,8 @@ pub enum SchemaVariantError {
     TryLock(#[from] tokio::sync::TryLockError),
     #[error("url parse error: {0}")]
     Url(#[from] ParseError),
+    #[error("schema variants {0} and {1} are not versions of the same schema")]
+    VersionsOfDifferentSchemas(SchemaVariantId, SchemaVariantId),
     #[error("workspace snapshot error: {0}")]
     WorkspaceSnapshot(#[from] WorkspaceSnapshotError),
 }
@@ -630,9 +635,16 @@ impl SchemaVariant {
     ) -> SchemaVariantResult<()> {
         let schema = schema_variant.schema(ctx).await?;
 
-        // Firstly we want to delete the asset func
+        // Firstly we want to delete the asset func, unless another variant still uses it
         let asset_func = schema_variant.get_asset_func(ctx).await?;
-        Func::delete_by_id(ctx, asset_func.id).await?;
+        let asset_func_is_shared =
+            Self::list_schema_variant_ids_using_asset_func_id(ctx, asset_func.id)
+                .await?
+                .into_iter()
+                .any(|id| id != schema_variant.id);
+        if !asset_func_is_shared {
+            Func::delete_by_id(ctx, asset_func.id).await?;
+        }
 
         let workspace_snapshot = ctx.workspace_snapshot()?;
 
@@ -855,6 +867,32 @@ impl SchemaVariant {
         Ok(component_ids)
     }
 
+    /// Returns whether or not any [`Components`](Component) are using the provided
+    /// [`SchemaVariantId`](SchemaVariant). Prefer this over [`Self::list_component_ids`] when
+    /// only emptiness matters, as it stops at the first [`Component`] found.
+    pub async fn has_components(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+    ) -> SchemaVariantResult<bool> {
+        let workspace_snapshot = ctx.workspace_snapshot()?;
+        let incoming_nodes_indices = workspace_snapshot
+            .incoming_sources_for_edge_weight_kind(
+                schema_variant_id,
+                EdgeWeightKindDiscriminants::Use,
+            )
+            .await?;
+
+        for incoming_node_idx in incoming_nodes_indices {
+            if let NodeWeight::Component(_) = workspace_snapshot
+                .get_node_weight(incoming_node_idx)
+                .await?
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     pub async fn get_authoring_func(
         &self,
         ctx: &DalContext,
@@ -1415,8 +1453,20 @@ impl SchemaVariant {
             Some(apa_id) => {
                 match AttributePrototypeArgument::static_value_by_id(ctx, *apa_id).await? {
                     Some(static_value) => {
-                        let color: String = serde_json::from_value(static_value.value)?;
-                        Ok(color)
+                        match serde_json::from_value::<String>(static_value.value.clone())
+                            .ok()
+                            .and_then(|color| normalize_color(&color))
+                        {
+                            Some(color) => Ok(color),
+                            None => {
+                                warn!(
+                                    schema_variant_id = %self.id,
+                                    color = %static_value.value,
+                                    "stored schema variant color is not a valid hex color, using default"
+                                );
+                                Ok(DEFAULT_SCHEMA_VARIANT_COLOR.to_string())
+                            }
+                        }
                     }
                     None => Ok(DEFAULT_SCHEMA_VARIANT_COLOR.to_string()),
                 }
@@ -2095,6 +2145,25 @@ impl SchemaVariant {
         Ok(results)
     }
 
+    /// Lists all [`SchemaVariantIds`](SchemaVariant) whose asset [`Func`] is the provided
+    /// [`FuncId`](Func).
+    pub async fn list_schema_variant_ids_using_asset_func_id(
+        ctx: &DalContext,
+        asset_func_id: FuncId,
+    ) -> SchemaVariantResult<Vec<SchemaVariantId>> {
+        let mut results = vec![];
+
+        for schema_id in Schema::list_ids(ctx).await? {
+            for schema_variant in Self::list_for_schema(ctx, schema_id).await? {
+                if schema_variant.asset_func_id == Some(asset_func_id) {
+                    results.push(schema_variant.id);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
     /// Find the [`SchemaVariantId`](SchemaVariant) for the given [`PropId`](Prop).
     pub async fn find_for_prop_id(
         ctx: &DalContext,
@@ -2416,10 +2485,7 @@ impl SchemaVariant {
             }
 
             for schema_variant in Self::list_for_schema(ctx, schema_id).await? {
-                if !Self::list_component_ids(ctx, schema_variant.id())
-                    .await?
-                    .is_empty()
-                {
+                if Self::has_components(ctx, schema_variant.id()).await? {
                     schema_variants.insert(
                         schema_variant.id,
                         schema_variant.into_frontend_type(ctx, schema_id).await?,
@@ -2450,3 +2516,14 @@ impl SchemaVariant {
         Ok(result)
     }
 }
+
+/// Returns the color as a `#`-prefixed hex string if it is a valid three or six digit hex color.
+pub(crate) fn normalize_color(color: &str) -> Option<String> {
+    let hex = color.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(format!("#{hex}"))
+    } else {
+        None
+    }
+}
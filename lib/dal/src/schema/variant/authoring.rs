@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::future::Future;
+use std::time::Duration;
 
 use base64::engine::general_purpose;
 use base64::Engine;
@@ -11,31 +13,33 @@ use thiserror::Error;
 
 use pkg::import::import_schema_variant;
 use si_events::ulid::Ulid;
-use si_events::FuncRunId;
+use si_events::{FuncRunId, FuncRunValue};
 use si_layer_cache::LayerDbError;
 use si_pkg::{
     FuncSpec, FuncSpecBackendKind, FuncSpecBackendResponseType, FuncSpecData, MergeSkip, PkgSpec,
-    SchemaVariantSpec, SiPkg, SiPkgError, SpecError,
+    PropSpec, SchemaVariantSpec, SiPkg, SiPkgError, SocketSpecKind, SpecError,
 };
 use telemetry::prelude::*;
 
 use crate::action::prototype::ActionPrototypeError;
 use crate::attribute::prototype::argument::AttributePrototypeArgumentError;
 use crate::attribute::prototype::AttributePrototypeError;
+use crate::attribute::value::{AttributeValue, AttributeValueError};
 use crate::func::authoring::FuncAuthoringError;
 use crate::func::intrinsics::IntrinsicFunc;
 use crate::func::runner::{FuncRunner, FuncRunnerError};
 use crate::pkg::export::PkgExporter;
 use crate::pkg::import::import_only_new_funcs;
 use crate::pkg::{import_pkg_from_pkg, ImportOptions, PkgError};
-use crate::prop::PropError;
-use crate::schema::variant::{SchemaVariantJson, SchemaVariantMetadataJson};
-use crate::socket::input::InputSocketError;
-use crate::socket::output::OutputSocketError;
+use crate::prop::{PropError, PROP_PATH_SEPARATOR};
+use crate::schema::variant::{normalize_color, SchemaVariantJson, SchemaVariantMetadataJson};
+use crate::socket::input::{InputSocket, InputSocketError};
+use crate::socket::output::{OutputSocket, OutputSocketError};
 use crate::{
-    pkg, Component, ComponentError, ComponentType, DalContext, Func, FuncBackendKind,
-    FuncBackendResponseType, FuncError, FuncId, HistoryEventError, Schema, SchemaError, SchemaId,
-    SchemaVariant, SchemaVariantError, SchemaVariantId,
+    generate_unique_id, pkg, Component, ComponentError, ComponentId, ComponentType, DalContext,
+    Func, FuncBackendKind, FuncBackendResponseType, FuncError, FuncId, HistoryEventError, Prop,
+    PropId, PropKind, Schema, SchemaError, SchemaId, SchemaVariant, SchemaVariantError,
+    SchemaVariantId,
 };
 
 #[allow(missing_docs)]
@@ -44,12 +48,18 @@ use crate::{
 pub enum VariantAuthoringError {
     #[error("action prototype error: {0}")]
     ActionPrototype(#[from] ActionPrototypeError),
+    #[error("asset func {0} has backend kind {1} and response type {2}, expected a schema variant definition")]
+    AssetFuncNotSchemaVariantDefinition(FuncId, FuncBackendKind, FuncBackendResponseType),
+    #[error("asset func {0} is shared with other schema variants: {1:?}")]
+    AssetFuncSharedAcrossVariants(FuncId, Vec<SchemaVariantId>),
     #[error("found unexpected return type: expected type 'Asset' to be returned for asset func (FuncId {0}): raw error: {1})")]
     AssetTypeNotReturnedForAssetFunc(FuncId, String),
     #[error("attribute prototype error: {0}")]
     AttributePrototype(#[from] AttributePrototypeError),
     #[error("attribute prototype error: {0}")]
     AttributePrototypeArgument(#[from] AttributePrototypeArgumentError),
+    #[error("attribute value error: {0}")]
+    AttributeValue(#[from] AttributeValueError),
     #[error("component error: {0}")]
     Component(#[from] ComponentError),
     #[error("there already exists a Schema with the name {0}")]
@@ -62,14 +72,31 @@ pub enum VariantAuthoringError {
     FuncAuthoring(#[from] FuncAuthoringError),
     #[error("func execution failure error: {0}")]
     FuncExecutionFailure(String),
+    #[error("func execution failure error: {message} (line: {line:?}, column: {column:?})")]
+    FuncExecutionFailureDetailed {
+        message: String,
+        line: Option<u64>,
+        column: Option<u64>,
+        stack: Option<String>,
+    },
+    #[error("asset func {0} did not finish within {2:?} (FuncRunId {1})")]
+    FuncExecutionTimeout(FuncId, FuncRunId, Duration),
     #[error("func run error: {0}")]
     FuncRun(#[from] FuncRunnerError),
     #[error("func run value sender has terminated without sending")]
     FuncRunGone,
     #[error("history event error: {0}")]
     HistoryEvent(#[from] HistoryEventError),
+    #[error("imported schema variant {schema_variant_id} does not match its spec: missing props {missing_props:?}, missing sockets {missing_sockets:?}")]
+    ImportedVariantMismatch {
+        schema_variant_id: SchemaVariantId,
+        missing_props: Vec<String>,
+        missing_sockets: Vec<String>,
+    },
     #[error("input socket error: {0}")]
     InputSocket(#[from] InputSocketError),
+    #[error("invalid hex color: {0}")]
+    InvalidHexColor(String),
     #[error("layer db error: {0}")]
     LayerDb(#[from] LayerDbError),
     #[error("trying to modify locked variant: {0}")]
@@ -96,6 +123,8 @@ pub enum VariantAuthoringError {
     SchemaVariantAssetNotFound(SchemaVariantId),
     #[error("schema variant not found: {0}")]
     SchemaVariantNotFound(SchemaVariantId),
+    #[error("schema variant {0} is not a version of schema {1}")]
+    SchemaVariantNotVersionOfSchema(SchemaVariantId, SchemaId),
     #[error("schema variant not updated: {0}")]
     SchemaVariantUpdatedFailed(SchemaVariantId),
     #[error("json serialization error: {0}")]
@@ -104,10 +133,60 @@ pub enum VariantAuthoringError {
     SiPkg(#[from] SiPkgError),
     #[error("spec error: {0}")]
     Spec(#[from] SpecError),
+    #[error("schema variant {0} is still used by {1} component(s)")]
+    VariantHasComponents(SchemaVariantId, usize),
 }
 
 type VariantAuthoringResult<T> = Result<T, VariantAuthoringError>;
 
+/// How long [`VariantAuthoringClient::execute_asset_func`] waits for an asset func to return.
+pub const DEFAULT_ASSET_FUNC_TIMEOUT: Duration = Duration::from_secs(300);
+/// How many times [`VariantAuthoringClient::execute_asset_func`] dispatches an asset func whose
+/// result never arrives.
+pub const DEFAULT_ASSET_FUNC_MAX_ATTEMPTS: u32 = 3;
+
+/// Controls how [`VariantAuthoringClient::execute_asset_func_with_options`] runs an asset func.
+#[derive(Clone, Copy, Debug)]
+pub struct AssetFuncExecutionOptions {
+    /// How long to wait for a single dispatch to return. A func run that times out is killed,
+    /// marked as failed and is not retried.
+    pub timeout: Duration,
+    /// How many times to dispatch the func when its result channel is dropped before a value is
+    /// sent, as happens when the executing veritech instance goes away. Func errors are never
+    /// retried.
+    pub max_attempts: u32,
+}
+
+impl Default for AssetFuncExecutionOptions {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_ASSET_FUNC_TIMEOUT,
+            max_attempts: DEFAULT_ASSET_FUNC_MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// How [`VariantAuthoringClient::regenerate_variant`] applies changes to an asset func.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegenerateStrategy {
+    /// No [`Components`](Component) use the variant, so it is rebuilt in place.
+    RegenerateInPlace,
+    /// [`Components`](Component) use the variant, so a new variant is created and the components
+    /// are upgraded to it.
+    NewVersion,
+}
+
+/// What [`VariantAuthoringClient::regenerate_variant`] would do, as reported by
+/// [`VariantAuthoringClient::preview_regenerate_variant`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegenerateVariantPreview {
+    pub strategy: RegenerateStrategy,
+    /// The number of [`Components`](Component) that would be upgraded to the new variant.
+    pub affected_component_count: usize,
+    /// The prototypes of the existing variant that would not be carried over.
+    pub skips: Vec<MergeSkip>,
+}
+
 const DEFAULT_ASSET_CODE: &str = r#"function main() {
   const asset = new AssetBuilder();
   return asset.build();
@@ -117,6 +196,33 @@ const DEFAULT_ASSET_CODE: &str = r#"function main() {
 struct SchemaVariantJsonWrapper {
     definition: Option<SchemaVariantJson>,
     error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    column: Option<u64>,
+    #[serde(
+        default,
+        rename = "stackTrace",
+        skip_serializing_if = "Option::is_none"
+    )]
+    stack_trace: Option<String>,
+}
+
+impl SchemaVariantJsonWrapper {
+    /// Builds the error for a failed asset func, including where it failed when the func run
+    /// reported a location.
+    fn execution_failure(&self, message: String) -> VariantAuthoringError {
+        if self.line.is_none() && self.column.is_none() && self.stack_trace.is_none() {
+            return VariantAuthoringError::FuncExecutionFailure(message);
+        }
+
+        VariantAuthoringError::FuncExecutionFailureDetailed {
+            message,
+            line: self.line,
+            column: self.column,
+            stack: self.stack_trace.clone(),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -139,6 +245,8 @@ impl VariantAuthoringClient {
         if Schema::is_name_taken(ctx, &name).await? {
             return Err(VariantAuthoringError::DuplicatedSchemaName(name));
         };
+        let color = color.into();
+        let color = normalize_color(&color).ok_or(VariantAuthoringError::InvalidHexColor(color))?;
 
         let variant_version = SchemaVariant::generate_version_string();
 
@@ -221,10 +329,28 @@ impl VariantAuthoringClient {
         .await
     }
 
+    /// Like [`Self::create_schema_and_variant`], but rather than erroring when a [`Schema`] with
+    /// the provided name already exists, a numeric suffix is appended to make the name unique.
+    pub async fn create_schema_and_variant_with_available_name(
+        ctx: &DalContext,
+        name: impl AsRef<str>,
+        description: Option<String>,
+        link: Option<String>,
+        category: impl Into<String>,
+        color: impl Into<String>,
+    ) -> VariantAuthoringResult<SchemaVariant> {
+        let name = Schema::find_available_name(ctx, name.as_ref()).await?;
+        Self::create_schema_and_variant(ctx, name, description, link, category, color).await
+    }
+
     #[instrument(
         name = "variant.authoring.new_schema_with_cloned_variant",
         level = "info",
-        skip_all
+        skip_all,
+        fields(
+            schema_variant_id = %schema_variant_id,
+            schema_name = %schema_name,
+        )
     )]
     pub async fn new_schema_with_cloned_variant(
         ctx: &DalContext,
@@ -239,6 +365,21 @@ impl VariantAuthoringClient {
         let schema = variant.schema(ctx).await?;
 
         if let Some(asset_func_id) = variant.asset_func_id() {
+            // Cloning from an asset func that other variants also point at would tie the clone's
+            // provenance to all of them, so we refuse rather than guess which one is the source.
+            let other_variant_ids: Vec<SchemaVariantId> =
+                SchemaVariant::list_schema_variant_ids_using_asset_func_id(ctx, asset_func_id)
+                    .await?
+                    .into_iter()
+                    .filter(|id| *id != schema_variant_id)
+                    .collect();
+            if !other_variant_ids.is_empty() {
+                return Err(VariantAuthoringError::AssetFuncSharedAcrossVariants(
+                    asset_func_id,
+                    other_variant_ids,
+                ));
+            }
+
             let old_func = Func::get_by_id_or_error(ctx, asset_func_id).await?;
 
             let cloned_func = old_func
@@ -288,11 +429,21 @@ impl VariantAuthoringClient {
                 .first()
                 .copied()
                 .ok_or(VariantAuthoringError::NoAssetCreated)?;
+            let new_schema_variant =
+                SchemaVariant::get_by_id_or_error(ctx, new_schema_variant_id).await?;
+
+            // The clone must own its asset func outright so that editing one variant can never
+            // leak into the other.
+            if new_schema_variant.asset_func_id() != Some(cloned_func.id) {
+                return Err(VariantAuthoringError::AssetFuncSharedAcrossVariants(
+                    asset_func_id,
+                    vec![schema_variant_id, new_schema_variant_id],
+                ));
+            }
 
-            Ok((
-                SchemaVariant::get_by_id_or_error(ctx, new_schema_variant_id).await?,
-                schema,
-            ))
+            debug!(%new_schema_variant_id, "cloned schema variant");
+
+            Ok((new_schema_variant, schema))
         } else {
             return Err(VariantAuthoringError::SchemaVariantAssetNotFound(
                 schema_variant_id,
@@ -300,6 +451,13 @@ impl VariantAuthoringClient {
         }
     }
 
+    /// Re-runs the asset func of an unlocked [`SchemaVariant`] and applies the result.
+    ///
+    /// A variant without [`Components`](Component) is rebuilt in place. Otherwise a new variant is
+    /// generated and every component is moved onto it with [`Component::upgrade_to_new_variant`],
+    /// which carries over the values of props whose paths still exist. Rebuilding in place while
+    /// components exist is not supported: the root prop is rebuilt from scratch, which would leave
+    /// the components' attribute values pointing at props that no longer exist.
     #[instrument(
         name = "variant.authoring.regenerate_variant",
         level = "info",
@@ -309,6 +467,21 @@ impl VariantAuthoringClient {
         ctx: &DalContext,
         schema_variant_id: SchemaVariantId,
     ) -> VariantAuthoringResult<SchemaVariantId> {
+        let (schema_variant_id, _skips) =
+            Self::regenerate_variant_with_skips(ctx, schema_variant_id).await?;
+        Ok(schema_variant_id)
+    }
+
+    /// Like [`Self::regenerate_variant`], but also returns the [`MergeSkips`](MergeSkip) for the
+    /// prototypes of the existing variant that could not be carried over to the regenerated one.
+    ///
+    /// Domain prop defaults that the asset func does not set, such as those promoted by
+    /// [`Self::apply_defaults_from_component`], are not carried over either and are reported as
+    /// [`MergeSkip::PropDefaultValueDropped`].
+    pub async fn regenerate_variant_with_skips(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+    ) -> VariantAuthoringResult<(SchemaVariantId, Vec<MergeSkip>)> {
         let schema_variant = SchemaVariant::get_by_id_or_error(ctx, schema_variant_id).await?;
 
         if schema_variant.is_locked {
@@ -316,11 +489,10 @@ impl VariantAuthoringClient {
         };
 
         let schema = schema_variant.schema(ctx).await?;
+        let previous_defaults = domain_default_value_paths(ctx, schema_variant_id).await?;
 
-        let components_in_use = SchemaVariant::list_component_ids(ctx, schema_variant_id).await?;
-
-        if components_in_use.is_empty() {
-            Self::update_existing_variant_and_regenerate(
+        if !SchemaVariant::has_components(ctx, schema_variant_id).await? {
+            let mut skips = Self::update_existing_variant_and_regenerate(
                 ctx,
                 schema_variant_id,
                 schema.name,
@@ -332,11 +504,12 @@ impl VariantAuthoringClient {
                 schema_variant.component_type,
             )
             .await?;
-            Ok(schema_variant_id)
+            skips.extend(dropped_default_values(ctx, schema_variant_id, previous_defaults).await?);
+            Ok((schema_variant_id, skips))
         } else {
             let original_is_default = schema_variant.is_default(ctx).await?;
 
-            let new_variant = Self::generate_variant_with_updates(
+            let (new_variant, mut skips) = Self::generate_variant_with_updates(
                 ctx,
                 schema_variant_id,
                 &schema.name,
@@ -349,7 +522,7 @@ impl VariantAuthoringClient {
             )
             .await?;
 
-            for component_id in components_in_use {
+            for component_id in SchemaVariant::list_component_ids(ctx, schema_variant_id).await? {
                 Component::get_by_id(ctx, component_id)
                     .await?
                     .upgrade_to_new_variant(ctx, new_variant.id)
@@ -368,10 +541,191 @@ impl VariantAuthoringClient {
             // So we should clean it up
             SchemaVariant::cleanup_unlocked_variant(ctx, schema_variant_id).await?;
 
-            Ok(new_variant.id)
+            skips.extend(dropped_default_values(ctx, new_variant.id, previous_defaults).await?);
+            Ok((new_variant.id, skips))
         }
     }
 
+    /// Promotes the values set on a reference [`Component`]'s domain to the defaults of the
+    /// matching props of the [`SchemaVariant`], so that new components start out configured like
+    /// it. Props are matched by path. Only scalar values set directly on the component are
+    /// promoted; values computed by functions, and the contents of arrays and maps, are skipped.
+    ///
+    /// The defaults are not written to the asset func, so regenerating the variant drops them
+    /// unless the asset func sets them too (see [`Self::regenerate_variant_with_skips`]).
+    ///
+    /// Returns the ids of the props whose defaults were set.
+    pub async fn apply_defaults_from_component(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+        component_id: ComponentId,
+    ) -> VariantAuthoringResult<Vec<PropId>> {
+        let schema_variant = SchemaVariant::get_by_id_or_error(ctx, schema_variant_id).await?;
+        if schema_variant.is_locked {
+            return Err(VariantAuthoringError::LockedVariant(schema_variant_id));
+        }
+
+        let domain_av_id = Component::get_by_id(ctx, component_id)
+            .await?
+            .domain_prop_attribute_value(ctx)
+            .await?;
+
+        let mut updated_prop_ids = Vec::new();
+        let mut work_queue = vec![domain_av_id];
+        while let Some(av_id) = work_queue.pop() {
+            let prop = AttributeValue::prop(ctx, av_id).await?;
+            if prop.kind == PropKind::Object {
+                work_queue.extend(AttributeValue::get_child_av_ids_in_order(ctx, av_id).await?);
+                continue;
+            }
+            if !prop.kind.is_scalar() {
+                continue;
+            }
+
+            // Only promote what was set on the component itself, not what it inherited or
+            // computed.
+            if AttributeValue::component_prototype_id(ctx, av_id)
+                .await?
+                .is_none()
+                || AttributeValue::is_set_by_dependent_function(ctx, av_id).await?
+            {
+                continue;
+            }
+            let Some(value) = AttributeValue::get_by_id(ctx, av_id)
+                .await?
+                .value(ctx)
+                .await?
+            else {
+                continue;
+            };
+
+            let path = prop.path(ctx).await?;
+            let Some(target_prop_id) =
+                Prop::find_prop_id_by_path_opt(ctx, schema_variant_id, &path).await?
+            else {
+                continue;
+            };
+            let target_prop = Prop::get_by_id(ctx, target_prop_id).await?;
+            if target_prop.kind != prop.kind
+                || Prop::is_set_by_dependent_function(ctx, target_prop_id).await?
+            {
+                continue;
+            }
+
+            Prop::set_default_value(ctx, target_prop_id, value).await?;
+            updated_prop_ids.push(target_prop_id);
+        }
+
+        Ok(updated_prop_ids)
+    }
+
+    /// Checks that every prop and socket described by `variant_spec` exists on the
+    /// [`SchemaVariant`], so that a partial import is reported instead of leaving the variant
+    /// silently missing part of its definition.
+    pub async fn verify_variant_matches_spec(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+        variant_spec: &SchemaVariantSpec,
+    ) -> VariantAuthoringResult<()> {
+        let mut existing_prop_paths = HashSet::new();
+        for prop in SchemaVariant::all_props(ctx, schema_variant_id).await? {
+            existing_prop_paths.insert(prop.path(ctx).await?.with_replaced_sep("/"));
+        }
+        let mut missing_props: Vec<String> = spec_prop_paths(variant_spec)
+            .into_iter()
+            .filter(|path| !existing_prop_paths.contains(path))
+            .collect();
+        missing_props.sort();
+
+        let input_socket_names: HashSet<String> = InputSocket::list(ctx, schema_variant_id)
+            .await?
+            .iter()
+            .map(|socket| socket.name().to_owned())
+            .collect();
+        let output_socket_names: HashSet<String> = OutputSocket::list(ctx, schema_variant_id)
+            .await?
+            .iter()
+            .map(|socket| socket.name().to_owned())
+            .collect();
+        let mut missing_sockets: Vec<String> = variant_spec
+            .sockets
+            .iter()
+            .filter(|socket| match socket.kind() {
+                Some(SocketSpecKind::Input) => !input_socket_names.contains(&socket.name),
+                Some(SocketSpecKind::Output) => !output_socket_names.contains(&socket.name),
+                None => false,
+            })
+            .map(|socket| socket.name.to_owned())
+            .collect();
+        missing_sockets.sort();
+
+        if missing_props.is_empty() && missing_sockets.is_empty() {
+            return Ok(());
+        }
+
+        Err(VariantAuthoringError::ImportedVariantMismatch {
+            schema_variant_id,
+            missing_props,
+            missing_sockets,
+        })
+    }
+
+    /// Runs the asset func and builds the spec that [`Self::regenerate_variant`] would import,
+    /// without importing it or modifying the [`Schema`].
+    #[instrument(
+        name = "variant.authoring.preview_regenerate_variant",
+        level = "info",
+        skip(ctx)
+    )]
+    pub async fn preview_regenerate_variant(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+    ) -> VariantAuthoringResult<RegenerateVariantPreview> {
+        let schema_variant = SchemaVariant::get_by_id_or_error(ctx, schema_variant_id).await?;
+
+        if schema_variant.is_locked {
+            return Err(VariantAuthoringError::LockedVariant(schema_variant_id));
+        };
+
+        let schema = schema_variant.schema(ctx).await?;
+
+        let component_ids = SchemaVariant::list_component_ids(ctx, schema_variant_id).await?;
+        let strategy = if component_ids.is_empty() {
+            RegenerateStrategy::RegenerateInPlace
+        } else {
+            RegenerateStrategy::NewVersion
+        };
+
+        let asset_func = schema_variant.get_asset_func(ctx).await?;
+        let asset_func_spec = build_asset_func_spec(&asset_func)?;
+        let definition = Self::execute_asset_func(ctx, &asset_func).await?;
+        let metadata = SchemaVariantMetadataJson {
+            schema_name: schema.name.clone(),
+            version: SchemaVariant::generate_version_string(),
+            display_name: schema_variant.display_name.clone(),
+            category: schema_variant.category.clone(),
+            color: schema_variant.color.clone(),
+            component_type: schema_variant.component_type,
+            link: schema_variant.link.clone(),
+            description: schema_variant.description.clone(),
+        };
+
+        let (_, skips, _) = build_variant_spec_based_on_existing_variant(
+            ctx,
+            definition,
+            &asset_func_spec,
+            &metadata,
+            schema_variant_id,
+        )
+        .await?;
+
+        Ok(RegenerateVariantPreview {
+            strategy,
+            affected_component_count: component_ids.len(),
+            skips,
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[instrument(
         name = "variant.authoring.update_existing_variant_and_regenerate",
@@ -388,7 +742,7 @@ impl VariantAuthoringClient {
         link: Option<String>,
         description: Option<String>,
         component_type: ComponentType,
-    ) -> VariantAuthoringResult<()> {
+    ) -> VariantAuthoringResult<Vec<MergeSkip>> {
         // Ok we need to delete the first level of outgoing children for the schema variant
         let current_schema_variant =
             SchemaVariant::get_by_id_or_error(ctx, current_schema_variant_id).await?;
@@ -414,7 +768,7 @@ impl VariantAuthoringClient {
             description: description.clone(),
         };
 
-        let (new_variant_spec, _skips, variant_funcs) =
+        let (new_variant_spec, skips, variant_funcs) =
             build_variant_spec_based_on_existing_variant(
                 ctx,
                 definition,
@@ -423,8 +777,9 @@ impl VariantAuthoringClient {
                 current_schema_variant_id,
             )
             .await?;
+        warn_merge_skips(current_schema_variant_id, &skips);
 
-        let schema_spec = metadata.to_schema_spec(new_variant_spec)?;
+        let schema_spec = metadata.to_schema_spec(new_variant_spec.clone())?;
         let email = ctx.history_actor().email(ctx).await?;
         let pkg_spec = PkgSpec::builder()
             .name(schema_name.clone())
@@ -488,6 +843,8 @@ impl VariantAuthoringClient {
                 current_schema_variant_id,
             ));
         }
+        Self::verify_variant_matches_spec(ctx, current_schema_variant_id, &new_variant_spec)
+            .await?;
 
         // Let's update the SV struct now to reflect any changes
         new_schema_variant
@@ -503,7 +860,7 @@ impl VariantAuthoringClient {
             })
             .await?;
 
-        Ok(())
+        Ok(skips)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -522,7 +879,7 @@ impl VariantAuthoringClient {
         link: Option<String>,
         description: Option<String>,
         component_type: ComponentType,
-    ) -> VariantAuthoringResult<SchemaVariant> {
+    ) -> VariantAuthoringResult<(SchemaVariant, Vec<MergeSkip>)> {
         let schema_name = schema_name.into();
 
         let old_sv = SchemaVariant::get_by_id_or_error(ctx, current_sv_id).await?;
@@ -546,7 +903,7 @@ impl VariantAuthoringClient {
             description: description.clone(),
         };
 
-        let (new_variant_spec, _skips, variant_funcs) =
+        let (new_variant_spec, skips, variant_funcs) =
             build_variant_spec_based_on_existing_variant(
                 ctx,
                 definition,
@@ -555,8 +912,9 @@ impl VariantAuthoringClient {
                 current_sv_id,
             )
             .await?;
+        warn_merge_skips(current_sv_id, &skips);
 
-        let schema_spec = metadata.to_schema_spec(new_variant_spec)?;
+        let schema_spec = metadata.to_schema_spec(new_variant_spec.clone())?;
 
         let email = ctx.history_actor().email(ctx).await?;
 
@@ -598,7 +956,7 @@ impl VariantAuthoringClient {
 
         let mut thing_map = import_only_new_funcs(ctx, pkg.funcs()?).await?;
 
-        Ok(import_schema_variant(
+        let new_variant = import_schema_variant(
             ctx,
             &schema,
             schema_spec.clone(),
@@ -607,7 +965,10 @@ impl VariantAuthoringClient {
             &mut thing_map,
             None,
         )
-        .await?)
+        .await?;
+        Self::verify_variant_matches_spec(ctx, new_variant.id, &new_variant_spec).await?;
+
+        Ok((new_variant, skips))
     }
 
     // Note(victor): This is very similar to the logic in update_and_generate_variant_with_new_version, with a few differences:
@@ -703,6 +1064,56 @@ impl VariantAuthoringClient {
             .await?)
     }
 
+    /// Deletes an unlocked [`SchemaVariant`] and its asset func. Refuses to do so while any
+    /// [`Component`](crate::Component) still uses the variant.
+    #[instrument(name = "variant.authoring.delete_variant", level = "info", skip(ctx))]
+    pub async fn delete_variant(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+    ) -> VariantAuthoringResult<()> {
+        let component_ids = SchemaVariant::list_component_ids(ctx, schema_variant_id).await?;
+        if !component_ids.is_empty() {
+            return Err(VariantAuthoringError::VariantHasComponents(
+                schema_variant_id,
+                component_ids.len(),
+            ));
+        }
+
+        SchemaVariant::cleanup_unlocked_variant(ctx, schema_variant_id).await?;
+
+        Ok(())
+    }
+
+    /// Makes the given version the default [`SchemaVariant`] for its [`Schema`], e.g. to roll
+    /// back to a previous version. Existing [`Components`](crate::Component) stay on their
+    /// current variant until they are upgraded.
+    #[instrument(
+        name = "variant.authoring.set_default_version",
+        level = "info",
+        skip(ctx)
+    )]
+    pub async fn set_default_version(
+        ctx: &DalContext,
+        schema_id: SchemaId,
+        schema_variant_id: SchemaVariantId,
+    ) -> VariantAuthoringResult<()> {
+        if SchemaVariant::schema_id_for_schema_variant_id(ctx, schema_variant_id).await?
+            != schema_id
+        {
+            return Err(VariantAuthoringError::SchemaVariantNotVersionOfSchema(
+                schema_variant_id,
+                schema_id,
+            ));
+        }
+
+        Schema::get_by_id_or_error(ctx, schema_id)
+            .await?
+            .set_default_schema_variant(ctx, schema_variant_id)
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[instrument(
         name = "variant.authoring.save_variant_content",
@@ -797,10 +1208,20 @@ impl VariantAuthoringClient {
         ctx: &DalContext,
         asset_func: &Func,
     ) -> VariantAuthoringResult<SchemaVariantJson> {
-        let result_channel = FuncRunner::run_asset_definition_func(ctx, asset_func).await?;
-        let func_run_value = result_channel
+        Self::execute_asset_func_with_options(ctx, asset_func, AssetFuncExecutionOptions::default())
             .await
-            .map_err(|_| VariantAuthoringError::FuncRunGone)??;
+    }
+
+    /// Executes the asset func according to the provided [`AssetFuncExecutionOptions`].
+    pub async fn execute_asset_func_with_options(
+        ctx: &DalContext,
+        asset_func: &Func,
+        options: AssetFuncExecutionOptions,
+    ) -> VariantAuthoringResult<SchemaVariantJson> {
+        let func_run_value = retry_when_func_run_gone(options.max_attempts, || {
+            run_asset_func_once(ctx, asset_func, options.timeout)
+        })
+        .await?;
 
         let value = func_run_value
             .value()
@@ -825,9 +1246,7 @@ impl VariantAuthoringClient {
         };
 
         if let Some(error) = &wrapper.error {
-            return Err(VariantAuthoringError::FuncExecutionFailure(
-                error.to_owned(),
-            ));
+            return Err(wrapper.execution_failure(error.to_owned()));
         }
 
         let Some(definition) = wrapper.definition else {
@@ -874,8 +1293,187 @@ async fn build_variant_spec_based_on_existing_variant(
     Ok((merged_variant, skips, variant_funcs))
 }
 
+/// Collects the paths (with `/` separators) of the props that importing `variant_spec` creates
+/// under the user-definable roots of a [`SchemaVariant`].
+fn spec_prop_paths(variant_spec: &SchemaVariantSpec) -> Vec<String> {
+    let mut roots = vec![
+        &variant_spec.domain,
+        &variant_spec.resource_value,
+        &variant_spec.secrets,
+    ];
+    roots.extend(variant_spec.secret_definition.as_ref());
+
+    let mut paths = Vec::new();
+    let mut queue: Vec<(String, &PropSpec)> = roots
+        .into_iter()
+        .map(|root| (format!("root/{}", root.name()), root))
+        .collect();
+    while let Some((path, prop_spec)) = queue.pop() {
+        for child in prop_spec.direct_children() {
+            queue.push((format!("{path}/{}", child.name()), child));
+        }
+        paths.push(path);
+    }
+
+    paths
+}
+
+/// Returns the paths of the [`SchemaVariant`]'s domain props that have a static default value.
+async fn domain_default_value_paths(
+    ctx: &DalContext,
+    schema_variant_id: SchemaVariantId,
+) -> VariantAuthoringResult<HashSet<String>> {
+    let domain_prefix = format!("root{PROP_PATH_SEPARATOR}domain{PROP_PATH_SEPARATOR}");
+
+    let mut paths = HashSet::new();
+    for prop in SchemaVariant::all_props(ctx, schema_variant_id).await? {
+        let path = prop.path(ctx).await?;
+        if !path.as_str().starts_with(&domain_prefix) {
+            continue;
+        }
+        if Prop::default_value(ctx, prop.id).await?.is_some() {
+            paths.insert(path.as_str().to_owned());
+        }
+    }
+    Ok(paths)
+}
+
+/// Reports the domain prop defaults in `previous_paths` that the regenerated variant no longer
+/// has.
+async fn dropped_default_values(
+    ctx: &DalContext,
+    regenerated_variant_id: SchemaVariantId,
+    previous_paths: HashSet<String>,
+) -> VariantAuthoringResult<Vec<MergeSkip>> {
+    let current_paths = domain_default_value_paths(ctx, regenerated_variant_id).await?;
+    let mut dropped: Vec<String> = previous_paths
+        .into_iter()
+        .filter(|path| !current_paths.contains(path))
+        .collect();
+    dropped.sort();
+
+    let skips: Vec<MergeSkip> = dropped
+        .into_iter()
+        .map(MergeSkip::PropDefaultValueDropped)
+        .collect();
+    warn_merge_skips(regenerated_variant_id, &skips);
+    Ok(skips)
+}
+
+/// The parts of a [`MergeSkip`] that are worth recording when a prototype is dropped.
+#[derive(Debug, PartialEq, Eq)]
+struct MergeSkipDetails<'a> {
+    kind: &'static str,
+    /// The prop path (with `/` separators) or socket name the skip applies to.
+    path: String,
+    /// What was missing or mismatched, if the skip is about something other than `path` itself.
+    detail: Option<String>,
+    func_unique_id: Option<&'a str>,
+}
+
+impl<'a> From<&'a MergeSkip> for MergeSkipDetails<'a> {
+    fn from(skip: &'a MergeSkip) -> Self {
+        let readable_path = |path: &str| path.replace(PROP_PATH_SEPARATOR, "/");
+        match skip {
+            MergeSkip::FuncInputInputSocketMissing {
+                prop_path,
+                missing_socket_name,
+                func_unique_id,
+                ..
+            } => Self {
+                kind: "func_input_input_socket_missing",
+                path: readable_path(prop_path),
+                detail: Some(missing_socket_name.to_owned()),
+                func_unique_id: Some(func_unique_id),
+            },
+            MergeSkip::FuncInputOutputSocketMissing {
+                prop_path,
+                missing_socket_name,
+                func_unique_id,
+                ..
+            } => Self {
+                kind: "func_input_output_socket_missing",
+                path: readable_path(prop_path),
+                detail: Some(missing_socket_name.to_owned()),
+                func_unique_id: Some(func_unique_id),
+            },
+            MergeSkip::FuncInputPropMissing {
+                prop_path,
+                missing_prop_path,
+                func_unique_id,
+                ..
+            } => Self {
+                kind: "func_input_prop_missing",
+                path: readable_path(prop_path),
+                detail: Some(readable_path(missing_prop_path)),
+                func_unique_id: Some(func_unique_id),
+            },
+            MergeSkip::InputSocketMissing { socket_name } => Self {
+                kind: "input_socket_missing",
+                path: socket_name.to_owned(),
+                detail: None,
+                func_unique_id: None,
+            },
+            MergeSkip::OutputSocketMissing { socket_name } => Self {
+                kind: "output_socket_missing",
+                path: socket_name.to_owned(),
+                detail: None,
+                func_unique_id: None,
+            },
+            MergeSkip::PropDefaultValueDropped(path) => Self {
+                kind: "prop_default_value_dropped",
+                path: readable_path(path),
+                detail: None,
+                func_unique_id: None,
+            },
+            MergeSkip::PropKindMismatch {
+                path,
+                other_kind,
+                self_kind,
+            } => Self {
+                kind: "prop_kind_mismatch",
+                path: readable_path(path),
+                detail: Some(format!("{other_kind:?} -> {self_kind:?}")),
+                func_unique_id: None,
+            },
+            MergeSkip::PropMissing(path) => Self {
+                kind: "prop_missing",
+                path: readable_path(path),
+                detail: None,
+                func_unique_id: None,
+            },
+        }
+    }
+}
+
+/// Records each [`MergeSkip`] so that customizations dropped by regenerating a variant can be
+/// traced back to the edit that dropped them.
+fn warn_merge_skips(schema_variant_id: SchemaVariantId, skips: &[MergeSkip]) {
+    for skip in skips {
+        let details = MergeSkipDetails::from(skip);
+        warn!(
+            si.schema_variant.id = %schema_variant_id,
+            si.merge_skip.kind = details.kind,
+            si.merge_skip.path = details.path.as_str(),
+            si.merge_skip.detail = details.detail.as_deref(),
+            si.merge_skip.func_unique_id = details.func_unique_id,
+            "prototype not carried over from existing schema variant"
+        );
+    }
+}
+
 #[allow(clippy::result_large_err)]
 fn build_asset_func_spec(asset_func: &Func) -> VariantAuthoringResult<FuncSpec> {
+    if asset_func.backend_kind != FuncBackendKind::JsSchemaVariantDefinition
+        || asset_func.backend_response_type != FuncBackendResponseType::SchemaVariantDefinition
+    {
+        return Err(VariantAuthoringError::AssetFuncNotSchemaVariantDefinition(
+            asset_func.id,
+            asset_func.backend_kind,
+            asset_func.backend_response_type,
+        ));
+    }
+
     let mut schema_variant_func_spec = FuncSpec::builder();
     schema_variant_func_spec.name(asset_func.name.clone());
     schema_variant_func_spec.unique_id(asset_func.id.to_string());
@@ -928,8 +1526,220 @@ fn build_pkg_spec_for_variant(
         .build()?)
 }
 
+async fn run_asset_func_once(
+    ctx: &DalContext,
+    asset_func: &Func,
+    timeout: Duration,
+) -> VariantAuthoringResult<FuncRunValue> {
+    let (func_run_id, result_channel) =
+        FuncRunner::run_asset_definition_func(ctx, asset_func).await?;
+    match tokio::time::timeout(timeout, result_channel).await {
+        Ok(result) => Ok(result.map_err(|_| VariantAuthoringError::FuncRunGone)??),
+        Err(_) => {
+            // Nothing will read the result, so don't leave the execution running in veritech.
+            if let Err(err) = FuncRunner::request_kill_execution(ctx, func_run_id).await {
+                warn!(si.error.message = ?err, %func_run_id, "could not kill timed out asset func execution");
+            }
+            ctx.layer_db()
+                .func_run()
+                .set_state_to_failure(func_run_id, ctx.events_tenancy(), ctx.events_actor())
+                .await?;
+            Err(VariantAuthoringError::FuncExecutionTimeout(
+                asset_func.id,
+                func_run_id,
+                timeout,
+            ))
+        }
+    }
+}
+
+/// Runs `dispatch` until it returns something other than [`VariantAuthoringError::FuncRunGone`]
+/// or `max_attempts` dispatches have been made.
+async fn retry_when_func_run_gone<T, F, Fut>(
+    max_attempts: u32,
+    mut dispatch: F,
+) -> VariantAuthoringResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = VariantAuthoringResult<T>>,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match dispatch().await {
+            Err(VariantAuthoringError::FuncRunGone) if attempt < max_attempts => {
+                warn!(
+                    "asset func result channel dropped, retrying (attempt {attempt} of {max_attempts})"
+                );
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
 fn generate_scaffold_func_name(name: impl AsRef<str>) -> String {
     let version = Utc::now().format("%Y%m%d%H%M%S%f").to_string();
-    let generated_name = format!("{}Scaffold_{}", name.as_ref().to_case(Case::Camel), version);
+    // The timestamp alone can repeat on platforms with coarse clocks, so add a random suffix.
+    let suffix = generate_unique_id(8);
+    let generated_name = format!(
+        "{}Scaffold_{}{}",
+        name.as_ref().to_case(Case::Camel),
+        version,
+        suffix
+    );
     generated_name
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn scaffold_func_names_are_unique() {
+        let names: HashSet<String> = (0..1000)
+            .map(|_| generate_scaffold_func_name("bulk asset"))
+            .collect();
+
+        assert_eq!(1000, names.len());
+    }
+
+    #[test]
+    fn merge_skip_details_use_readable_paths() {
+        let skip = MergeSkip::FuncInputPropMissing {
+            prop_path: ["root", "domain", "region"].join(PROP_PATH_SEPARATOR),
+            input_name: "value".to_owned(),
+            missing_prop_path: ["root", "domain", "zone"].join(PROP_PATH_SEPARATOR),
+            func_unique_id: "func-id".to_owned(),
+        };
+
+        assert_eq!(
+            MergeSkipDetails {
+                kind: "func_input_prop_missing",
+                path: "root/domain/region".to_owned(),
+                detail: Some("root/domain/zone".to_owned()),
+                func_unique_id: Some("func-id"),
+            },
+            MergeSkipDetails::from(&skip)
+        );
+
+        let skip = MergeSkip::InputSocketMissing {
+            socket_name: "Region".to_owned(),
+        };
+
+        assert_eq!(
+            MergeSkipDetails {
+                kind: "input_socket_missing",
+                path: "Region".to_owned(),
+                detail: None,
+                func_unique_id: None,
+            },
+            MergeSkipDetails::from(&skip)
+        );
+    }
+
+    #[test]
+    fn execution_failure_includes_reported_location() {
+        let wrapper: SchemaVariantJsonWrapper = serde_json::from_value(serde_json::json!({
+            "definition": null,
+            "error": "asset is not defined",
+            "line": 3,
+            "column": 17,
+            "stackTrace": "ReferenceError: asset is not defined\n    at main (3:17)",
+        }))
+        .expect("could not deserialize wrapper");
+
+        let error = wrapper.execution_failure("asset is not defined".to_string());
+
+        assert_eq!(
+            "func execution failure error: asset is not defined (line: Some(3), column: Some(17))",
+            error.to_string()
+        );
+        match error {
+            VariantAuthoringError::FuncExecutionFailureDetailed {
+                message,
+                line,
+                column,
+                stack,
+            } => {
+                assert_eq!("asset is not defined", message);
+                assert_eq!(Some(3), line);
+                assert_eq!(Some(17), column);
+                assert_eq!(
+                    Some("ReferenceError: asset is not defined\n    at main (3:17)"),
+                    stack.as_deref()
+                );
+            }
+            other => panic!("expected a detailed execution failure, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn execution_failure_without_location_is_plain() {
+        let wrapper: SchemaVariantJsonWrapper = serde_json::from_value(serde_json::json!({
+            "definition": null,
+            "error": "it broke",
+        }))
+        .expect("could not deserialize wrapper");
+
+        let error = wrapper.execution_failure("it broke".to_string());
+
+        assert_eq!("func execution failure error: it broke", error.to_string());
+        assert!(matches!(
+            error,
+            VariantAuthoringError::FuncExecutionFailure(message) if message == "it broke"
+        ));
+    }
+
+    #[tokio::test]
+    async fn retries_dropped_func_run_until_success() {
+        let attempts = &AtomicU32::new(0);
+
+        let result = retry_when_func_run_gone(3, move || async move {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(VariantAuthoringError::FuncRunGone)
+            } else {
+                Ok("definition")
+            }
+        })
+        .await;
+
+        assert_eq!("definition", result.expect("should succeed after a retry"));
+        assert_eq!(2, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let attempts = &AtomicU32::new(0);
+
+        let result: VariantAuthoringResult<()> = retry_when_func_run_gone(3, move || async move {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(VariantAuthoringError::FuncRunGone)
+        })
+        .await;
+
+        assert!(matches!(result, Err(VariantAuthoringError::FuncRunGone)));
+        assert_eq!(3, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_func_failures() {
+        let attempts = &AtomicU32::new(0);
+
+        let result: VariantAuthoringResult<()> = retry_when_func_run_gone(3, move || async move {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(VariantAuthoringError::FuncExecutionFailure(
+                "it broke".to_string(),
+            ))
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(VariantAuthoringError::FuncExecutionFailure(_))
+        ));
+        assert_eq!(1, attempts.load(Ordering::SeqCst));
+    }
+}
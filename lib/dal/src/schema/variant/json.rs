@@ -269,7 +269,9 @@ impl PropDefinition {
         builder.kind(self.kind);
         builder.has_data(true);
         if let Some(doc_url) = &self.doc_link {
-            builder.try_doc_link(doc_url.as_str())?;
+            builder.try_doc_link(doc_url.as_str()).map_err(|err| {
+                SchemaVariantError::InvalidPropDocLink(self.name.clone(), doc_url.clone(), err)
+            })?;
         }
         if let Some(docs) = &self.documentation {
             builder.documentation(docs);
@@ -0,0 +1,138 @@
+//! This module contains the ability to list the versions of a [`Schema`](crate::Schema) (i.e. its
+//! [`SchemaVariants`](SchemaVariant)) and to compare two of them.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DalContext, Prop, PropKind, SchemaId, SchemaVariantId};
+
+use super::{SchemaVariant, SchemaVariantError, SchemaVariantResult};
+
+/// The names (or paths, for [`Props`](Prop)) of the entries added, removed or changed between two
+/// versions.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionDiffEntries {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl VersionDiffEntries {
+    fn between<V: PartialEq>(from: BTreeMap<String, V>, to: BTreeMap<String, V>) -> Self {
+        let mut entries = Self::default();
+        for (key, from_value) in &from {
+            match to.get(key) {
+                Some(to_value) if to_value != from_value => entries.changed.push(key.clone()),
+                Some(_) => {}
+                None => entries.removed.push(key.clone()),
+            }
+        }
+        entries.added = to
+            .into_keys()
+            .filter(|key| !from.contains_key(key))
+            .collect();
+        entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The differences between the prop trees and sockets of two versions of a
+/// [`Schema`](crate::Schema). Generated by [`SchemaVariant::diff_versions`].
+///
+/// A [`Prop`] is changed if its kind differs and a socket is changed if its arity differs.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaVariantVersionDiff {
+    pub from: SchemaVariantId,
+    pub to: SchemaVariantId,
+    pub props: VersionDiffEntries,
+    pub input_sockets: VersionDiffEntries,
+    pub output_sockets: VersionDiffEntries,
+}
+
+impl SchemaVariantVersionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty() && self.input_sockets.is_empty() && self.output_sockets.is_empty()
+    }
+}
+
+impl SchemaVariant {
+    /// Lists every version of the given [`Schema`](crate::Schema), oldest first.
+    pub async fn list_versions(
+        ctx: &DalContext,
+        schema_id: SchemaId,
+    ) -> SchemaVariantResult<Vec<Self>> {
+        let mut versions = Self::list_for_schema(ctx, schema_id).await?;
+        // Version strings are timestamps, but fall back to the (also time-ordered) ids in case two
+        // versions were minted at the same instant.
+        versions.sort_by(|a, b| {
+            a.version()
+                .cmp(b.version())
+                .then_with(|| a.id().cmp(&b.id()))
+        });
+        Ok(versions)
+    }
+
+    /// Compares the prop trees and sockets of two versions of the same
+    /// [`Schema`](crate::Schema), reporting what changed going from `from` to `to`.
+    pub async fn diff_versions(
+        ctx: &DalContext,
+        from: SchemaVariantId,
+        to: SchemaVariantId,
+    ) -> SchemaVariantResult<SchemaVariantVersionDiff> {
+        if Self::schema_id_for_schema_variant_id(ctx, from).await?
+            != Self::schema_id_for_schema_variant_id(ctx, to).await?
+        {
+            return Err(SchemaVariantError::VersionsOfDifferentSchemas(from, to));
+        }
+
+        let (from_output_sockets, from_input_sockets) = Self::list_all_sockets(ctx, from).await?;
+        let (to_output_sockets, to_input_sockets) = Self::list_all_sockets(ctx, to).await?;
+
+        Ok(SchemaVariantVersionDiff {
+            from,
+            to,
+            props: VersionDiffEntries::between(
+                Self::prop_kinds_by_path(ctx, from).await?,
+                Self::prop_kinds_by_path(ctx, to).await?,
+            ),
+            input_sockets: VersionDiffEntries::between(
+                from_input_sockets
+                    .iter()
+                    .map(|socket| (socket.name().to_owned(), socket.arity()))
+                    .collect(),
+                to_input_sockets
+                    .iter()
+                    .map(|socket| (socket.name().to_owned(), socket.arity()))
+                    .collect(),
+            ),
+            output_sockets: VersionDiffEntries::between(
+                from_output_sockets
+                    .iter()
+                    .map(|socket| (socket.name().to_owned(), socket.arity()))
+                    .collect(),
+                to_output_sockets
+                    .iter()
+                    .map(|socket| (socket.name().to_owned(), socket.arity()))
+                    .collect(),
+            ),
+        })
+    }
+
+    async fn prop_kinds_by_path(
+        ctx: &DalContext,
+        schema_variant_id: SchemaVariantId,
+    ) -> SchemaVariantResult<BTreeMap<String, PropKind>> {
+        let mut kinds = BTreeMap::new();
+        for prop in Self::all_props(ctx, schema_variant_id).await? {
+            let path = Prop::path_by_id(ctx, prop.id).await?;
+            kinds.insert(format!("/{}", path.with_replaced_sep("/")), prop.kind);
+        }
+        Ok(kinds)
+    }
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use si_events::ContentHash;
 use si_frontend_types as frontend_types;
 use si_layer_cache::LayerDbError;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 use telemetry::prelude::*;
@@ -11,6 +12,7 @@ use crate::attribute::prototype::argument::AttributePrototypeArgumentId;
 use crate::attribute::prototype::AttributePrototypeError;
 use crate::attribute::value::AttributeValueError;
 use crate::change_set::ChangeSetError;
+use crate::component::ComponentError;
 use crate::layer_db_types::{OutputSocketContent, OutputSocketContentV1};
 use crate::socket::{SocketArity, SocketKind};
 use crate::workspace_snapshot::content_address::{ContentAddress, ContentAddressDiscriminants};
@@ -18,8 +20,8 @@ use crate::workspace_snapshot::edge_weight::{EdgeWeightKind, EdgeWeightKindDiscr
 use crate::workspace_snapshot::node_weight::{ContentNodeWeight, NodeWeight, NodeWeightError};
 use crate::workspace_snapshot::WorkspaceSnapshotError;
 use crate::{
-    implement_add_edge_to, AttributePrototypeId, AttributeValue, AttributeValueId, ComponentId,
-    InputSocketId, SchemaVariantId,
+    implement_add_edge_to, AttributePrototypeId, AttributeValue, AttributeValueId, Component,
+    ComponentId, InputSocketId, SchemaVariantId,
 };
 use crate::{
     AttributePrototype, DalContext, FuncId, HelperError, InputSocket, SchemaVariant,
@@ -38,6 +40,8 @@ pub enum OutputSocketError {
     AttributeValue(#[from] Box<AttributeValueError>),
     #[error("change set error: {0}")]
     ChangeSet(#[from] ChangeSetError),
+    #[error("component error: {0}")]
+    Component(#[from] Box<ComponentError>),
     #[error(transparent)]
     ConnectionAnnotation(#[from] ConnectionAnnotationError),
     #[error("found too many matches for output and socket: {0}, {1}")]
@@ -74,6 +78,14 @@ pub type OutputSocketResult<T> = Result<T, OutputSocketError>;
 
 pub use si_id::OutputSocketId;
 
+/// An [`InputSocket`] on a [`Component`] that an [`OutputSocket`] is compatible with.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SocketMatch {
+    pub component_id: ComponentId,
+    pub input_socket_id: InputSocketId,
+}
+
 /// This socket can only provide data to external [`SchemaVariants`](crate::SchemaVariant). It can
 /// only consume data within its own [`SchemaVariant`](crate::SchemaVariant).
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
@@ -445,6 +457,50 @@ impl OutputSocket {
         false
     }
 
+    /// Finds every [`InputSocket`] on a [`Component`] in the workspace that the given
+    /// [`OutputSocket`] on the source [`Component`] fits, going by connection annotations rather
+    /// than by name. The source [`Component`] is skipped, as it cannot connect to itself.
+    #[instrument(level = "debug", skip(ctx))]
+    pub async fn find_compatible_inputs(
+        ctx: &DalContext,
+        source_component_id: ComponentId,
+        output_socket_id: OutputSocketId,
+    ) -> OutputSocketResult<Vec<SocketMatch>> {
+        let output_socket = Self::get_by_id(ctx, output_socket_id).await?;
+
+        let mut compatible_by_variant: HashMap<SchemaVariantId, Vec<InputSocketId>> =
+            HashMap::new();
+        let mut matches = vec![];
+        for component_id in Component::list_ids(ctx).await.map_err(Box::new)? {
+            if component_id == source_component_id {
+                continue;
+            }
+            let schema_variant_id = Component::schema_variant_id(ctx, component_id)
+                .await
+                .map_err(Box::new)?;
+            let compatible_input_socket_ids = match compatible_by_variant.entry(schema_variant_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let mut input_socket_ids = vec![];
+                    for input_socket in InputSocket::list(ctx, schema_variant_id).await? {
+                        if output_socket.fits_input(&input_socket) {
+                            input_socket_ids.push(input_socket.id());
+                        }
+                    }
+                    entry.insert(input_socket_ids)
+                }
+            };
+            matches.extend(compatible_input_socket_ids.iter().map(|&input_socket_id| {
+                SocketMatch {
+                    component_id,
+                    input_socket_id,
+                }
+            }));
+        }
+
+        Ok(matches)
+    }
+
     pub async fn find_for_attribute_value_id(
         ctx: &DalContext,
         attribute_value_id: AttributeValueId,
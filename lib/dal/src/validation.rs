@@ -306,6 +306,17 @@ impl ValidationOutput {
         }
     }
 
+    /// Runs a validation format against a sample value, outside of any [`Component`]. This lets
+    /// authors preview a rule while writing it. Only formats that can be run locally are
+    /// supported; anything else is rejected with a deserialization error.
+    pub fn evaluate(
+        validation_format: &str,
+        sample_value: Option<serde_json::Value>,
+    ) -> ValidationResult<ValidationOutput> {
+        let validator = serde_json::from_str(validation_format)?;
+        Ok(run_locally(validator, sample_value))
+    }
+
     pub async fn list_for_component(
         ctx: &DalContext,
         component_id: ComponentId,
@@ -412,3 +423,41 @@ async fn run_remotely(
         message,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn evaluate_allowed_strings() {
+        let validation_format = r#"{ "type": "string", "flags": { "only": true }, "allow": ["us-east-1", "us-west-2"] }"#;
+
+        let in_list = ValidationOutput::evaluate(validation_format, Some(json!("us-east-1")))
+            .expect("could not evaluate validation format");
+        assert_eq!(
+            ValidationOutput {
+                status: ValidationStatus::Success,
+                message: None,
+            },
+            in_list
+        );
+
+        let out_of_list = ValidationOutput::evaluate(validation_format, Some(json!("eu-west-1")))
+            .expect("could not evaluate validation format");
+        assert_eq!(ValidationStatus::Failure, out_of_list.status);
+        assert!(out_of_list
+            .message
+            .expect("failure should have a message")
+            .contains("must be one of"));
+    }
+
+    #[test]
+    fn evaluate_rejects_formats_that_cannot_run_locally() {
+        assert!(matches!(
+            ValidationOutput::evaluate(r#"{ "type": "any" }"#, None),
+            Err(ValidationError::SerdeJson(_))
+        ));
+    }
+}
@@ -6,8 +6,14 @@ use thiserror::Error;
 #[remain::sorted]
 #[derive(Error, Debug)]
 pub enum WorkspaceIntegrationsError {
+    #[error("invalid webhook url: {0}")]
+    InvalidWebhookUrl(String),
+    #[error("integration {0} not found")]
+    NotFound(WorkspaceIntegrationId),
     #[error(transparent)]
     Pg(#[from] PgError),
+    #[error("integration {0} was changed since it was read: expected version {1}, found {2}")]
+    StaleVersion(WorkspaceIntegrationId, i64, i64),
     #[error("transactions error: {0}")]
     Transactions(#[from] TransactionsError),
 }
@@ -16,11 +22,18 @@ pub type WorkspaceIntegrationsResult<T> = Result<T, WorkspaceIntegrationsError>;
 
 pub use si_id::WorkspaceIntegrationId;
 
+/// The host Slack serves incoming webhooks from. Webhook urls on any other host are rejected,
+/// unless the `allow-any-webhook-host` feature is enabled for self-hosted Slack-compatible
+/// endpoints.
+pub const SLACK_WEBHOOK_HOST: &str = "hooks.slack.com";
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct WorkspaceIntegration {
     pk: WorkspaceIntegrationId,
     workspace_pk: WorkspaceId,
     slack_webhook_url: Option<String>,
+    /// Incremented on every update, so that writers can detect changes made since they read.
+    version: i64,
 }
 
 impl TryFrom<PgRow> for WorkspaceIntegration {
@@ -31,6 +44,7 @@ impl TryFrom<PgRow> for WorkspaceIntegration {
             pk: row.try_get("pk")?,
             workspace_pk: row.try_get("workspace_pk")?,
             slack_webhook_url: row.try_get("slack_webhook_url")?,
+            version: row.try_get("version")?,
         })
     }
 }
@@ -44,20 +58,77 @@ impl WorkspaceIntegration {
         self.slack_webhook_url.clone()
     }
 
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
     pub async fn update_webhook_url(
         &mut self,
         ctx: &DalContext,
         webhook_url: String,
     ) -> WorkspaceIntegrationsResult<()> {
-        ctx.txns()
+        validate_webhook_url(&webhook_url)?;
+        self.set_webhook_url(ctx, Some(webhook_url), None).await
+    }
+
+    /// Updates the webhook url only if the stored integration is still at `expected_version`,
+    /// returning [`WorkspaceIntegrationsError::StaleVersion`] if someone else updated it first.
+    pub async fn update_webhook_url_if_unchanged(
+        &mut self,
+        ctx: &DalContext,
+        webhook_url: String,
+        expected_version: i64,
+    ) -> WorkspaceIntegrationsResult<()> {
+        validate_webhook_url(&webhook_url)?;
+        self.set_webhook_url(ctx, Some(webhook_url), Some(expected_version))
+            .await
+    }
+
+    /// Removes the webhook url, turning off notifications for the workspace.
+    pub async fn clear_webhook_url(&mut self, ctx: &DalContext) -> WorkspaceIntegrationsResult<()> {
+        self.set_webhook_url(ctx, None, None).await
+    }
+
+    /// Like [`Self::clear_webhook_url`], but only if the stored integration is still at
+    /// `expected_version`.
+    pub async fn clear_webhook_url_if_unchanged(
+        &mut self,
+        ctx: &DalContext,
+        expected_version: i64,
+    ) -> WorkspaceIntegrationsResult<()> {
+        self.set_webhook_url(ctx, None, Some(expected_version))
+            .await
+    }
+
+    async fn set_webhook_url(
+        &mut self,
+        ctx: &DalContext,
+        webhook_url: Option<String>,
+        expected_version: Option<i64>,
+    ) -> WorkspaceIntegrationsResult<()> {
+        let maybe_row = ctx
+            .txns()
             .await?
             .pg()
-            .query_none(
-                "UPDATE workspace_integrations SET slack_webhook_url = $2 WHERE pk = $1",
-                &[&self.pk, &webhook_url],
+            .query_opt(
+                "UPDATE workspace_integrations SET slack_webhook_url = $2, version = version + 1 WHERE pk = $1 AND ($3::bigint IS NULL OR version = $3) RETURNING version",
+                &[&self.pk, &webhook_url, &expected_version],
             )
             .await?;
-        self.slack_webhook_url = Some(webhook_url);
+        let Some(row) = maybe_row else {
+            // Nothing was updated, either because the integration is gone or because its version
+            // moved on.
+            let current = Self::get_by_pk(ctx, self.pk)
+                .await?
+                .ok_or(WorkspaceIntegrationsError::NotFound(self.pk))?;
+            return Err(WorkspaceIntegrationsError::StaleVersion(
+                self.pk,
+                expected_version.unwrap_or(self.version),
+                current.version,
+            ));
+        };
+        self.slack_webhook_url = webhook_url;
+        self.version = row.try_get("version")?;
 
         Ok(())
     }
@@ -104,6 +175,21 @@ impl WorkspaceIntegration {
         Ok(maybe_workspace_integration)
     }
 
+    pub async fn list_for_workspace_pk(ctx: &DalContext) -> WorkspaceIntegrationsResult<Vec<Self>> {
+        let workspace_pk = ctx.workspace_pk()?;
+
+        let rows = ctx
+            .txns()
+            .await?
+            .pg()
+            .query(
+                "SELECT * FROM workspace_integrations AS w WHERE workspace_pk = $1 ORDER BY pk",
+                &[&workspace_pk],
+            )
+            .await?;
+        rows.into_iter().map(Self::try_from).collect()
+    }
+
     pub async fn get_by_pk(
         ctx: &DalContext,
         workspace_integration_id: WorkspaceIntegrationId,
@@ -124,3 +210,60 @@ impl WorkspaceIntegration {
         Ok(maybe_workspace_integration)
     }
 }
+
+fn validate_webhook_url(webhook_url: &str) -> WorkspaceIntegrationsResult<()> {
+    let url = url::Url::parse(webhook_url)
+        .map_err(|err| WorkspaceIntegrationsError::InvalidWebhookUrl(err.to_string()))?;
+    if url.scheme() != "https" {
+        return Err(WorkspaceIntegrationsError::InvalidWebhookUrl(format!(
+            "webhook urls must use https, got: {}",
+            url.scheme()
+        )));
+    }
+    if !cfg!(feature = "allow-any-webhook-host") && url.host_str() != Some(SLACK_WEBHOOK_HOST) {
+        return Err(WorkspaceIntegrationsError::InvalidWebhookUrl(format!(
+            "webhook urls must be on {SLACK_WEBHOOK_HOST}, got: {}",
+            url.host_str().unwrap_or_default()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_slack_webhook_url() {
+        validate_webhook_url("https://hooks.slack.com/services/T0000/B0000/abcdwxyz")
+            .expect("slack webhook urls are valid");
+    }
+
+    #[test]
+    fn rejects_non_https_webhook_url() {
+        let err = validate_webhook_url("http://hooks.slack.com/services/T0000/B0000/abcdwxyz")
+            .expect_err("http webhook urls are invalid");
+        assert!(matches!(
+            err,
+            WorkspaceIntegrationsError::InvalidWebhookUrl(_)
+        ));
+    }
+
+    #[cfg(not(feature = "allow-any-webhook-host"))]
+    #[test]
+    fn rejects_webhook_url_on_wrong_host() {
+        let err = validate_webhook_url("https://hooks.slack.example.com/services/T0000")
+            .expect_err("webhook urls off of slack are invalid");
+        assert!(matches!(
+            err,
+            WorkspaceIntegrationsError::InvalidWebhookUrl(_)
+        ));
+    }
+
+    #[cfg(feature = "allow-any-webhook-host")]
+    #[test]
+    fn accepts_webhook_url_on_any_host_when_allowed() {
+        validate_webhook_url("https://hooks.slack.example.com/services/T0000")
+            .expect("any https host is valid when allowed");
+    }
+}
@@ -217,6 +217,15 @@ pub struct WorkspaceSnapshot {
     inferred_connection_graph: Arc<RwLock<Option<InferredConnectionGraph>>>,
 }
 
+/// A copy of a [`WorkspaceSnapshot`]'s in-progress changes, taken with
+/// [`WorkspaceSnapshot::checkpoint`] so that later changes can be discarded with
+/// [`WorkspaceSnapshot::restore_checkpoint`].
+#[derive(Debug, Clone)]
+pub struct WorkspaceSnapshotCheckpoint {
+    working_copy: Option<WorkspaceSnapshotGraphVCurrent>,
+    dvu_roots: HashSet<DependentValueRoot>,
+}
+
 /// A pretty dumb attempt to make enabling the cycle check more ergonomic. This
 /// will reset the cycle check to false on drop, if nothing else is holding onto
 /// the cycle check besides the guard being dropped and the workspace snapshot.
@@ -597,6 +606,22 @@ impl WorkspaceSnapshot {
         }
     }
 
+    /// Copies the working copy and the pending dependent value roots so that any changes made
+    /// after this point can be discarded with [`Self::restore_checkpoint`].
+    pub async fn checkpoint(&self) -> WorkspaceSnapshotCheckpoint {
+        WorkspaceSnapshotCheckpoint {
+            working_copy: self.working_copy.read().await.clone(),
+            dvu_roots: self.dvu_roots.lock().await.clone(),
+        }
+    }
+
+    /// Discards all changes made since the [`WorkspaceSnapshotCheckpoint`] was taken.
+    pub async fn restore_checkpoint(&self, checkpoint: WorkspaceSnapshotCheckpoint) {
+        *self.working_copy.write().await = checkpoint.working_copy;
+        *self.dvu_roots.lock().await = checkpoint.dvu_roots;
+        self.clear_inferred_connection_graph().await;
+    }
+
     pub async fn serialized(&self) -> WorkspaceSnapshotResult<Vec<u8>> {
         let graph = self.working_copy().await.clone();
         Ok(si_layer_cache::db::serialize::to_vec(&WorkspaceSnapshotGraph::V4(graph))?.0)
@@ -1402,6 +1427,20 @@ impl WorkspaceSnapshot {
         Ok(())
     }
 
+    /// Replaces the order of the children of an ordered container node. The new order must contain
+    /// exactly the ids of the container's current children.
+    pub async fn update_order(
+        &self,
+        container_id: impl Into<Ulid>,
+        new_order: Vec<Ulid>,
+    ) -> WorkspaceSnapshotResult<()> {
+        self.working_copy_mut()
+            .await
+            .update_order(container_id.into(), new_order)?;
+
+        Ok(())
+    }
+
     pub async fn ordered_children_for_node(
         &self,
         id: impl Into<Ulid>,
@@ -267,6 +267,10 @@ impl CorrectTransforms for OrderingNodeWeight {
     }
 }
 
+/// Merges a divergent child order. The result depends only on its inputs: children keep their
+/// relative order from `update_order`, followed by the children only present in `order`, in the
+/// order they appear there. Rebasing the same pair of orderings therefore always converges on the
+/// same result.
 fn resolve_ordering(
     final_children: HashSet<Ulid>,
     order: &[Ulid],
@@ -303,3 +307,40 @@ fn resolve_ordering(
 
     final_order
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use pretty_assertions_sorted::assert_eq;
+    use si_events::ulid::Ulid;
+
+    use super::resolve_ordering;
+
+    #[test]
+    fn resolve_ordering_is_deterministic() {
+        let [a, b, c, d, e] = [
+            Ulid::new(),
+            Ulid::new(),
+            Ulid::new(),
+            Ulid::new(),
+            Ulid::new(),
+        ];
+
+        // Our graph reordered the children and added "d", while the updated node reordered them
+        // differently and added "e".
+        let order = vec![c, a, d, b];
+        let update_order = vec![b, e, a, c];
+        let final_children: HashSet<Ulid> = [a, b, c, d, e].into_iter().collect();
+
+        let resolved = resolve_ordering(final_children.clone(), &order, &update_order);
+        assert_eq!(vec![b, e, a, c, d], resolved);
+
+        for _ in 0..10 {
+            assert_eq!(
+                resolved,
+                resolve_ordering(final_children.clone(), &order, &update_order)
+            );
+        }
+    }
+}
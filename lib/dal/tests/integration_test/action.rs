@@ -1,8 +1,9 @@
 use dal::action::dependency_graph::ActionDependencyGraph;
 use dal::component::frame::Frame;
 use dal::{
-    action::prototype::ActionKind, action::prototype::ActionPrototype, action::Action,
-    action::ActionState, AttributeValue, Component, DalContext,
+    action::prototype::ActionKind, action::prototype::ActionPrototype,
+    action::prototype::ActionRunFailure, action::Action, action::ActionError, action::ActionState,
+    AttributeValue, Component, DalContext,
 };
 use dal_test::helpers::create_component_for_default_schema_name_in_default_view;
 use dal_test::helpers::create_component_for_schema_name_with_type_on_default_view;
@@ -12,6 +13,7 @@ use dal_test::helpers::{
 };
 use dal_test::test;
 use pretty_assertions_sorted::assert_eq;
+use veritech_client::ResourceStatus;
 
 #[test]
 async fn prototype_id(ctx: &mut DalContext) {
@@ -492,3 +494,276 @@ async fn actions_are_ordered_correctly(ctx: &mut DalContext) {
         vec![first_component_action]
     );
 }
+
+#[test]
+async fn rerun_from_func_run(ctx: &mut DalContext) {
+    let component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "shake it off")
+            .await
+            .expect("could not create component");
+    let variant_id = Component::schema_variant_id(ctx, component.id())
+        .await
+        .expect("find variant id for component");
+    let proto = ActionPrototype::for_variant(ctx, variant_id)
+        .await
+        .expect("unable to list prototypes for variant")
+        .pop()
+        .expect("unable to find prototype for variant");
+    Action::remove_all_for_component_id(ctx, component.id())
+        .await
+        .expect("could not remove queued actions");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let (_, func_run_id) = ActionPrototype::run(ctx, proto.id(), component.id())
+        .await
+        .expect("unable to run ActionPrototype");
+
+    // Rerunning the func run enqueues a new action for the same prototype and component.
+    let action_id = Action::rerun_from_func_run(ctx, func_run_id)
+        .await
+        .expect("could not rerun action");
+    let action = Action::get_by_id(ctx, action_id)
+        .await
+        .expect("find action by id");
+    assert_eq!(ActionState::Queued, action.state());
+    assert_eq!(
+        proto.id(),
+        Action::prototype_id(ctx, action_id)
+            .await
+            .expect("get prototype id from action")
+    );
+    assert_eq!(
+        Some(component.id()),
+        Action::component_id(ctx, action_id)
+            .await
+            .expect("get component id from action")
+    );
+
+    // Rerunning again while the action is still queued does not enqueue a duplicate.
+    assert_eq!(
+        action_id,
+        Action::rerun_from_func_run(ctx, func_run_id)
+            .await
+            .expect("could not rerun action")
+    );
+
+    // If the original action is still on the graph but failed, it is queued again.
+    Action::set_state(ctx, action_id, ActionState::Failed)
+        .await
+        .expect("could not set action state");
+    assert_eq!(
+        action_id,
+        Action::rerun_from_func_run(ctx, func_run_id)
+            .await
+            .expect("could not rerun action")
+    );
+    assert_eq!(
+        ActionState::Queued,
+        Action::get_by_id(ctx, action_id)
+            .await
+            .expect("find action by id")
+            .state()
+    );
+
+    // Once the component is gone, the rerun is rejected.
+    Action::remove_all_for_component_id(ctx, component.id())
+        .await
+        .expect("could not remove queued actions");
+    component.delete(ctx).await.expect("delete component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+    let error = Action::rerun_from_func_run(ctx, func_run_id)
+        .await
+        .expect_err("rerun for a deleted component should fail");
+    assert!(matches!(
+        error,
+        ActionError::ComponentNotFoundForFuncRun(id, _) if id == func_run_id
+    ));
+}
+
+#[test]
+async fn list_topologically_for_component_id(ctx: &mut DalContext) {
+    let first_component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "fearless")
+            .await
+            .expect("could not create component");
+    let second_component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "evermore")
+            .await
+            .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let all_action_ids = Action::list_topologically(ctx)
+        .await
+        .expect("find action ids");
+    assert_eq!(2, all_action_ids.len());
+
+    let first_action_ids = Action::list_topologically_for_component_id(ctx, first_component.id())
+        .await
+        .expect("find action ids for component");
+    assert_eq!(1, first_action_ids.len());
+    for action_id in &first_action_ids {
+        assert_eq!(
+            Some(first_component.id()),
+            Action::component_id(ctx, *action_id)
+                .await
+                .expect("get component id from action")
+        );
+    }
+
+    let second_action_ids = Action::list_topologically_for_component_id(ctx, second_component.id())
+        .await
+        .expect("find action ids for component");
+    assert_eq!(1, second_action_ids.len());
+    assert!(!second_action_ids.contains(&first_action_ids[0]));
+}
+
+#[test]
+async fn dry_run(ctx: &mut DalContext) {
+    let component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "shake it off")
+            .await
+            .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let action_id = Action::find_for_kind_and_component_id(ctx, component.id(), ActionKind::Create)
+        .await
+        .expect("could not find create actions")
+        .pop()
+        .expect("creating a component queues a create action");
+    let prototype_id = Action::prototype_id(ctx, action_id)
+        .await
+        .expect("get prototype id from action");
+
+    let dry_run = ActionPrototype::dry_run(ctx, prototype_id, component.id())
+        .await
+        .expect("could not dry run action");
+    assert_eq!(ActionKind::Create, dry_run.kind);
+    assert_eq!(component.id(), dry_run.component_id);
+    assert!(dry_run.args.get("properties").is_some());
+
+    // The planned action is reported, but nothing ran.
+    assert!(component
+        .resource(ctx)
+        .await
+        .expect("could not get resource")
+        .is_none());
+    assert_eq!(
+        ActionState::Queued,
+        Action::get_by_id(ctx, action_id)
+            .await
+            .expect("find action by id")
+            .state()
+    );
+}
+
+#[test]
+async fn failed_action_run_records_its_failure(ctx: &mut DalContext) {
+    // Without a secret, the "fallout" create action returns an error status with a message.
+    create_component_for_default_schema_name_in_default_view(ctx, "fallout", "no secret")
+        .await
+        .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+    ChangeSetTestHelpers::wait_for_actions_to_run(ctx)
+        .await
+        .expect("deadline for actions to run exceeded");
+
+    let action_ids = Action::list_topologically(ctx)
+        .await
+        .expect("could not list actions");
+    assert_eq!(1, action_ids.len());
+    let action_id = action_ids[0];
+    let action = Action::get_by_id(ctx, action_id)
+        .await
+        .expect("could not get action");
+    assert_eq!(ActionState::Failed, action.state());
+
+    let func_run_id = ctx
+        .layer_db()
+        .func_run()
+        .get_last_run_for_action_id(ctx.events_tenancy().workspace_pk, action_id)
+        .await
+        .expect("could not get last run for action")
+        .expect("action has no func run")
+        .id();
+    let failure = ActionRunFailure::for_func_run(ctx, func_run_id)
+        .await
+        .expect("could not get action failure");
+    assert_eq!(
+        Some(ActionRunFailure {
+            reason: "cannot create: dummy secret string is empty".to_string(),
+            message: None,
+        }),
+        failure
+    );
+}
+
+#[test]
+async fn aggregate_action_status_reflects_action_runs(ctx: &mut DalContext) {
+    // Without a secret, the "fallout" create action fails, while the "swifty" one succeeds.
+    let failing = create_component_for_default_schema_name_in_default_view(ctx, "fallout", "fails")
+        .await
+        .expect("could not create component");
+    let succeeding =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "succeeds")
+            .await
+            .expect("could not create component");
+    let untouched =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "never applied")
+            .await
+            .expect("could not create component");
+    Action::remove_all_for_component_id(ctx, untouched.id())
+        .await
+        .expect("could not remove actions");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    // The actions run on head, but they originate in the change set they were queued in.
+    let change_set_id = ctx.change_set_id();
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+    ChangeSetTestHelpers::wait_for_actions_to_run(ctx)
+        .await
+        .expect("deadline for actions to run exceeded");
+
+    assert_eq!(
+        ResourceStatus::Error,
+        Component::aggregate_action_status(ctx, failing.id(), change_set_id)
+            .await
+            .expect("could not aggregate action status")
+    );
+    assert_eq!(
+        ResourceStatus::Ok,
+        Component::aggregate_action_status(ctx, succeeding.id(), change_set_id)
+            .await
+            .expect("could not aggregate action status")
+    );
+    assert_eq!(
+        ResourceStatus::Ok,
+        Component::aggregate_action_status(ctx, untouched.id(), change_set_id)
+            .await
+            .expect("could not aggregate action status")
+    );
+
+    // Runs are scoped to the change set the actions originated in.
+    assert_eq!(
+        ResourceStatus::Ok,
+        Component::aggregate_action_status(ctx, failing.id(), ctx.change_set_id())
+            .await
+            .expect("could not aggregate action status")
+    );
+}
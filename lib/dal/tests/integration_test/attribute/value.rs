@@ -1,5 +1,6 @@
+use dal::attribute::value::AttributeValueError;
 use dal::{AttributeValue, DalContext};
-use dal_test::expected::ExpectComponent;
+use dal_test::expected::{ExpectComponent, ExpectSchemaVariant};
 use dal_test::helpers::ChangeSetTestHelpers;
 use dal_test::{test, Result};
 use pretty_assertions_sorted::assert_eq;
@@ -28,3 +29,172 @@ async fn arguments_for_prototype_function_execution(ctx: &mut DalContext) -> Res
     );
     Ok(())
 }
+
+#[test]
+async fn consistent_read_after_write(ctx: &mut DalContext) -> Result<()> {
+    let component = ExpectComponent::create_named(ctx, "swifty", "before").await;
+    let si_name_prop = component.prop(ctx, ["root", "si", "name"]).await;
+    let domain_name_prop = component.prop(ctx, ["root", "domain", "name"]).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    ctx.set_assert_consistent_reads(true);
+
+    // Once propagated, reads are consistent and do not panic.
+    assert_eq!(Some(json!("before")), domain_name_prop.view(ctx).await);
+
+    si_name_prop.set(ctx, "after").await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert!(
+        !ctx.workspace_snapshot()?
+            .has_dependent_value_roots()
+            .await?
+    );
+    assert_eq!(Some(json!("after")), domain_name_prop.view(ctx).await);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "stale read")]
+async fn stale_read_after_write_panics(ctx: &mut DalContext) {
+    let component = ExpectComponent::create_named(ctx, "swifty", "before").await;
+    let si_name_prop = component.prop(ctx, ["root", "si", "name"]).await;
+    let domain_name_prop = component.prop(ctx, ["root", "domain", "name"]).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit");
+    ctx.set_assert_consistent_reads(true);
+
+    // Write without propagating: the write leaves dependent value roots behind, so reading the
+    // derived value would be stale and must panic.
+    si_name_prop.set(ctx, "after").await;
+    domain_name_prop.view(ctx).await;
+}
+
+#[test]
+async fn unset_reverts_to_default_rather_than_null(ctx: &mut DalContext) -> Result<()> {
+    // The test exclusive schema derives "/root/domain/name" from "/root/si/name", which is what
+    // the value should revert to once unset.
+    let component = ExpectComponent::create_named(ctx, "swifty", "derived").await;
+    let domain_name_prop = component.prop(ctx, ["root", "domain", "name"]).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(Some(json!("derived")), domain_name_prop.view(ctx).await);
+
+    domain_name_prop.set(ctx, "overridden").await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(Some(json!("overridden")), domain_name_prop.view(ctx).await);
+
+    domain_name_prop.unset(ctx).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(Some(json!("derived")), domain_name_prop.view(ctx).await);
+
+    // Unsetting a value that is not set is a no-op.
+    domain_name_prop.unset(ctx).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(Some(json!("derived")), domain_name_prop.view(ctx).await);
+
+    // Setting null, on the other hand, is an explicit value: the derived value no longer shows
+    // through, and the value reads back as empty.
+    domain_name_prop.update(ctx, Some(json!(null))).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(None, domain_name_prop.view(ctx).await);
+
+    // Unsetting the explicit null restores the derived value.
+    domain_name_prop.unset(ctx).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(Some(json!("derived")), domain_name_prop.view(ctx).await);
+
+    Ok(())
+}
+
+#[test]
+async fn remove_array_element_keeps_order(ctx: &mut DalContext) -> Result<()> {
+    let component = ExpectComponent::create(ctx, "Docker Image").await;
+    let exposed_ports = component
+        .prop(ctx, ["root", "domain", "ExposedPorts"])
+        .await;
+    exposed_ports.push(ctx, "1").await;
+    exposed_ports.push(ctx, "2").await;
+    exposed_ports.push(ctx, "3").await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(json!(["1", "2", "3"]), exposed_ports.get(ctx).await);
+
+    exposed_ports.remove_at(ctx, 1).await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(json!(["1", "3"]), exposed_ports.get(ctx).await);
+
+    // The remaining elements are reindexed to fill the gap.
+    let mut indices = Vec::new();
+    for child in exposed_ports.children(ctx).await {
+        let index = AttributeValue::get_index_or_key_of_child_entry(ctx, child.id())
+            .await?
+            .map(|key_or_index| key_or_index.to_string());
+        indices.push(index);
+    }
+    assert_eq!(
+        vec![Some("[0]".to_string()), Some("[1]".to_string())],
+        indices
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn rename_map_key_keeps_nested_values(ctx: &mut DalContext) -> Result<()> {
+    let variant = ExpectSchemaVariant::create_named(
+        ctx,
+        "mapOfObjects",
+        r#"
+            function main() {
+                const entry = new PropBuilder()
+                    .setName("entry")
+                    .setKind("object")
+                    .addChild(new PropBuilder().setName("inner").setKind("string").build())
+                    .build();
+                const things = new PropBuilder()
+                    .setName("things")
+                    .setKind("map")
+                    .setEntry(entry)
+                    .build();
+                return new AssetBuilder().addProp(things).build();
+            }
+        "#,
+    )
+    .await;
+    let component = variant.create_component_on_default_view(ctx).await;
+    let things = component.prop(ctx, ["root", "domain", "things"]).await;
+    things
+        .push_with_key(ctx, "a", json!({ "inner": "first" }))
+        .await;
+    things
+        .push_with_key(ctx, "b", json!({ "inner": "second" }))
+        .await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(
+        json!({ "a": { "inner": "first" }, "b": { "inner": "second" } }),
+        things.get(ctx).await
+    );
+
+    // Renaming onto an existing key is refused.
+    let things_av_id = things.attribute_value(ctx).await.id();
+    assert!(matches!(
+        AttributeValue::rename_map_key(ctx, things_av_id, "a", "b").await,
+        Err(AttributeValueError::MapKeyAlreadyExists(_, key)) if key == "b"
+    ));
+
+    let renamed = things.rename_key(ctx, "a", "c").await;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    assert_eq!(
+        json!({ "c": { "inner": "first" }, "b": { "inner": "second" } }),
+        things.get(ctx).await
+    );
+    assert_eq!(json!({ "inner": "first" }), renamed.get(ctx).await);
+
+    // The renamed entry keeps its position.
+    let mut keys = Vec::new();
+    for child in things.children(ctx).await {
+        keys.push(AttributeValue::key_for_id(ctx, child.id()).await?);
+    }
+    assert_eq!(vec![Some("c".to_string()), Some("b".to_string())], keys);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use dal::action::Action;
 use dal::change_set::view::OpenChangeSetsView;
 use dal::{
     context::TransactionsErrorDiscriminants, DalContext, DalContextBuilder, HistoryActor,
@@ -8,13 +9,16 @@ use dal_test::helpers::{
     create_component_for_default_schema_name_in_default_view, create_user, ChangeSetTestHelpers,
 };
 use dal_test::test;
+use futures::StreamExt;
 use itertools::Itertools;
 use pretty_assertions_sorted::assert_eq;
 use si_events::authentication_method::AuthenticationMethodV1;
 use si_events::{AuthenticationMethod, AuthenticationMethodRole};
 use std::collections::HashSet;
+use std::time::Duration;
 
 mod approval;
+mod blast_radius;
 
 #[test]
 async fn open_change_sets(ctx: &mut DalContext) {
@@ -564,3 +568,97 @@ async fn change_set_approval_flow(ctx: &mut DalContext) {
         .collect_vec();
     assert_eq!(components.len(), 2);
 }
+
+#[test]
+async fn apply_already_applied_change_set_is_noop(ctx: &mut DalContext) {
+    create_component_for_default_schema_name_in_default_view(ctx, "swifty", "apply me twice")
+        .await
+        .expect("could not create component");
+    let applied_change_set_id = ctx.change_set_id();
+
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+    ChangeSetTestHelpers::wait_for_actions_to_run(ctx)
+        .await
+        .expect("deadline for actions to run exceeded");
+
+    let head_change_set_id = ctx.change_set_id();
+    let head_before = ChangeSet::get_by_id(ctx, head_change_set_id)
+        .await
+        .expect("could not get head change set");
+    let action_ids_before = Action::all_ids(ctx).await.expect("could not list actions");
+
+    // Retry the apply of the change set that was already applied.
+    ctx.update_visibility_and_snapshot_to_visibility(applied_change_set_id)
+        .await
+        .expect("could not update visibility");
+    ChangeSet::prepare_for_apply(ctx)
+        .await
+        .expect("could not prepare already applied change set");
+    let applied_change_set = ChangeSet::apply_to_base_change_set(ctx)
+        .await
+        .expect("could not apply already applied change set");
+    assert_eq!(ChangeSetStatus::Applied, applied_change_set.status);
+
+    ctx.update_visibility_and_snapshot_to_visibility(head_change_set_id)
+        .await
+        .expect("could not update visibility");
+    let head_after = ChangeSet::get_by_id(ctx, head_change_set_id)
+        .await
+        .expect("could not get head change set");
+    assert_eq!(
+        head_before.workspace_snapshot_address,
+        head_after.workspace_snapshot_address
+    );
+    assert_eq!(
+        action_ids_before,
+        Action::all_ids(ctx).await.expect("could not list actions")
+    );
+}
+
+#[test]
+async fn apply_publishes_change_set_applied_summary(ctx: &mut DalContext) {
+    let mut subscriber = ctx
+        .nats_conn()
+        .subscribe(format!(
+            "si.workspace_pk.{}.event",
+            ctx.workspace_pk().expect("could not get workspace pk")
+        ))
+        .await
+        .expect("could not subscribe to workspace events");
+
+    create_component_for_default_schema_name_in_default_view(ctx, "swifty", "summarized")
+        .await
+        .expect("could not create component");
+    let applied_change_set_id = ctx.change_set_id();
+
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+
+    let summary = tokio::time::timeout(Duration::from_secs(10), async {
+        while let Some(message) = subscriber.next().await {
+            let event: serde_json::Value =
+                serde_json::from_slice(message.payload()).expect("could not parse event");
+            let payload = &event["payload"];
+            if payload["kind"] == "ChangeSetApplied"
+                && payload["data"]["changeSetId"] == applied_change_set_id.to_string()
+            {
+                return payload["data"]["summary"].clone();
+            }
+        }
+        panic!("subscription closed before change set applied event");
+    })
+    .await
+    .expect("timed out waiting for change set applied event");
+
+    // The swifty component is new and enqueues its create action on apply.
+    assert_eq!(
+        serde_json::json!({
+            "componentsAffected": 1,
+            "actionsEnqueued": 1,
+        }),
+        summary
+    );
+}
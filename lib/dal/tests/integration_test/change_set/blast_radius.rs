@@ -0,0 +1,79 @@
+use dal::action::Action;
+use dal::change_set::blast_radius::ChangeSetBlastRadius;
+use dal::{ComponentType, DalContext};
+use dal_test::helpers::{
+    connect_components_with_socket_names, create_component_for_default_schema_name_in_default_view,
+    create_component_for_schema_name_with_type_on_default_view,
+    update_attribute_value_for_component, ChangeSetTestHelpers,
+};
+use dal_test::test;
+use pretty_assertions_sorted::assert_eq;
+
+#[test]
+async fn blast_radius(ctx: &mut DalContext) {
+    let upstream = create_component_for_schema_name_with_type_on_default_view(
+        ctx,
+        "small odd lego",
+        "upstream",
+        ComponentType::ConfigurationFrameDown,
+    )
+    .await
+    .expect("could not create component");
+    let downstream = create_component_for_schema_name_with_type_on_default_view(
+        ctx,
+        "small even lego",
+        "downstream",
+        ComponentType::ConfigurationFrameDown,
+    )
+    .await
+    .expect("could not create component");
+    let unrelated =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "unrelated")
+            .await
+            .expect("could not create component");
+    connect_components_with_socket_names(ctx, upstream.id(), "two", downstream.id(), "two")
+        .await
+        .expect("could not create connection");
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+
+    ChangeSetTestHelpers::fork_from_head_change_set(ctx)
+        .await
+        .expect("could not fork change set");
+    update_attribute_value_for_component(
+        ctx,
+        upstream.id(),
+        &["root", "si", "name"],
+        serde_json::json!("renamed upstream"),
+    )
+    .await
+    .expect("could not update name");
+    let added = create_component_for_default_schema_name_in_default_view(ctx, "swifty", "added")
+        .await
+        .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let blast_radius = ChangeSetBlastRadius::compute(ctx)
+        .await
+        .expect("could not compute blast radius");
+
+    let mut expected_changed_component_ids = vec![upstream.id(), added.id()];
+    expected_changed_component_ids.sort();
+    assert_eq!(
+        expected_changed_component_ids,
+        blast_radius.changed_component_ids
+    );
+    assert_eq!(vec![downstream.id()], blast_radius.downstream_component_ids);
+    assert!(!blast_radius.changed_component_ids.contains(&unrelated.id()));
+
+    let added_action_ids = Action::find_for_component_id(ctx, added.id())
+        .await
+        .expect("could not list actions");
+    assert!(!added_action_ids.is_empty());
+    for action_id in added_action_ids {
+        assert!(blast_radius.action_ids.contains(&action_id));
+    }
+}
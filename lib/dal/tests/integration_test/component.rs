@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use dal::attribute::value::DependentValueGraph;
 use dal::diagram::view::View;
 use dal::diagram::Diagram;
@@ -5,12 +7,13 @@ use dal::prop::{Prop, PropPath};
 use dal::property_editor::values::PropertyEditorValues;
 use dal::workspace_snapshot::DependentValueRoot;
 use dal::{AttributeValue, AttributeValueId};
-use dal::{Component, DalContext, Schema, SchemaVariant};
+use dal::{Component, ComponentId, DalContext, Schema, SchemaVariant};
 use dal_test::expected::{self, ExpectComponent};
 use dal_test::helpers::{
     create_component_for_default_schema_name_in_default_view,
-    create_component_for_schema_variant_on_default_view, update_attribute_value_for_component,
-    ChangeSetTestHelpers,
+    create_component_for_schema_variant_on_default_view,
+    create_components_for_default_schema_names_in_default_view,
+    update_attribute_value_for_component, ChangeSetTestHelpers,
 };
 use dal_test::{test, Result};
 use pretty_assertions_sorted::assert_eq;
@@ -20,6 +23,7 @@ mod debug;
 mod delete;
 mod get_code;
 mod get_diff;
+mod get_resource;
 mod paste;
 mod property_order;
 mod set_type;
@@ -200,6 +204,27 @@ async fn create_and_determine_lineage(ctx: &DalContext) -> Result<()> {
     Ok(())
 }
 
+#[test]
+async fn create_components_in_one_call(ctx: &mut DalContext) -> Result<()> {
+    let requested = [
+        ("alpha", "starfield"),
+        ("beta", "fallout"),
+        ("gamma", "starfield"),
+    ];
+    let components =
+        create_components_for_default_schema_names_in_default_view(ctx, &requested).await?;
+
+    assert_eq!(requested.len(), components.len());
+    let ids: HashSet<ComponentId> = components.iter().map(|c| c.id()).collect();
+    assert_eq!(requested.len(), ids.len());
+    for ((name, schema_name), component) in requested.iter().zip(&components) {
+        assert_eq!(*name, component.name(ctx).await?);
+        assert_eq!(*schema_name, component.schema(ctx).await?.name());
+    }
+
+    Ok(())
+}
+
 #[test]
 async fn through_the_wormholes_simple(ctx: &mut DalContext) -> Result<()> {
     let name = "across the universe";
@@ -144,3 +144,57 @@ async fn get_diff_component_change_comp_type(ctx: &mut DalContext) {
         first_diff.code // actual
     );
 }
+
+#[test]
+async fn get_property_diff(ctx: &mut DalContext) {
+    let starfield_component = create_component_for_default_schema_name_in_default_view(
+        ctx,
+        "starfield",
+        "this is a new component",
+    )
+    .await
+    .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit");
+
+    // A component that does not exist on head is entirely added.
+    let diff = Component::get_property_diff(ctx, starfield_component.id())
+        .await
+        .expect("unable to get property diff");
+    assert_eq!(vec!["/root".to_string()], diff.added);
+    assert!(diff.removed.is_empty());
+    assert!(diff.changed.is_empty());
+
+    // Apply the change set and create a new change set.
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+    ChangeSetTestHelpers::fork_from_head_change_set(ctx)
+        .await
+        .expect("could not fork change set");
+
+    let diff = Component::get_property_diff(ctx, starfield_component.id())
+        .await
+        .expect("unable to get property diff");
+    assert!(diff.is_empty());
+
+    Component::set_type_by_id(
+        ctx,
+        starfield_component.id(),
+        ComponentType::ConfigurationFrameDown,
+    )
+    .await
+    .expect("could not update type");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let diff = Component::get_property_diff(ctx, starfield_component.id())
+        .await
+        .expect("unable to get property diff");
+    assert_eq!(starfield_component.id(), diff.component_id);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(vec!["/root/si/type".to_string()], diff.changed);
+}
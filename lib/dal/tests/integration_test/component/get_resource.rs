@@ -0,0 +1,58 @@
+use dal::component::resource::{ResourceData, ResourceView, RESOURCE_VIEW_PAYLOAD_SIZE_CAP};
+use dal::DalContext;
+use dal_test::helpers::create_component_for_default_schema_name_in_default_view;
+use dal_test::helpers::ChangeSetTestHelpers;
+use dal_test::test;
+use pretty_assertions_sorted::assert_eq;
+use veritech_client::ResourceStatus;
+
+#[test]
+async fn get_resource_truncates_oversized_payload(ctx: &mut DalContext) {
+    let small = create_component_for_default_schema_name_in_default_view(ctx, "swifty", "small")
+        .await
+        .expect("could not create component");
+    let large = create_component_for_default_schema_name_in_default_view(ctx, "swifty", "large")
+        .await
+        .expect("could not create component");
+
+    let small_payload = serde_json::json!({ "poop": "canoe" });
+    let large_payload =
+        serde_json::json!({ "blob": "a".repeat(RESOURCE_VIEW_PAYLOAD_SIZE_CAP + 1) });
+    small
+        .set_resource(
+            ctx,
+            ResourceData::new(ResourceStatus::Ok, Some(small_payload.clone())),
+        )
+        .await
+        .expect("unable to set resource");
+    large
+        .set_resource(
+            ctx,
+            ResourceData::new(ResourceStatus::Ok, Some(large_payload.clone())),
+        )
+        .await
+        .expect("unable to set resource");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let small_view = ResourceView::get_by_component_id(ctx, small.id())
+        .await
+        .expect("could not get resource view");
+    assert!(!small_view.truncated);
+    assert_eq!(None, small_view.full_value_ref);
+    assert_eq!(Some(small_payload), small_view.payload);
+
+    let large_view = ResourceView::get_by_component_id(ctx, large.id())
+        .await
+        .expect("could not get resource view");
+    assert!(large_view.truncated);
+    assert_eq!(None, large_view.payload);
+    let full_value_ref = large_view
+        .full_value_ref
+        .expect("truncated view has no full value ref");
+    let full_payload = ResourceView::get_full_payload(ctx, full_value_ref)
+        .await
+        .expect("could not get full payload");
+    assert_eq!(Some(large_payload), full_payload);
+}
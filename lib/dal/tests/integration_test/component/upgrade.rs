@@ -929,3 +929,127 @@ async fn update_schema_variant_description(
         .expect("could not get updated variant")
         .into()
 }
+
+#[test]
+async fn migrate_to_variant_reports_uncarried_values(ctx: &mut DalContext) {
+    let variant_code = r#"
+    function main() {
+        const regionProp = new PropBuilder().setName("region").setKind("string").build();
+        const countProp = new PropBuilder().setName("count").setKind("string").build();
+        return new AssetBuilder().addProp(regionProp).addProp(countProp).build();
+    }"#;
+    let first_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        "migratingAsset",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+        variant_code,
+    )
+    .await
+    .expect("Unable to create new asset");
+    let schema = first_variant
+        .schema(ctx)
+        .await
+        .expect("Unable to get the schema for the variant");
+
+    let component =
+        create_component_for_default_schema_name_in_default_view(ctx, &schema.name, "migrator")
+            .await
+            .expect("could not create component");
+    for (path, value) in [("region", "us-east-2"), ("count", "3")] {
+        let av_id = component
+            .attribute_values_for_prop(ctx, &["root", "domain", path])
+            .await
+            .expect("find value ids for the prop")
+            .pop()
+            .expect("there should only be one value id");
+        AttributeValue::update(ctx, av_id, Some(json!(value)))
+            .await
+            .expect("could not update value");
+    }
+    let first_variant = first_variant
+        .lock(ctx)
+        .await
+        .expect("unable to lock the schema variant");
+
+    // The next version adds a prop and changes the kind of another.
+    let second_variant =
+        VariantAuthoringClient::create_unlocked_variant_copy(ctx, first_variant.id())
+            .await
+            .expect("unable to create an unlocked copy of a schema variant");
+    let new_code = r#"
+    function main() {
+        const regionProp = new PropBuilder().setName("region").setKind("string").build();
+        const countProp = new PropBuilder().setName("count").setKind("integer").build();
+        const zoneProp = new PropBuilder().setName("zone").setKind("string").build();
+        return new AssetBuilder().addProp(regionProp).addProp(countProp).addProp(zoneProp).build();
+    }"#;
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        second_variant.id(),
+        &schema.name,
+        second_variant.display_name(),
+        second_variant.category(),
+        second_variant.description(),
+        second_variant.link(),
+        second_variant
+            .get_color(ctx)
+            .await
+            .expect("get color from schema variant"),
+        second_variant.component_type(),
+        Some(new_code.to_string()),
+    )
+    .await
+    .expect("save variant contents");
+    let second_variant_id = VariantAuthoringClient::regenerate_variant(ctx, second_variant.id())
+        .await
+        .expect("unable to update asset");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    // The component was on the locked version, so it stays put until migrated.
+    assert_eq!(
+        first_variant.id(),
+        Component::schema_variant_id(ctx, component.id())
+            .await
+            .expect("unable to get schema variant id")
+    );
+
+    let migration = Component::migrate_to_variant(ctx, component.id(), second_variant_id)
+        .await
+        .expect("unable to migrate component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let migrated = migration.component;
+    assert_eq!(
+        second_variant_id,
+        Component::schema_variant_id(ctx, migrated.id())
+            .await
+            .expect("unable to get schema variant id")
+    );
+    assert_eq!(
+        vec!["/root/domain/count".to_string()],
+        migration.uncarried_values
+    );
+
+    let view = PropEditorTestView::for_component_id(ctx, migrated.id())
+        .await
+        .expect("could not get property editor test view");
+    assert_eq!(
+        json!("us-east-2"),
+        view.get_value(&["root", "domain", "region"])
+            .expect("could not get region")["value"]
+    );
+    assert_eq!(
+        serde_json::Value::Null,
+        view.get_value(&["root", "domain", "count"])
+            .expect("could not get count")["value"]
+    );
+    view.get_value(&["root", "domain", "zone"])
+        .expect("the new prop should appear on the migrated component");
+}
@@ -1,5 +1,7 @@
 use dal::attribute::prototype::argument::AttributePrototypeArgument;
 use dal::diagram::Diagram;
+use dal::schema::variant::authoring::VariantAuthoringClient;
+use dal::socket::output::SocketMatch;
 use dal::{
     AttributeValue, Component, DalContext, InputSocket, OutputSocket, Schema, SchemaVariant,
 };
@@ -716,3 +718,119 @@ async fn remove_connection(ctx: &mut DalContext) {
         .expect("could not assemble the diagram");
     assert_eq!(1, diagram.edges.len());
 }
+
+#[test]
+async fn find_compatible_inputs_by_annotation(ctx: &mut DalContext) {
+    let mut variant_ids = vec![];
+    for (name, socket_code) in [
+        (
+            "imageSource",
+            "asset.addOutputSocket(new SocketDefinitionBuilder()
+                .setName(\"Image Ref\")
+                .setArity(\"one\")
+                .setConnectionAnnotation(\"image<string>\")
+                .build());
+            asset.addInputSocket(new SocketDefinitionBuilder()
+                .setName(\"Base Image\")
+                .setArity(\"one\")
+                .setConnectionAnnotation(\"string\")
+                .build());",
+        ),
+        (
+            "imageConsumer",
+            "asset.addInputSocket(new SocketDefinitionBuilder()
+                .setName(\"Container Source\")
+                .setArity(\"one\")
+                .setConnectionAnnotation(\"string\")
+                .build());",
+        ),
+        (
+            "portConsumer",
+            "asset.addInputSocket(new SocketDefinitionBuilder()
+                .setName(\"Port Number\")
+                .setArity(\"one\")
+                .setConnectionAnnotation(\"port<number>\")
+                .build());",
+        ),
+    ] {
+        let code = format!(
+            "function main() {{
+                const asset = new AssetBuilder();
+                {socket_code}
+                return asset.build();
+            }}"
+        );
+        let variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+            ctx,
+            name,
+            None,
+            None,
+            "Integration Tests",
+            "#00b0b0",
+            code,
+        )
+        .await
+        .expect("unable to create schema and variant");
+        variant_ids.push(variant.id());
+    }
+
+    let mut components = vec![];
+    for (name, &variant_id) in ["source", "compatible", "incompatible"]
+        .iter()
+        .zip(variant_ids.iter())
+    {
+        components.push(
+            create_named_component_for_schema_variant_on_default_view(ctx, name, variant_id)
+                .await
+                .expect("could not create component"),
+        );
+    }
+    // Another component of the source's variant, whose input the output also fits.
+    let other_source = create_named_component_for_schema_variant_on_default_view(
+        ctx,
+        "other source",
+        variant_ids[0],
+    )
+    .await
+    .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let output_socket = OutputSocket::find_with_name_or_error(ctx, "Image Ref", variant_ids[0])
+        .await
+        .expect("could not find output socket");
+    let compatible_input_socket =
+        InputSocket::find_with_name_or_error(ctx, "Container Source", variant_ids[1])
+            .await
+            .expect("could not find input socket");
+    let incompatible_input_socket =
+        InputSocket::find_with_name_or_error(ctx, "Port Number", variant_ids[2])
+            .await
+            .expect("could not find input socket");
+    let source_input_socket =
+        InputSocket::find_with_name_or_error(ctx, "Base Image", variant_ids[0])
+            .await
+            .expect("could not find input socket");
+
+    let matches = OutputSocket::find_compatible_inputs(ctx, components[0].id(), output_socket.id())
+        .await
+        .expect("could not find compatible inputs");
+
+    assert!(matches.contains(&SocketMatch {
+        component_id: components[1].id(),
+        input_socket_id: compatible_input_socket.id(),
+    }));
+    assert!(!matches
+        .iter()
+        .any(|socket_match| socket_match.input_socket_id == incompatible_input_socket.id()));
+
+    // The source component is skipped, but other components of its variant are not.
+    assert!(!matches
+        .iter()
+        .any(|socket_match| socket_match.component_id == components[0].id()));
+    assert!(matches.contains(&SocketMatch {
+        component_id: other_source.id(),
+        input_socket_id: source_input_socket.id(),
+    }));
+}
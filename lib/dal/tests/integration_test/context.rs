@@ -0,0 +1,105 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use dal::component::refresh::RefreshSchedule;
+use dal::{Component, DalContext, TransactionsError};
+use dal_test::helpers::create_component_for_default_schema_name_in_default_view;
+use dal_test::test;
+
+#[test]
+async fn with_savepoint_rolls_back_only_inner_writes(ctx: &mut DalContext) {
+    let outer_component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "outer")
+            .await
+            .expect("could not create component");
+    let outer_schedule = RefreshSchedule::new(ctx, Vec::new(), Duration::from_secs(60))
+        .await
+        .expect("could not create refresh schedule");
+
+    let (inner_component_id, inner_schedule_id) = ctx
+        .with_savepoint(|ctx| async move {
+            let component =
+                create_component_for_default_schema_name_in_default_view(&ctx, "swifty", "inner")
+                    .await
+                    .expect("could not create component");
+            let schedule = RefreshSchedule::new(&ctx, Vec::new(), Duration::from_secs(60))
+                .await
+                .expect("could not create refresh schedule");
+            Ok::<_, TransactionsError>((component.id(), schedule.id()))
+        })
+        .await
+        .expect("could not run savepoint");
+
+    let discarded = Mutex::new(None);
+    let result = ctx
+        .with_savepoint(|ctx| {
+            let discarded = &discarded;
+            async move {
+                let component = create_component_for_default_schema_name_in_default_view(
+                    &ctx,
+                    "swifty",
+                    "discarded",
+                )
+                .await
+                .expect("could not create component");
+                let schedule = RefreshSchedule::new(&ctx, Vec::new(), Duration::from_secs(60))
+                    .await
+                    .expect("could not create refresh schedule");
+                *discarded.lock().expect("could not lock") = Some((component.id(), schedule.id()));
+                Err::<(), _>(TransactionsError::TxnRollback)
+            }
+        })
+        .await;
+    assert!(matches!(result, Err(TransactionsError::TxnRollback)));
+
+    // Everything written outside of the failed savepoint survives.
+    assert!(Component::try_get_by_id(ctx, outer_component.id())
+        .await
+        .expect("could not get component")
+        .is_some());
+    assert!(Component::try_get_by_id(ctx, inner_component_id)
+        .await
+        .expect("could not get component")
+        .is_some());
+    assert!(RefreshSchedule::get_by_id(ctx, outer_schedule.id())
+        .await
+        .expect("could not get refresh schedule")
+        .is_some());
+    assert!(RefreshSchedule::get_by_id(ctx, inner_schedule_id)
+        .await
+        .expect("could not get refresh schedule")
+        .is_some());
+
+    // Only the writes from the failed savepoint are gone.
+    let (discarded_component_id, discarded_schedule_id) = discarded
+        .into_inner()
+        .expect("could not lock")
+        .expect("savepoint did not run");
+    assert!(Component::try_get_by_id(ctx, discarded_component_id)
+        .await
+        .expect("could not get component")
+        .is_none());
+    assert!(RefreshSchedule::get_by_id(ctx, discarded_schedule_id)
+        .await
+        .expect("could not get refresh schedule")
+        .is_none());
+}
+
+#[test]
+async fn with_savepoint_refuses_to_commit(ctx: &mut DalContext) {
+    let result = ctx
+        .with_savepoint(|ctx| async move {
+            ctx.commit().await?;
+            Ok::<_, TransactionsError>(())
+        })
+        .await;
+    assert!(matches!(result, Err(TransactionsError::InSavepoint)));
+
+    let result = ctx
+        .with_savepoint(|ctx| async move { ctx.clone().rollback().await })
+        .await;
+    assert!(matches!(result, Err(TransactionsError::InSavepoint)));
+
+    // The outer context still owns its transactions.
+    ctx.commit().await.expect("could not commit outer context");
+}
@@ -1,8 +1,28 @@
+use std::time::Duration;
+
+use base64::{engine::general_purpose, Engine};
+use dal::action::prototype::ActionPrototype;
+use dal::func::argument::{FuncArgument, FuncArgumentKind};
 use dal::func::authoring::FuncAuthoringClient;
-use dal::{DalContext, Func, Prop, Schema, SchemaVariant};
-use dal_test::helpers::create_unlocked_variant_copy_for_schema_name;
+use dal::func::binding::authentication::AuthBinding;
+use dal::func::runner::{
+    func_executions_in_flight, max_concurrent_func_executions, FuncRunner,
+    DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS,
+};
+use dal::func::FuncError;
+use dal::schema::variant::authoring::VariantAuthoringClient;
+use dal::{
+    Component, ComponentType, DalContext, Func, FuncBackendKind, FuncBackendResponseType, FuncId,
+    Prop, Schema, SchemaVariant,
+};
+use dal_test::helpers::{
+    create_component_for_default_schema_name_in_default_view,
+    create_unlocked_variant_copy_for_schema_name, ChangeSetTestHelpers,
+};
 use dal_test::test;
 use pretty_assertions_sorted::assert_eq;
+use si_events::ContentHash;
+use veritech_client::KillExecutionRequest;
 
 mod argument;
 mod authoring;
@@ -72,6 +92,252 @@ async fn duplicate(ctx: &mut DalContext) {
     assert_eq!(false, duplicated_func.is_locked);
 }
 
+#[test]
+async fn duplicate_with_arguments(ctx: &mut DalContext) {
+    let func = Func::new(
+        ctx,
+        "Paul's Transformation Func",
+        None::<String>,
+        None::<String>,
+        None::<String>,
+        false,
+        false,
+        FuncBackendKind::JsAttribute,
+        FuncBackendResponseType::Json,
+        Some("main"),
+        None::<String>,
+    )
+    .await
+    .expect("unable to create func");
+    FuncArgument::new(ctx, "domain", FuncArgumentKind::Object, None, func.id)
+        .await
+        .expect("unable to create func argument");
+    FuncArgument::new(
+        ctx,
+        "tags",
+        FuncArgumentKind::Array,
+        Some(FuncArgumentKind::String),
+        func.id,
+    )
+    .await
+    .expect("unable to create func argument");
+
+    let duplicated_func = func
+        .duplicate_with_arguments(ctx, "Paul's Transformation Func Clone".to_string())
+        .await
+        .expect("unable to duplicate the func");
+    assert_ne!(func.id, duplicated_func.id);
+
+    let args = FuncArgument::list_for_func_ordered(ctx, func.id)
+        .await
+        .expect("could not list func arguments");
+    let duplicated_args = FuncArgument::list_for_func_ordered(ctx, duplicated_func.id)
+        .await
+        .expect("could not list func arguments");
+    assert_eq!(2, duplicated_args.len());
+    for (arg, duplicated_arg) in args.iter().zip(duplicated_args.iter()) {
+        assert_ne!(arg.id, duplicated_arg.id);
+        assert_eq!(arg.name, duplicated_arg.name);
+        assert_eq!(arg.kind, duplicated_arg.kind);
+        assert_eq!(arg.element_kind, duplicated_arg.element_kind);
+    }
+}
+
+#[test]
+async fn code_integrity_holds_across_code_changes(ctx: &mut DalContext) {
+    let func = Func::new(
+        ctx,
+        "Paul's Code Func",
+        None::<String>,
+        None::<String>,
+        None::<String>,
+        false,
+        false,
+        FuncBackendKind::JsAttribute,
+        FuncBackendResponseType::Json,
+        Some("main"),
+        Some(general_purpose::STANDARD_NO_PAD.encode("function main() { return 1; }")),
+    )
+    .await
+    .expect("unable to create func");
+    assert!(func
+        .verify_code_integrity()
+        .expect("could not verify code integrity"));
+
+    let original_code_blake3 = func.code_blake3;
+    let func = func
+        .modify(ctx, |func| {
+            func.code_base64 =
+                Some(general_purpose::STANDARD_NO_PAD.encode("function main() { return 2; }"));
+            Ok(())
+        })
+        .await
+        .expect("unable to modify func");
+    assert_ne!(original_code_blake3, func.code_blake3);
+    assert_eq!(
+        Some("function main() { return 2; }".to_string()),
+        func.code_plaintext().expect("could not decode code")
+    );
+    assert!(func
+        .verify_code_integrity()
+        .expect("could not verify code integrity"));
+
+    let func = Func::get_by_id_or_error(ctx, func.id)
+        .await
+        .expect("could not get func");
+    assert!(func
+        .verify_code_integrity()
+        .expect("could not verify code integrity"));
+}
+
+#[test]
+async fn code_integrity_fails_for_a_corrupted_hash(ctx: &mut DalContext) {
+    let mut func = Func::new(
+        ctx,
+        "Paul's Corrupted Func",
+        None::<String>,
+        None::<String>,
+        None::<String>,
+        false,
+        false,
+        FuncBackendKind::JsAttribute,
+        FuncBackendResponseType::Json,
+        Some("main"),
+        Some(general_purpose::STANDARD_NO_PAD.encode("function main() { return 1; }")),
+    )
+    .await
+    .expect("unable to create func");
+
+    func.code_blake3 = ContentHash::new("corrupted".as_bytes());
+    assert!(!func
+        .verify_code_integrity()
+        .expect("could not verify code integrity"));
+}
+
+#[test]
+async fn metadata_view(ctx: &mut DalContext) {
+    let func = Func::new(
+        ctx,
+        "Paul's Documented Func",
+        Some("Paul's Func"),
+        Some("does a thing"),
+        Some("https://docs.example.com/func"),
+        false,
+        false,
+        FuncBackendKind::JsAttribute,
+        FuncBackendResponseType::Json,
+        Some("main"),
+        None::<String>,
+    )
+    .await
+    .expect("unable to create func");
+    let metadata_view = func.metadata_view();
+    assert_eq!("Paul's Func", metadata_view.display_name);
+    assert_eq!(Some("does a thing".to_string()), metadata_view.description);
+    assert_eq!(
+        Some("https://docs.example.com/func".to_string()),
+        metadata_view.link
+    );
+
+    let func = Func::new(
+        ctx,
+        "Paul's Undocumented Func",
+        None::<String>,
+        None::<String>,
+        None::<String>,
+        false,
+        false,
+        FuncBackendKind::JsAttribute,
+        FuncBackendResponseType::Json,
+        Some("main"),
+        None::<String>,
+    )
+    .await
+    .expect("unable to create func");
+    let metadata_view = func.metadata_view();
+    assert_eq!("Paul's Undocumented Func", metadata_view.display_name);
+    assert_eq!(None, metadata_view.description);
+    assert_eq!(None, metadata_view.link);
+}
+
+#[test]
+async fn affected_components(ctx: &mut DalContext) {
+    let first = create_component_for_default_schema_name_in_default_view(ctx, "swifty", "first")
+        .await
+        .expect("could not create component");
+    let second = create_component_for_default_schema_name_in_default_view(ctx, "swifty", "second")
+        .await
+        .expect("could not create component");
+    let unrelated =
+        create_component_for_default_schema_name_in_default_view(ctx, "starfield", "unrelated")
+            .await
+            .expect("could not create component");
+
+    let schema_variant_id = Component::schema_variant_id(ctx, first.id())
+        .await
+        .expect("could not get schema variant id");
+    let prototype = ActionPrototype::for_variant(ctx, schema_variant_id)
+        .await
+        .expect("could not list action prototypes")
+        .pop()
+        .expect("swifty has an action prototype");
+    let func_id = ActionPrototype::func_id(ctx, prototype.id())
+        .await
+        .expect("could not get func id");
+
+    let affected_component_ids = Func::affected_components(ctx, func_id)
+        .await
+        .expect("could not list affected components");
+    assert!(affected_component_ids.contains(&first.id()));
+    assert!(affected_component_ids.contains(&second.id()));
+    assert!(!affected_component_ids.contains(&unrelated.id()));
+    assert_eq!(2, affected_component_ids.len());
+}
+
+#[test]
+async fn list_from_ids(ctx: &mut DalContext) {
+    let mut funcs = Vec::new();
+    for name in [
+        "Paul's First Func",
+        "Paul's Second Func",
+        "Paul's Third Func",
+    ] {
+        funcs.push(
+            Func::new(
+                ctx,
+                name,
+                None::<String>,
+                None::<String>,
+                None::<String>,
+                false,
+                false,
+                FuncBackendKind::JsAttribute,
+                FuncBackendResponseType::Json,
+                Some("main"),
+                None::<String>,
+            )
+            .await
+            .expect("unable to create func"),
+        );
+    }
+
+    // Funcs come back in the order their ids were given.
+    let mut func_ids: Vec<FuncId> = funcs.iter().map(|func| func.id).collect();
+    func_ids.reverse();
+    let listed = Func::list_from_ids(ctx, &func_ids)
+        .await
+        .expect("could not list funcs from ids");
+    let listed_ids: Vec<FuncId> = listed.iter().map(|func| func.id).collect();
+    assert_eq!(func_ids, listed_ids);
+    for func in funcs {
+        assert!(listed.contains(&func));
+    }
+
+    // An id without a func is an error rather than silently left out.
+    func_ids.push(FuncId::new());
+    assert!(Func::list_from_ids(ctx, &func_ids).await.is_err());
+}
+
 #[test]
 async fn get_ts_type_from_root(ctx: &mut DalContext) {
     let schema = Schema::get_by_name(ctx, "starfield")
@@ -93,3 +359,101 @@ async fn get_ts_type_from_root(ctx: &mut DalContext) {
     // TODO(nick): check that the ts type is right!
     let _ts_type = root_prop.ts_type(ctx).await.expect("could not get ts type");
 }
+
+#[test]
+async fn delete_refuses_referenced_func(ctx: &mut DalContext) {
+    let schema_variant_id = create_unlocked_variant_copy_for_schema_name(ctx, "starfield")
+        .await
+        .expect("could not create unlocked copy");
+    let func = FuncAuthoringClient::create_new_auth_func(
+        ctx,
+        Some("Paul's Test Func".to_string()),
+        schema_variant_id,
+    )
+    .await
+    .expect("unable to create func");
+
+    // The func is bound to the variant, so deleting it must fail and name the variant.
+    match Func::delete_by_id(ctx, func.id).await {
+        Err(FuncError::FuncToBeDeletedHasBindings(func_id, referencing_schema_variant_ids)) => {
+            assert_eq!(func.id, func_id);
+            assert_eq!(vec![schema_variant_id], referencing_schema_variant_ids);
+        }
+        other => panic!("expected the func to still be referenced, got: {other:?}"),
+    }
+
+    // Once unbound, the func can be deleted.
+    AuthBinding::delete_auth_binding(ctx, func.id, schema_variant_id)
+        .await
+        .expect("could not delete auth binding");
+    let deleted_func_name = Func::delete_by_id(ctx, func.id)
+        .await
+        .expect("could not delete func by id");
+    assert_eq!("Paul's Test Func", deleted_func_name.as_str());
+}
+
+#[test]
+async fn func_runner_holds_an_execution_slot_while_running(ctx: &mut DalContext) {
+    let name = "Bingo";
+    let category = "Blue Heelers";
+    let color = "#00A19B";
+
+    let schema_variant =
+        VariantAuthoringClient::create_schema_and_variant(ctx, name, None, None, category, color)
+            .await
+            .expect("could not create schema and variant");
+    let asset_func = "async function main() {
+        await new Promise(() => {});
+    }";
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        schema_variant.id(),
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(asset_func),
+    )
+    .await
+    .expect("could not save variant content");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let func = SchemaVariant::get_by_id_or_error(ctx, schema_variant.id())
+        .await
+        .expect("could not get schema variant")
+        .get_asset_func(ctx)
+        .await
+        .expect("could not get asset func");
+
+    // Nothing configures a limit in tests, so the default applies.
+    assert_eq!(
+        DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS,
+        max_concurrent_func_executions()
+    );
+
+    let (func_run_id, result_channel) = FuncRunner::run_asset_definition_func(ctx, &func)
+        .await
+        .expect("could not run asset func");
+
+    // The func never returns, so once it is dispatched it holds its slot until it is killed.
+    let mut attempts = 0;
+    while func_executions_in_flight() == 0 {
+        attempts += 1;
+        assert!(attempts < 100, "func execution never took a slot");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    ctx.veritech()
+        .kill_execution(&KillExecutionRequest {
+            execution_id: func_run_id.to_string(),
+        })
+        .await
+        .expect("could not kill execution");
+    // Wait for the killed execution to wind down so its slot is released.
+    let _ = result_channel.await;
+}
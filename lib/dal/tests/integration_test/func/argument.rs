@@ -76,3 +76,67 @@ async fn list_attribute_prototype_argument_ids(ctx: &DalContext) {
         found_func_id  // actual
     );
 }
+
+#[test]
+async fn remove_with_many_attribute_prototype_arguments(ctx: &mut DalContext) {
+    let func_id = Func::find_id_by_name(ctx, "test:falloutEntriesToGalaxies")
+        .await
+        .expect("could not perform find by name")
+        .expect("no func found");
+    let func_argument = FuncArgument::find_by_name_for_func(ctx, "entries", func_id)
+        .await
+        .expect("could not perform find by name")
+        .expect("no func argument found");
+    let existing_attribute_prototype_argument_id =
+        FuncArgument::list_attribute_prototype_argument_ids(ctx, func_argument.id)
+            .await
+            .expect("could not list attribute prototype argument ids")
+            .pop()
+            .expect("empty attribute prototype argument ids");
+    let attribute_prototype_id = AttributePrototypeArgument::prototype_id_for_argument_id(
+        ctx,
+        existing_attribute_prototype_argument_id,
+    )
+    .await
+    .expect("could not get attribute prototype id");
+
+    // Add a few more arguments referencing the same func argument.
+    let mut attribute_prototype_argument_ids = vec![existing_attribute_prototype_argument_id];
+    for _ in 0..3 {
+        let attribute_prototype_argument =
+            AttributePrototypeArgument::new(ctx, attribute_prototype_id, func_argument.id)
+                .await
+                .expect("could not create attribute prototype argument");
+        attribute_prototype_argument_ids.push(attribute_prototype_argument.id());
+    }
+    let mut found_attribute_prototype_argument_ids =
+        FuncArgument::list_attribute_prototype_argument_ids(ctx, func_argument.id)
+            .await
+            .expect("could not list attribute prototype argument ids");
+    found_attribute_prototype_argument_ids.sort();
+    attribute_prototype_argument_ids.sort();
+    assert_eq!(
+        attribute_prototype_argument_ids,       // expected
+        found_attribute_prototype_argument_ids  // actual
+    );
+
+    FuncArgument::remove(ctx, func_argument.id)
+        .await
+        .expect("could not remove func argument");
+
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    assert!(FuncArgument::find_by_name_for_func(ctx, "entries", func_id)
+        .await
+        .expect("could not perform find by name")
+        .is_none());
+    for attribute_prototype_argument_id in attribute_prototype_argument_ids {
+        assert!(
+            AttributePrototypeArgument::get_by_id(ctx, attribute_prototype_argument_id)
+                .await
+                .is_err()
+        );
+    }
+}
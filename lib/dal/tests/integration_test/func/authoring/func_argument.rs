@@ -1,5 +1,5 @@
-use dal::func::argument::{FuncArgument, FuncArgumentKind};
-use dal::func::authoring::FuncAuthoringClient;
+use dal::func::argument::{FuncArgument, FuncArgumentError, FuncArgumentKind};
+use dal::func::authoring::{FuncAuthoringClient, FuncAuthoringError};
 use dal::func::binding::attribute::AttributeBinding;
 use dal::func::binding::{EventualParent, FuncBinding};
 use dal::prop::PropPath;
@@ -217,3 +217,202 @@ async fn create_and_delete_attribute_func_with_arguments(ctx: &mut DalContext) {
         assert!(maybe_array_func_argument.is_none());
     }
 }
+
+#[test]
+async fn create_func_argument_with_mismatched_element_kind(ctx: &mut DalContext) {
+    let schema_variant_id = create_unlocked_variant_copy_for_schema_name(ctx, "katy perry")
+        .await
+        .expect("could not create unlocked copy");
+    let prop_id = Prop::find_prop_id_by_path(
+        ctx,
+        schema_variant_id,
+        &PropPath::new(["root", "si", "name"]),
+    )
+    .await
+    .expect("could not find prop");
+    let func = FuncAuthoringClient::create_new_attribute_func(
+        ctx,
+        Some("Chloe or Sam".to_owned()),
+        Some(EventualParent::SchemaVariant(schema_variant_id)),
+        dal::func::binding::AttributeFuncDestination::Prop(prop_id),
+        vec![],
+    )
+    .await
+    .expect("could not create func");
+
+    // An element kind on a string argument is rejected.
+    let result = FuncAuthoringClient::create_func_argument(
+        ctx,
+        func.id,
+        "Chloe",
+        FuncArgumentKind::String,
+        Some(FuncArgumentKind::Object),
+    )
+    .await;
+    if let Err(FuncAuthoringError::FuncArgument(
+        FuncArgumentError::ElementKindRequiresCollection(kind, element_kind),
+    )) = result
+    {
+        assert_eq!(FuncArgumentKind::String, kind);
+        assert_eq!(FuncArgumentKind::Object, element_kind);
+    } else {
+        panic!("Test should fail if we don't get an element kind requires collection error");
+    }
+
+    // An element kind on an array argument is accepted.
+    let func_argument = FuncAuthoringClient::create_func_argument(
+        ctx,
+        func.id,
+        "Sam",
+        FuncArgumentKind::Array,
+        Some(FuncArgumentKind::Object),
+    )
+    .await
+    .expect("unable to create func argument");
+
+    // Changing the kind out from under the element kind is rejected as well.
+    let result = FuncArgument::modify_by_id(ctx, func_argument.id, |func_argument| {
+        func_argument.kind = FuncArgumentKind::Boolean;
+        Ok(())
+    })
+    .await;
+    assert!(matches!(
+        result,
+        Err(FuncArgumentError::ElementKindRequiresCollection(
+            FuncArgumentKind::Boolean,
+            FuncArgumentKind::Object
+        ))
+    ));
+}
+
+#[test]
+async fn modify_func_argument_name_and_kind(ctx: &mut DalContext) {
+    let schema_variant_id = create_unlocked_variant_copy_for_schema_name(ctx, "katy perry")
+        .await
+        .expect("could not create unlocked copy");
+    let prop_id = Prop::find_prop_id_by_path(
+        ctx,
+        schema_variant_id,
+        &PropPath::new(["root", "si", "name"]),
+    )
+    .await
+    .expect("could not find prop");
+    let func = FuncAuthoringClient::create_new_attribute_func(
+        ctx,
+        Some("Chloe or Sam".to_owned()),
+        Some(EventualParent::SchemaVariant(schema_variant_id)),
+        dal::func::binding::AttributeFuncDestination::Prop(prop_id),
+        vec![],
+    )
+    .await
+    .expect("could not create func");
+    let func_argument = FuncAuthoringClient::create_func_argument(
+        ctx,
+        func.id,
+        "Chloe",
+        FuncArgumentKind::String,
+        None,
+    )
+    .await
+    .expect("unable to create func argument");
+
+    // Renaming and retyping to an array with an element kind in one update is accepted.
+    let modified = FuncArgument::modify_by_id(ctx, func_argument.id, |func_argument| {
+        func_argument.name = "Sam".to_string();
+        func_argument.kind = FuncArgumentKind::Array;
+        func_argument.element_kind = Some(FuncArgumentKind::String);
+        Ok(())
+    })
+    .await
+    .expect("unable to modify func argument");
+    assert_eq!("Sam", modified.name);
+    assert_eq!(FuncArgumentKind::Array, modified.kind);
+    assert_eq!(Some(FuncArgumentKind::String), modified.element_kind);
+
+    let fetched = FuncArgument::get_by_id_or_error(ctx, func_argument.id)
+        .await
+        .expect("could not get func argument");
+    assert_eq!(modified, fetched);
+}
+
+#[test]
+async fn list_func_arguments_ordered_by_name(ctx: &mut DalContext) {
+    let schema_variant_id = create_unlocked_variant_copy_for_schema_name(ctx, "katy perry")
+        .await
+        .expect("could not create unlocked copy");
+    let prop_id = Prop::find_prop_id_by_path(
+        ctx,
+        schema_variant_id,
+        &PropPath::new(["root", "si", "name"]),
+    )
+    .await
+    .expect("could not find prop");
+    let func = FuncAuthoringClient::create_new_attribute_func(
+        ctx,
+        Some("Chloe or Sam".to_owned()),
+        Some(EventualParent::SchemaVariant(schema_variant_id)),
+        dal::func::binding::AttributeFuncDestination::Prop(prop_id),
+        vec![],
+    )
+    .await
+    .expect("could not create func");
+    for name in ["zebra", "alpha", "mango"] {
+        FuncAuthoringClient::create_func_argument(
+            ctx,
+            func.id,
+            name,
+            FuncArgumentKind::String,
+            None,
+        )
+        .await
+        .expect("unable to create func argument");
+    }
+
+    let names: Vec<String> = FuncArgument::list_for_func_ordered(ctx, func.id)
+        .await
+        .expect("could not list func arguments")
+        .into_iter()
+        .map(|func_argument| func_argument.name)
+        .collect();
+    assert_eq!(vec!["alpha", "mango", "zebra"], names);
+}
+
+#[test]
+async fn create_func_argument_with_duplicate_name(ctx: &mut DalContext) {
+    let schema_variant_id = create_unlocked_variant_copy_for_schema_name(ctx, "katy perry")
+        .await
+        .expect("could not create unlocked copy");
+    let prop_id = Prop::find_prop_id_by_path(
+        ctx,
+        schema_variant_id,
+        &PropPath::new(["root", "si", "name"]),
+    )
+    .await
+    .expect("could not find prop");
+    let func = FuncAuthoringClient::create_new_attribute_func(
+        ctx,
+        Some("Chloe or Sam".to_owned()),
+        Some(EventualParent::SchemaVariant(schema_variant_id)),
+        dal::func::binding::AttributeFuncDestination::Prop(prop_id),
+        vec![],
+    )
+    .await
+    .expect("could not create func");
+
+    FuncArgument::new(ctx, "domain", FuncArgumentKind::Object, None, func.id)
+        .await
+        .expect("unable to create func argument");
+    let result = FuncArgument::new(ctx, "domain", FuncArgumentKind::String, None, func.id).await;
+    assert!(matches!(
+        result,
+        Err(FuncArgumentError::DuplicateName { func_id, ref name })
+            if func_id == func.id && name == "domain"
+    ));
+    assert_eq!(
+        1,
+        FuncArgument::list_for_func(ctx, func.id)
+            .await
+            .expect("could not list func arguments")
+            .len()
+    );
+}
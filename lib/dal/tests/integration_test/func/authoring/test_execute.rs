@@ -220,6 +220,52 @@ async fn test_execute_with_modified_code(ctx: &mut DalContext) {
     );
 }
 
+#[test]
+async fn test_execute_records_request_ulid(ctx: &mut DalContext) {
+    let component = create_component_for_default_schema_name_in_default_view(
+        ctx,
+        "starfield",
+        "request tracing",
+    )
+    .await
+    .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let func_id = Func::find_id_by_name(ctx, "test:createActionStarfield")
+        .await
+        .expect("could not perform find func by name")
+        .expect("no func found");
+
+    // Act as though the context was built for a request carrying a known id.
+    let request_ulid = ulid::Ulid::new();
+    ctx.update_request_ulid(Some(request_ulid));
+
+    let func_run_id = FuncAuthoringClient::test_execute_func(
+        ctx,
+        func_id,
+        serde_json::Value::Null,
+        None,
+        component.id(),
+    )
+    .await
+    .expect("could not perform test execution for func");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    wait_for_func_run_with_success_state(ctx, func_run_id).await;
+    assert_eq!(
+        Some(request_ulid), // expected
+        ctx.layer_db()
+            .func_run()
+            .get_request_ulid(func_run_id)
+            .await
+            .expect("could not get request ulid for func run")  // actual
+    );
+}
+
 async fn wait_for_func_run_with_success_state(ctx: &DalContext, func_run_id: FuncRunId) -> FuncRun {
     let seconds = 15;
 
@@ -5,6 +5,7 @@ mod audit_logging;
 mod change_set;
 mod component;
 mod connection;
+mod context;
 mod cycle_check_guard;
 mod dependent_values_update;
 mod deserialize;
@@ -21,6 +22,7 @@ mod prop;
 mod property_editor;
 mod qualifications;
 mod rebaser;
+mod refresh;
 mod resource_metadata;
 mod schema;
 mod secret;
@@ -1,15 +1,21 @@
+use std::sync::{Arc, Mutex};
+
+use dal::func::argument::{FuncArgument, FuncArgumentKind};
 use dal::pkg::export::PkgExporter;
-use dal::pkg::{import_pkg_from_pkg, ImportOptions};
+use dal::pkg::{import_pkg_from_pkg, ImportOptions, ImportProgress, ImportProgressCallback};
 use dal::prop::PropPath;
 use dal::schema::variant::authoring::VariantAuthoringClient;
 use dal::{
-    AttributeValue, AttributeValueId, Component, ComponentId, DalContext, FuncBackendKind,
+    AttributeValue, AttributeValueId, Component, ComponentId, DalContext, Func, FuncBackendKind,
     FuncBackendResponseType, Prop, PropId, SchemaVariant, SchemaVariantId,
 };
 use dal_test::expected::ExpectSchemaVariant;
 use dal_test::helpers::create_component_for_schema_variant_on_default_view;
 use dal_test::{test, Result};
-use si_pkg::{FuncSpec, FuncSpecData, PkgSpec, PropSpec, SchemaSpec, SchemaSpecData, SiPkg};
+use si_pkg::{
+    FuncArgumentKind as PkgFuncArgumentKind, FuncArgumentSpec, FuncSpec, FuncSpecData, PkgSpec,
+    PropSpec, SchemaSpec, SchemaSpecData, SiPkg,
+};
 
 #[test]
 async fn import_pkg_from_pkg_set_latest_default(ctx: &mut DalContext) -> Result<()> {
@@ -205,6 +211,138 @@ async fn prop_order_preserved(ctx: &mut DalContext) -> Result<()> {
     Ok(())
 }
 
+#[test]
+async fn export_variants_into_one_pkg(ctx: &mut DalContext) -> Result<()> {
+    let starfield_id = SchemaVariant::default_id_for_schema_name(ctx, "starfield").await?;
+    let fallout_id = SchemaVariant::default_id_for_schema_name(ctx, "fallout").await?;
+
+    let pkg = PkgExporter::export_variants(ctx, "team assets", &[starfield_id, fallout_id]).await?;
+
+    let mut schema_names = Vec::new();
+    for schema in pkg.schemas()? {
+        assert_eq!(1, schema.variants()?.len());
+        schema_names.push(schema.name().to_owned());
+    }
+    schema_names.sort();
+    assert_eq!(vec!["fallout", "starfield"], schema_names);
+
+    // Both variants use si:identity, but it is only exported once.
+    assert_eq!(1, pkg.funcs_for_name("si:identity")?.len());
+
+    Ok(())
+}
+
+#[test]
+async fn import_drops_element_kinds_of_non_collection_arguments(
+    ctx: &mut DalContext,
+) -> Result<()> {
+    // Packages built before element kinds were validated can carry one on any kind.
+    let func_name = "legacy element kinds";
+    let func_spec = FuncSpec::builder()
+        .name(func_name)
+        .unique_id(func_name)
+        .data(
+            FuncSpecData::builder()
+                .name(func_name)
+                .backend_kind(FuncBackendKind::JsAttribute)
+                .response_type(FuncBackendResponseType::String)
+                .handler("main")
+                .code_plaintext("function main() { return \"\"; }")
+                .build()?,
+        )
+        .argument(
+            FuncArgumentSpec::builder()
+                .name("name")
+                .kind(PkgFuncArgumentKind::String)
+                .element_kind(Some(PkgFuncArgumentKind::String))
+                .build()?,
+        )
+        .argument(
+            FuncArgumentSpec::builder()
+                .name("tags")
+                .kind(PkgFuncArgumentKind::Array)
+                .element_kind(Some(PkgFuncArgumentKind::String))
+                .build()?,
+        )
+        .build()?;
+    let pkg_spec = PkgSpec::builder()
+        .name(func_name)
+        .created_by("sally@systeminit.com")
+        .func(func_spec)
+        .version("0")
+        .build()?;
+    let pkg = SiPkg::load_from_spec(pkg_spec)?;
+
+    import_pkg_from_pkg(ctx, &pkg, None).await?;
+
+    let func_id = Func::find_id_by_name(ctx, func_name)
+        .await?
+        .expect("func not imported");
+    let argument = FuncArgument::find_by_name_for_func(ctx, "name", func_id)
+        .await?
+        .expect("argument not imported");
+    assert_eq!(FuncArgumentKind::String, argument.kind);
+    assert_eq!(None, argument.element_kind);
+    let argument = FuncArgument::find_by_name_for_func(ctx, "tags", func_id)
+        .await?
+        .expect("argument not imported");
+    assert_eq!(FuncArgumentKind::Array, argument.kind);
+    assert_eq!(Some(FuncArgumentKind::String), argument.element_kind);
+
+    Ok(())
+}
+
+#[test]
+async fn import_reports_progress_for_each_schema(ctx: &mut DalContext) -> Result<()> {
+    let mut variant_ids = Vec::new();
+    for asset_name in ["progress one", "progress two"] {
+        let variant = VariantAuthoringClient::create_schema_and_variant(
+            ctx,
+            asset_name,
+            None,
+            None,
+            "Integration Tests",
+            "#00b0b0",
+        )
+        .await?;
+        variant_ids.push(variant.id());
+    }
+    let pkg = PkgExporter::export_variants(ctx, "progress", &variant_ids).await?;
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let recorder = reported.clone();
+    import_pkg_from_pkg(
+        ctx,
+        &pkg,
+        Some(ImportOptions {
+            progress: Some(ImportProgressCallback::new(move |progress| {
+                recorder.lock().expect("lock poisoned").push(progress);
+            })),
+            ..Default::default()
+        }),
+    )
+    .await?;
+
+    let reported = reported.lock().expect("lock poisoned").clone();
+    assert_eq!(
+        vec![
+            ImportProgress {
+                schema_name: "progress one".to_string(),
+                index: 1,
+                total: 2,
+            },
+            ImportProgress {
+                schema_name: "progress two".to_string(),
+                index: 2,
+                total: 2,
+            },
+        ],
+        reported
+    );
+
+    Ok(())
+}
+
 async fn variant_prop_names(
     ctx: &mut DalContext,
     variant_id: SchemaVariantId,
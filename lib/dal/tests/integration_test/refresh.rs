@@ -0,0 +1,192 @@
+use std::time::Duration;
+
+use dal::action::prototype::ActionKind;
+use dal::action::Action;
+use dal::component::refresh::{RefreshSchedule, RefreshSummary};
+use dal::job::consumer::{JobConsumer, JobConsumerMetadata};
+use dal::job::definition::RefreshJob;
+use dal::{Component, DalContext};
+use dal_test::helpers::create_component_for_default_schema_name_in_default_view;
+use dal_test::helpers::ChangeSetTestHelpers;
+use dal_test::test;
+use pretty_assertions_sorted::assert_eq;
+
+#[test]
+async fn new_for_all_components(ctx: &mut DalContext) {
+    let mut component_ids = Vec::new();
+    for name in ["shake it off", "style", "wildest dreams"] {
+        let component =
+            create_component_for_default_schema_name_in_default_view(ctx, "swifty", name)
+                .await
+                .expect("could not create component");
+        component_ids.push(component.id());
+    }
+    let to_delete =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "delicate")
+            .await
+            .expect("could not create component")
+            .set_to_delete(ctx, true)
+            .await
+            .expect("could not set component to delete");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let job = RefreshJob::new_for_all_components(ctx)
+        .await
+        .expect("could not create refresh job");
+
+    component_ids.sort();
+    assert_eq!(component_ids.as_slice(), job.component_ids());
+    assert!(!job.component_ids().contains(&to_delete.id()));
+
+    job.run(ctx).await.expect("could not run refresh job");
+    ctx.update_snapshot_to_visibility()
+        .await
+        .expect("could not update snapshot to visibility");
+
+    for component_id in component_ids {
+        let refresh_actions =
+            Action::find_for_kind_and_component_id(ctx, component_id, ActionKind::Refresh)
+                .await
+                .expect("could not find refresh actions");
+        assert_eq!(1, refresh_actions.len());
+    }
+}
+
+#[test]
+async fn recurring_reschedules_until_cancelled(ctx: &mut DalContext) {
+    let component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "shake it off")
+            .await
+            .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+
+    let schedule = RefreshSchedule::new(ctx, vec![component.id()], Duration::from_secs(3600))
+        .await
+        .expect("could not schedule recurring refresh");
+
+    // A new schedule is due right away and is only claimed once.
+    let enqueued = RefreshJob::enqueue_due(ctx)
+        .await
+        .expect("could not enqueue due refreshes");
+    assert!(enqueued.contains(&schedule.id()));
+    let run = ctx
+        .txns()
+        .await
+        .expect("could not get txns")
+        .job_queue()
+        .fetch_job()
+        .await
+        .expect("run was not enqueued");
+    assert_eq!("RefreshJob", run.type_name());
+    let enqueued = RefreshJob::enqueue_due(ctx)
+        .await
+        .expect("could not enqueue due refreshes");
+    assert!(!enqueued.contains(&schedule.id()));
+
+    // Completing a run pushes the next one out by the interval from when it completed.
+    let claimed = RefreshSchedule::get_by_id(ctx, schedule.id())
+        .await
+        .expect("could not get schedule")
+        .expect("schedule not found");
+    let job = RefreshJob::for_schedule(&claimed);
+    assert_eq!(Some(schedule.id()), job.schedule_id());
+    assert!(job.reschedule(ctx).await.expect("could not reschedule"));
+    let mut schedule = RefreshSchedule::get_by_id(ctx, schedule.id())
+        .await
+        .expect("could not get schedule")
+        .expect("schedule not found");
+    assert!(schedule.next_run_at() > claimed.next_run_at());
+
+    schedule
+        .cancel(ctx)
+        .await
+        .expect("could not cancel schedule");
+    assert!(schedule.is_cancelled());
+
+    // A cancelled schedule neither reschedules nor refreshes.
+    assert!(!job.reschedule(ctx).await.expect("could not reschedule"));
+
+    job.run(ctx).await.expect("could not run refresh job");
+    ctx.update_snapshot_to_visibility()
+        .await
+        .expect("could not update snapshot to visibility");
+    let refresh_actions =
+        Action::find_for_kind_and_component_id(ctx, component.id(), ActionKind::Refresh)
+            .await
+            .expect("could not find refresh actions");
+    assert!(refresh_actions.is_empty());
+}
+
+#[test]
+async fn summary_records_drift(ctx: &mut DalContext) {
+    // Creating the component enqueues its create action, which triggers a refresh once it
+    // succeeds on HEAD.
+    let component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "shake it off")
+            .await
+            .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("could not commit and update snapshot to visibility");
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+    ChangeSetTestHelpers::wait_for_actions_to_run(ctx)
+        .await
+        .expect("deadline for actions to run exceeded");
+
+    // The refresh after create returns the same payload, so nothing drifted.
+    let summaries = RefreshSummary::list_for_component(ctx, component.id())
+        .await
+        .expect("could not list refresh summaries");
+    let summary = summaries.first().expect("no refresh summary recorded");
+    assert_eq!(component.id(), summary.component_id());
+    assert!(!summary.drifted());
+
+    // Summaries are scoped to the change set they were recorded in, so none show up in a fork.
+    // Clear the resource there so that the next refresh produces a different payload.
+    ChangeSetTestHelpers::fork_from_head_change_set(ctx)
+        .await
+        .expect("could not fork change set");
+    assert!(RefreshSummary::list_for_component(ctx, component.id())
+        .await
+        .expect("could not list refresh summaries")
+        .is_empty());
+    Component::get_by_id(ctx, component.id())
+        .await
+        .expect("could not get component")
+        .clear_resource(ctx)
+        .await
+        .expect("could not clear resource");
+    RefreshJob::new(
+        ctx.access_builder(),
+        *ctx.visibility(),
+        vec![component.id()],
+    )
+    .run(ctx)
+    .await
+    .expect("could not run refresh job");
+    ctx.update_snapshot_to_visibility()
+        .await
+        .expect("could not update snapshot to visibility");
+    ChangeSetTestHelpers::apply_change_set_to_base(ctx)
+        .await
+        .expect("could not apply change set");
+    ChangeSetTestHelpers::wait_for_actions_to_run(ctx)
+        .await
+        .expect("deadline for actions to run exceeded");
+
+    let summaries = RefreshSummary::list_for_component(ctx, component.id())
+        .await
+        .expect("could not list refresh summaries");
+    assert_eq!(2, summaries.len());
+    let latest = summaries.first().expect("no refresh summary recorded");
+    assert_eq!(component.id(), latest.component_id());
+    assert!(latest.drifted());
+    assert_eq!(None, latest.previous_resource_hash());
+    assert!(latest.new_resource_hash().is_some());
+}
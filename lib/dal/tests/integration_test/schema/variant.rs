@@ -3,7 +3,10 @@ use dal::{
     schema::{variant::leaves::LeafKind, SchemaVariant},
     ComponentType, DalContext, Func, Prop, Schema,
 };
-use dal_test::{helpers::create_schema, test};
+use dal_test::{
+    helpers::{create_component_for_default_schema_name_in_default_view, create_schema},
+    test,
+};
 use pretty_assertions_sorted::assert_eq;
 
 mod authoring;
@@ -248,6 +251,40 @@ async fn list_user_facing_works(ctx: &DalContext) {
         .expect("could not list user facing schema variants");
 }
 
+#[test]
+async fn has_components(ctx: &DalContext) {
+    let schema = Schema::get_by_name(ctx, "starfield")
+        .await
+        .expect("schema not found");
+    let schema_variant_id = schema
+        .get_default_schema_variant_id(ctx)
+        .await
+        .expect("unable to get schema variant")
+        .expect("schema variant not found");
+
+    let has_components = SchemaVariant::has_components(ctx, schema_variant_id)
+        .await
+        .expect("could not check for components");
+    let component_ids = SchemaVariant::list_component_ids(ctx, schema_variant_id)
+        .await
+        .expect("could not list component ids");
+    assert!(!has_components);
+    assert_eq!(!component_ids.is_empty(), has_components);
+
+    create_component_for_default_schema_name_in_default_view(ctx, "starfield", "tes vi")
+        .await
+        .expect("could not create component");
+
+    let has_components = SchemaVariant::has_components(ctx, schema_variant_id)
+        .await
+        .expect("could not check for components");
+    let component_ids = SchemaVariant::list_component_ids(ctx, schema_variant_id)
+        .await
+        .expect("could not list component ids");
+    assert!(has_components);
+    assert_eq!(!component_ids.is_empty(), has_components);
+}
+
 fn prepare_for_assertion(expected: &[&str], all_funcs: &[Func]) -> (Vec<String>, Vec<String>) {
     let expected = expected.iter().map(|s| s.to_string()).collect();
 
@@ -1,3 +1,4 @@
+mod apply_defaults_from_component;
 mod clone_variant;
 mod create_variant;
 mod delete_unlocked_variant;
@@ -0,0 +1,149 @@
+use dal::prop::PropPath;
+use dal::schema::variant::authoring::VariantAuthoringClient;
+use dal::{AttributeValue, DalContext, Prop};
+use dal_test::helpers::{
+    create_component_for_schema_variant_on_default_view, ChangeSetTestHelpers,
+};
+use dal_test::{color_eyre::Result, test};
+use pretty_assertions_sorted::assert_eq;
+use serde_json::json;
+use si_pkg::MergeSkip;
+
+#[test]
+async fn apply_defaults_from_component(ctx: &mut DalContext) -> Result<()> {
+    let code = "function main() {
+        const nickname = new PropBuilder().setName(\"nickname\").setKind(\"string\").build();
+        const age = new PropBuilder().setName(\"age\").setKind(\"integer\").build();
+        const breed = new PropBuilder().setName(\"breed\").setKind(\"string\").build();
+        return new AssetBuilder().addProp(nickname).addProp(age).addProp(breed).build();
+    }";
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        "Bandit",
+        None,
+        None,
+        "Blue Heelers",
+        "#00A19B",
+        code,
+    )
+    .await?;
+
+    // Configure a reference component, leaving "breed" unset.
+    let reference =
+        create_component_for_schema_variant_on_default_view(ctx, schema_variant.id()).await?;
+    for (name, value) in [("nickname", json!("Dad")), ("age", json!(40))] {
+        let av_id = reference
+            .attribute_value_for_prop(ctx, &["root", "domain", name])
+            .await?;
+        AttributeValue::update(ctx, av_id, Some(value)).await?;
+    }
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    let mut promoted = VariantAuthoringClient::apply_defaults_from_component(
+        ctx,
+        schema_variant.id(),
+        reference.id(),
+    )
+    .await?;
+    promoted.sort();
+
+    let nickname_prop_id = Prop::find_prop_id_by_path(
+        ctx,
+        schema_variant.id(),
+        &PropPath::new(["root", "domain", "nickname"]),
+    )
+    .await?;
+    let age_prop_id = Prop::find_prop_id_by_path(
+        ctx,
+        schema_variant.id(),
+        &PropPath::new(["root", "domain", "age"]),
+    )
+    .await?;
+    let mut expected = vec![nickname_prop_id, age_prop_id];
+    expected.sort();
+    assert_eq!(expected, promoted);
+
+    // New components of the variant start out with the promoted values.
+    let component =
+        create_component_for_schema_variant_on_default_view(ctx, schema_variant.id()).await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    for (name, expected) in [
+        ("nickname", Some(json!("Dad"))),
+        ("age", Some(json!(40))),
+        ("breed", None),
+    ] {
+        let av_id = component
+            .attribute_value_for_prop(ctx, &["root", "domain", name])
+            .await?;
+        let value = AttributeValue::get_by_id(ctx, av_id)
+            .await?
+            .value(ctx)
+            .await?;
+        assert_eq!(expected, value, "unexpected value for {name}");
+    }
+
+    Ok(())
+}
+
+#[test]
+async fn regenerate_reports_promoted_defaults_it_drops(ctx: &mut DalContext) -> Result<()> {
+    let code = "function main() {
+        const nickname = new PropBuilder().setName(\"nickname\").setKind(\"string\").build();
+        const breed = new PropBuilder().setName(\"breed\").setKind(\"string\").setDefaultValue(\"heeler\").build();
+        return new AssetBuilder().addProp(nickname).addProp(breed).build();
+    }";
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        "Bandit",
+        None,
+        None,
+        "Blue Heelers",
+        "#00A19B",
+        code,
+    )
+    .await?;
+
+    let reference =
+        create_component_for_schema_variant_on_default_view(ctx, schema_variant.id()).await?;
+    let av_id = reference
+        .attribute_value_for_prop(ctx, &["root", "domain", "nickname"])
+        .await?;
+    AttributeValue::update(ctx, av_id, Some(json!("Dad"))).await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    VariantAuthoringClient::apply_defaults_from_component(ctx, schema_variant.id(), reference.id())
+        .await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    let (regenerated_variant_id, skips) =
+        VariantAuthoringClient::regenerate_variant_with_skips(ctx, schema_variant.id()).await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    // The promoted default is not in the asset func, so it is dropped and reported. The default
+    // the asset func sets itself survives and is not reported.
+    let nickname_path = PropPath::new(["root", "domain", "nickname"]);
+    let breed_path = PropPath::new(["root", "domain", "breed"]);
+    let dropped: Vec<&MergeSkip> = skips
+        .iter()
+        .filter(|skip| matches!(skip, MergeSkip::PropDefaultValueDropped(_)))
+        .collect();
+    assert_eq!(
+        vec![&MergeSkip::PropDefaultValueDropped(
+            nickname_path.as_str().to_owned()
+        )],
+        dropped
+    );
+
+    let nickname_prop_id =
+        Prop::find_prop_id_by_path(ctx, regenerated_variant_id, &nickname_path).await?;
+    assert_eq!(None, Prop::default_value(ctx, nickname_prop_id).await?);
+    let breed_prop_id =
+        Prop::find_prop_id_by_path(ctx, regenerated_variant_id, &breed_path).await?;
+    assert_eq!(
+        Some(json!("heeler")),
+        Prop::default_value(ctx, breed_prop_id).await?
+    );
+
+    Ok(())
+}
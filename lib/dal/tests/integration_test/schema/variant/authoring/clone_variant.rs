@@ -1,5 +1,7 @@
+use dal::func::authoring::FuncAuthoringClient;
 use dal::schema::variant::authoring::VariantAuthoringClient;
-use dal::{ChangeSet, DalContext, Schema, SchemaVariant};
+use dal::schema::variant::DEFAULT_SCHEMA_VARIANT_COLOR;
+use dal::{ChangeSet, DalContext, Func, Schema, SchemaVariant};
 use dal_test::test;
 
 #[test]
@@ -59,3 +61,120 @@ async fn clone_variant(ctx: &mut DalContext) {
         default_schema_variant.expect("unable to unwrap default schema variant id")
     );
 }
+
+#[test]
+async fn clone_variant_asset_func_is_independent(ctx: &mut DalContext) {
+    let new_change_set = ChangeSet::fork_head(ctx, "new change set")
+        .await
+        .expect("could not create new change set");
+    ctx.update_visibility_and_snapshot_to_visibility(new_change_set.id)
+        .await
+        .expect("could not update visibility");
+
+    let schema = Schema::get_by_name(ctx, "dummy-secret")
+        .await
+        .expect("schema not found");
+    let existing_variant_id = schema
+        .get_default_schema_variant_id(ctx)
+        .await
+        .expect("Unable to find the default schema variant id")
+        .expect("no default schema variant id");
+    let existing_variant = SchemaVariant::get_by_id_or_error(ctx, existing_variant_id)
+        .await
+        .expect("unable to lookup the default schema variant");
+    let existing_asset_func = existing_variant
+        .get_asset_func(ctx)
+        .await
+        .expect("unable to get asset func");
+    let existing_code = existing_asset_func
+        .code_plaintext()
+        .expect("unable to decode code");
+
+    let (new_schema_variant, _) = VariantAuthoringClient::new_schema_with_cloned_variant(
+        ctx,
+        existing_variant_id,
+        format!("{}-Clone", schema.name()),
+    )
+    .await
+    .expect("unable to clone the schema variant");
+    let new_asset_func_id = new_schema_variant
+        .asset_func_id()
+        .expect("clone has no asset func");
+    assert_ne!(existing_asset_func.id, new_asset_func_id);
+
+    let new_code = "function main() {\n  return new AssetBuilder().build();\n}";
+    FuncAuthoringClient::save_code(ctx, new_asset_func_id, new_code.to_string())
+        .await
+        .expect("unable to save code");
+
+    let cloned_asset_func = Func::get_by_id_or_error(ctx, new_asset_func_id)
+        .await
+        .expect("unable to get cloned asset func");
+    assert_eq!(
+        Some(new_code.to_string()),
+        cloned_asset_func
+            .code_plaintext()
+            .expect("unable to decode code")
+    );
+
+    let existing_asset_func = Func::get_by_id_or_error(ctx, existing_asset_func.id)
+        .await
+        .expect("unable to get original asset func");
+    assert_eq!(
+        existing_code,
+        existing_asset_func
+            .code_plaintext()
+            .expect("unable to decode code")
+    );
+}
+
+#[test]
+async fn clone_variant_with_malformed_color(ctx: &mut DalContext) {
+    let new_change_set = ChangeSet::fork_head(ctx, "new change set")
+        .await
+        .expect("could not create new change set");
+    ctx.update_visibility_and_snapshot_to_visibility(new_change_set.id)
+        .await
+        .expect("could not update visibility");
+
+    let schema = Schema::get_by_name(ctx, "dummy-secret")
+        .await
+        .expect("schema not found");
+    let schema_variant_id = schema
+        .get_default_schema_variant_id(ctx)
+        .await
+        .expect("Unable to find the default schema variant id")
+        .expect("unable to unwrap schema variant id");
+    let existing_variant = SchemaVariant::get_by_id_or_error(ctx, schema_variant_id)
+        .await
+        .expect("unable to lookup the default schema variant");
+
+    // Simulate a bad import by storing a color that is not a hex string.
+    existing_variant
+        .set_color(ctx, "definitely not a color")
+        .await
+        .expect("unable to set color");
+    assert_eq!(
+        DEFAULT_SCHEMA_VARIANT_COLOR,
+        existing_variant
+            .get_color(ctx)
+            .await
+            .expect("unable to get color")
+    );
+
+    let (new_schema_variant, _) = VariantAuthoringClient::new_schema_with_cloned_variant(
+        ctx,
+        schema_variant_id,
+        format!("{}-Clone", schema.name()),
+    )
+    .await
+    .expect("unable to clone the schema variant");
+
+    assert_eq!(
+        DEFAULT_SCHEMA_VARIANT_COLOR,
+        new_schema_variant
+            .get_color(ctx)
+            .await
+            .expect("unable to get color")
+    );
+}
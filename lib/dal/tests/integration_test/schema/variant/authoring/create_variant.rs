@@ -1,6 +1,9 @@
 use dal::func::FuncKind;
+use dal::prop::PropPath;
+use dal::property_editor::schema::WidgetKind;
 use dal::schema::variant::authoring::VariantAuthoringClient;
-use dal::{ChangeSet, DalContext, Func, FuncBackendResponseType};
+use dal::schema::variant::authoring::VariantAuthoringError;
+use dal::{ChangeSet, DalContext, Func, FuncBackendResponseType, Prop, SchemaVariantError};
 use dal_test::test;
 
 #[test]
@@ -73,3 +76,221 @@ async fn create_variant(ctx: &mut DalContext) {
         func.code_plaintext().expect("Unable to get code plaintext")
     );
 }
+
+#[test]
+async fn create_variant_with_duplicate_name(ctx: &mut DalContext) {
+    let new_change_set = ChangeSet::fork_head(ctx, "new change set")
+        .await
+        .expect("could not create new change set");
+    ctx.update_visibility_and_snapshot_to_visibility(new_change_set.id)
+        .await
+        .expect("could not update visibility");
+
+    let asset_name = "paulsTestAsset".to_string();
+    let category = "Integration Tests".to_string();
+    let color = "#00b0b0".to_string();
+    VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        asset_name.clone(),
+        None,
+        None,
+        category.clone(),
+        color.clone(),
+    )
+    .await
+    .expect("Unable to create new asset");
+
+    // By default, reusing a schema name is an error.
+    let result = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        asset_name.clone(),
+        None,
+        None,
+        category.clone(),
+        color.clone(),
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(VariantAuthoringError::DuplicatedSchemaName(name)) if name == asset_name
+    ));
+
+    // Opting in to disambiguation appends a suffix instead.
+    for expected_name in ["paulsTestAsset-1", "paulsTestAsset-2"] {
+        let variant = VariantAuthoringClient::create_schema_and_variant_with_available_name(
+            ctx,
+            &asset_name,
+            None,
+            None,
+            category.clone(),
+            color.clone(),
+        )
+        .await
+        .expect("Unable to create new asset");
+        let schema = variant
+            .schema(ctx)
+            .await
+            .expect("Unable to get the schema for the variant");
+        assert_eq!(expected_name, schema.name());
+    }
+}
+
+#[test]
+async fn create_variant_infers_default_widgets(ctx: &mut DalContext) {
+    let new_change_set = ChangeSet::fork_head(ctx, "new change set")
+        .await
+        .expect("could not create new change set");
+    ctx.update_visibility_and_snapshot_to_visibility(new_change_set.id)
+        .await
+        .expect("could not update visibility");
+
+    let code = r#"function main() {
+        const stringProp = new PropBuilder().setName("aString").setKind("string").build();
+        const booleanProp = new PropBuilder().setName("aBoolean").setKind("boolean").build();
+        const integerProp = new PropBuilder().setName("anInteger").setKind("integer").build();
+        const objectProp = new PropBuilder()
+            .setName("anObject")
+            .setKind("object")
+            .addChild(new PropBuilder().setName("child").setKind("string").build())
+            .build();
+        const arrayProp = new PropBuilder()
+            .setName("anArray")
+            .setKind("array")
+            .setEntry(new PropBuilder().setName("entry").setKind("string").build())
+            .build();
+        const mapProp = new PropBuilder()
+            .setName("aMap")
+            .setKind("map")
+            .setEntry(new PropBuilder().setName("entry").setKind("string").build())
+            .build();
+        const explicitProp = new PropBuilder()
+            .setName("explicit")
+            .setKind("string")
+            .setWidget(new PropWidgetDefinitionBuilder().setKind("textArea").build())
+            .build();
+        return new AssetBuilder()
+            .addProp(stringProp)
+            .addProp(booleanProp)
+            .addProp(integerProp)
+            .addProp(objectProp)
+            .addProp(arrayProp)
+            .addProp(mapProp)
+            .addProp(explicitProp)
+            .build();
+    }"#;
+
+    let variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        "widgetInference",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+        code,
+    )
+    .await
+    .expect("Unable to create new asset");
+
+    for (name, expected) in [
+        ("aString", WidgetKind::Text),
+        ("aBoolean", WidgetKind::Checkbox),
+        ("anInteger", WidgetKind::Text),
+        ("anObject", WidgetKind::Header),
+        ("anArray", WidgetKind::Array),
+        ("aMap", WidgetKind::Map),
+        // Explicit widgets always win over the inferred default.
+        ("explicit", WidgetKind::TextArea),
+    ] {
+        let prop =
+            Prop::find_prop_by_path(ctx, variant.id(), &PropPath::new(["root", "domain", name]))
+                .await
+                .expect("could not find prop");
+        assert_eq!(expected, prop.widget_kind, "unexpected widget for {name}");
+    }
+}
+
+#[test]
+async fn create_variant_rejects_invalid_doc_link(ctx: &mut DalContext) {
+    let new_change_set = ChangeSet::fork_head(ctx, "new change set")
+        .await
+        .expect("could not create new change set");
+    ctx.update_visibility_and_snapshot_to_visibility(new_change_set.id)
+        .await
+        .expect("could not update visibility");
+
+    let code = r#"function main() {
+        const prop = new PropBuilder()
+            .setName("badLink")
+            .setKind("string")
+            .setDocLink("not a url")
+            .build();
+        return new AssetBuilder().addProp(prop).build();
+    }"#;
+
+    let result = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        "docLinkValidation",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+        code,
+    )
+    .await;
+
+    match result {
+        Err(VariantAuthoringError::SchemaVariant(SchemaVariantError::InvalidPropDocLink(
+            prop_name,
+            doc_link,
+            _,
+        ))) => {
+            assert_eq!("badLink", prop_name);
+            assert_eq!("not a url", doc_link);
+        }
+        other => panic!("expected an invalid doc link error, got: {other:?}"),
+    }
+}
+
+#[test]
+async fn create_variant_validates_hex_color(ctx: &mut DalContext) {
+    let new_change_set = ChangeSet::fork_head(ctx, "new change set")
+        .await
+        .expect("could not create new change set");
+    ctx.update_visibility_and_snapshot_to_visibility(new_change_set.id)
+        .await
+        .expect("could not update visibility");
+
+    // A color without the leading '#' is accepted and stored with it.
+    let variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "hexColorValid",
+        None,
+        None,
+        "Integration Tests",
+        "00b0b0",
+    )
+    .await
+    .expect("Unable to create new asset");
+    assert_eq!("#00b0b0", variant.color());
+
+    for (name, color) in [
+        ("hexColorInvalidLength", "#00b0b"),
+        ("hexColorInvalidCharacters", "#00b0bz"),
+    ] {
+        match VariantAuthoringClient::create_schema_and_variant(
+            ctx,
+            name,
+            None,
+            None,
+            "Integration Tests",
+            color,
+        )
+        .await
+        {
+            Err(VariantAuthoringError::InvalidHexColor(invalid_color)) => {
+                assert_eq!(color, invalid_color);
+            }
+            other => panic!("expected an invalid hex color error for {color}, got: {other:?}"),
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use dal::schema::variant::authoring::VariantAuthoringClient;
-use dal::{DalContext, Func, SchemaVariant};
-use dal_test::helpers::ChangeSetTestHelpers;
+use dal::schema::variant::authoring::{VariantAuthoringClient, VariantAuthoringError};
+use dal::{Component, DalContext, Func, SchemaVariant};
+use dal_test::helpers::{
+    create_component_for_schema_variant_on_default_view, ChangeSetTestHelpers,
+};
 use dal_test::test;
 
 #[test]
@@ -64,3 +66,58 @@ async fn delete_unlocked_variant(ctx: &mut DalContext) {
     let res = SchemaVariant::cleanup_unlocked_variant(ctx, unlocked_schema_variant.id).await;
     assert!(res.is_ok());
 }
+
+#[test]
+async fn delete_variant_refuses_while_components_exist(ctx: &mut DalContext) {
+    let variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "chainsawVariant",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+    )
+    .await
+    .expect("Unable to create new asset");
+    let asset_func_id = variant
+        .asset_func_id()
+        .expect("unable to get asset func id from variant");
+
+    let component = create_component_for_schema_variant_on_default_view(ctx, variant.id())
+        .await
+        .expect("could not create component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("unable to commit");
+
+    match VariantAuthoringClient::delete_variant(ctx, variant.id()).await {
+        Err(VariantAuthoringError::VariantHasComponents(schema_variant_id, count)) => {
+            assert_eq!(variant.id(), schema_variant_id);
+            assert_eq!(1, count);
+        }
+        other => panic!("expected the variant to still have components, got: {other:?}"),
+    }
+
+    Component::remove(ctx, component.id())
+        .await
+        .expect("could not remove component");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("unable to commit");
+
+    VariantAuthoringClient::delete_variant(ctx, variant.id())
+        .await
+        .expect("unable to delete variant");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("unable to commit");
+
+    assert!(SchemaVariant::get_by_id(ctx, variant.id())
+        .await
+        .expect("unable to look up variant")
+        .is_none());
+    assert!(Func::get_by_id(ctx, asset_func_id)
+        .await
+        .expect("unable to look up asset func")
+        .is_none());
+}
@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
 use dal::func::argument::{FuncArgument, FuncArgumentId};
 use dal::func::binding::attribute::AttributeBinding;
 use dal::func::binding::{
@@ -5,16 +9,31 @@ use dal::func::binding::{
 };
 use dal::func::intrinsics::IntrinsicFunc;
 use dal::prop::PropPath;
-use dal::schema::variant::authoring::VariantAuthoringClient;
+use dal::schema::variant::authoring::{
+    AssetFuncExecutionOptions, RegenerateStrategy, VariantAuthoringClient, VariantAuthoringError,
+};
 use dal::{
-    Component, ComponentType, DalContext, Func, FuncId, InputSocket, OutputSocket, OutputSocketId,
-    Prop, PropId, SchemaVariant, SchemaVariantId, SocketArity,
+    Component, ComponentType, DalContext, Func, FuncBackendKind, FuncBackendResponseType, FuncId,
+    InputSocket, OutputSocket, OutputSocketId, Prop, PropId, SchemaVariant, SchemaVariantId,
+    SocketArity,
 };
 use dal_test::helpers::{
     connect_components_with_socket_names, create_component_for_default_schema_name_in_default_view,
-    ChangeSetTestHelpers,
+    create_component_for_schema_variant_on_default_view, ChangeSetTestHelpers,
+};
+use dal_test::tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    Layer, Registry,
 };
 use dal_test::{color_eyre::Result, test};
+use si_events::FuncRunState;
+use si_pkg::{MergeSkip, PropSpec, PropSpecKind, SchemaVariantSpec};
+use telemetry::tracing::{
+    field::{Field, Visit},
+    instrument::WithSubscriber,
+    Event, Level, Subscriber,
+};
+use veritech_client::{FunctionResult, KillExecutionRequest};
 
 #[test]
 async fn regenerate_variant(ctx: &mut DalContext) -> Result<()> {
@@ -70,6 +89,447 @@ async fn regenerate_variant(ctx: &mut DalContext) -> Result<()> {
     Ok(())
 }
 
+#[test]
+async fn regenerate_variant_surfaces_asset_func_errors(ctx: &mut DalContext) -> Result<()> {
+    let name = "Bingo";
+    let category = "Blue Heelers";
+    let color = "#00A19B";
+
+    let schema_variant =
+        VariantAuthoringClient::create_schema_and_variant(ctx, name, None, None, category, color)
+            .await?;
+    let asset_func = "function main() {
+        throw new Error(\"Bingo is hiding\");
+    }";
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        schema_variant.id(),
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(asset_func),
+    )
+    .await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    // The thrown error comes back without location details, so it uses the plain variant.
+    match VariantAuthoringClient::regenerate_variant(ctx, schema_variant.id()).await {
+        Err(VariantAuthoringError::FuncExecutionFailure(message)) => {
+            assert!(message.contains("Bingo is hiding"), "message: {message}");
+        }
+        other => panic!("expected an asset func execution failure, got: {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+async fn regenerate_variant_reports_merge_skips(ctx: &mut DalContext) -> Result<()> {
+    let name = "Chilli";
+    let category = "Blue Heelers";
+    let color = "#00A19B";
+
+    let original_code = "function main() {
+        const prop = new PropBuilder().setName(\"nickname\").setKind(\"string\").build();
+        return new AssetBuilder().addProp(prop).build();
+    }";
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        name,
+        None,
+        None,
+        category,
+        color,
+        original_code,
+    )
+    .await?;
+
+    // Drop the prop from the asset func so that it can no longer be merged from the existing
+    // variant.
+    let updated_code = "function main() {
+        return new AssetBuilder().build();
+    }";
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        schema_variant.id(),
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(updated_code),
+    )
+    .await?;
+
+    let (regenerated_variant_id, skips) =
+        VariantAuthoringClient::regenerate_variant_with_skips(ctx, schema_variant.id()).await?;
+    assert_eq!(schema_variant.id(), regenerated_variant_id);
+
+    let missing_path = PropPath::new(["root", "domain", "nickname"]);
+    assert!(
+        skips.contains(&MergeSkip::PropMissing(missing_path.as_str().to_owned())),
+        "skips: {skips:?}"
+    );
+
+    Ok(())
+}
+
+/// Records the fields of every warning, keyed by field name.
+#[derive(Clone, Default)]
+struct WarningCapture(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+impl<S: Subscriber> Layer<S> for WarningCapture {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() != Level::WARN {
+            return;
+        }
+        let mut fields = FieldCapture::default();
+        event.record(&mut fields);
+        self.0.lock().expect("poisoned lock").push(fields.0);
+    }
+}
+
+#[derive(Default)]
+struct FieldCapture(HashMap<String, String>);
+
+impl Visit for FieldCapture {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_owned(), format!("{value:?}"));
+    }
+}
+
+#[test]
+async fn regenerate_variant_warns_about_merge_skips(ctx: &mut DalContext) -> Result<()> {
+    let name = "Bandit";
+    let category = "Blue Heelers";
+    let color = "#00A19B";
+
+    let original_code = "function main() {
+        const prop = new PropBuilder().setName(\"nickname\").setKind(\"string\").build();
+        return new AssetBuilder().addProp(prop).build();
+    }";
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        name,
+        None,
+        None,
+        category,
+        color,
+        original_code,
+    )
+    .await?;
+
+    let updated_code = "function main() {
+        return new AssetBuilder().build();
+    }";
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        schema_variant.id(),
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(updated_code),
+    )
+    .await?;
+
+    let capture = WarningCapture::default();
+    let subscriber = Registry::default().with(capture.clone());
+    let (regenerated_variant_id, _) =
+        VariantAuthoringClient::regenerate_variant_with_skips(ctx, schema_variant.id())
+            .with_subscriber(subscriber)
+            .await?;
+
+    let warnings = capture.0.lock().expect("poisoned lock").clone();
+    let warning = warnings
+        .iter()
+        .find(|fields| fields.get("si.merge_skip.kind").map(String::as_str) == Some("prop_missing"))
+        .unwrap_or_else(|| panic!("no merge skip warning was recorded: {warnings:?}"));
+    assert_eq!(
+        Some("prototype not carried over from existing schema variant"),
+        warning.get("message").map(String::as_str)
+    );
+    assert_eq!(
+        Some(regenerated_variant_id.to_string()),
+        warning.get("si.schema_variant.id").cloned()
+    );
+    assert_eq!(
+        Some("root/domain/nickname"),
+        warning.get("si.merge_skip.path").map(String::as_str)
+    );
+    assert_eq!(None, warning.get("si.merge_skip.detail"));
+    assert_eq!(None, warning.get("si.merge_skip.func_unique_id"));
+
+    Ok(())
+}
+
+#[test]
+async fn preview_regenerate_variant_matches_regenerate(ctx: &mut DalContext) -> Result<()> {
+    let name = "Bluey";
+    let category = "Blue Heelers";
+    let color = "#00A19B";
+
+    let original_code = "function main() {
+        const prop = new PropBuilder().setName(\"nickname\").setKind(\"string\").build();
+        return new AssetBuilder().addProp(prop).build();
+    }";
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        name,
+        None,
+        None,
+        category,
+        color,
+        original_code,
+    )
+    .await?;
+    let updated_code = "function main() {
+        return new AssetBuilder().build();
+    }";
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        schema_variant.id(),
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(updated_code),
+    )
+    .await?;
+
+    // Without components the variant is regenerated in place.
+    let preview =
+        VariantAuthoringClient::preview_regenerate_variant(ctx, schema_variant.id()).await?;
+    assert_eq!(RegenerateStrategy::RegenerateInPlace, preview.strategy);
+    assert_eq!(0, preview.affected_component_count);
+
+    // Previewing must not change the variant.
+    let prop_path = PropPath::new(["root", "domain", "nickname"]);
+    assert!(
+        Prop::find_prop_id_by_path_opt(ctx, schema_variant.id(), &prop_path)
+            .await?
+            .is_some()
+    );
+
+    let (regenerated_variant_id, skips) =
+        VariantAuthoringClient::regenerate_variant_with_skips(ctx, schema_variant.id()).await?;
+    assert_eq!(schema_variant.id(), regenerated_variant_id);
+    assert_eq!(preview.skips, skips);
+
+    // With a component the variant is regenerated as a new version.
+    create_component_for_schema_variant_on_default_view(ctx, regenerated_variant_id).await?;
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        regenerated_variant_id,
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(original_code),
+    )
+    .await?;
+
+    let preview =
+        VariantAuthoringClient::preview_regenerate_variant(ctx, regenerated_variant_id).await?;
+    assert_eq!(RegenerateStrategy::NewVersion, preview.strategy);
+    assert_eq!(1, preview.affected_component_count);
+
+    let (new_variant_id, skips) =
+        VariantAuthoringClient::regenerate_variant_with_skips(ctx, regenerated_variant_id).await?;
+    assert_ne!(regenerated_variant_id, new_variant_id);
+    assert_eq!(preview.skips, skips);
+    assert_eq!(
+        preview.affected_component_count,
+        SchemaVariant::list_component_ids(ctx, new_variant_id)
+            .await?
+            .len()
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn verify_variant_matches_spec_reports_missing_props(ctx: &mut DalContext) -> Result<()> {
+    let code = "function main() {
+        const prop = new PropBuilder().setName(\"nickname\").setKind(\"string\").build();
+        return new AssetBuilder().addProp(prop).build();
+    }";
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant_from_code(
+        ctx,
+        "Muffin",
+        None,
+        None,
+        "Blue Heelers",
+        "#00A19B",
+        code,
+    )
+    .await?;
+
+    let nickname = PropSpec::builder()
+        .name("nickname")
+        .kind(PropSpecKind::String)
+        .build()?;
+    let matching_spec = SchemaVariantSpec::builder()
+        .version("v0")
+        .domain_prop(nickname.clone())
+        .build()?;
+    VariantAuthoringClient::verify_variant_matches_spec(ctx, schema_variant.id(), &matching_spec)
+        .await?;
+
+    // Simulate an import that stopped before creating every prop in the spec.
+    let age = PropSpec::builder()
+        .name("age")
+        .kind(PropSpecKind::Integer)
+        .build()?;
+    let drifted_spec = SchemaVariantSpec::builder()
+        .version("v0")
+        .domain_prop(nickname)
+        .domain_prop(age)
+        .build()?;
+    match VariantAuthoringClient::verify_variant_matches_spec(
+        ctx,
+        schema_variant.id(),
+        &drifted_spec,
+    )
+    .await
+    {
+        Err(VariantAuthoringError::ImportedVariantMismatch {
+            schema_variant_id,
+            missing_props,
+            missing_sockets,
+        }) => {
+            assert_eq!(schema_variant.id(), schema_variant_id);
+            assert_eq!(vec!["root/domain/age".to_string()], missing_props);
+            assert!(missing_sockets.is_empty());
+        }
+        other => panic!("expected the spec mismatch to be reported, got: {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+async fn regenerate_variant_rejects_asset_func_with_wrong_kind(ctx: &mut DalContext) -> Result<()> {
+    let schema_variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "Socks",
+        None,
+        None,
+        "Blue Heelers",
+        "#00A19B",
+    )
+    .await?;
+    let asset_func = schema_variant.get_asset_func(ctx).await?;
+    let asset_func_id = asset_func.id;
+    asset_func
+        .modify(ctx, |func| {
+            func.backend_response_type = FuncBackendResponseType::Json;
+            Ok(())
+        })
+        .await?;
+
+    match VariantAuthoringClient::regenerate_variant(ctx, schema_variant.id()).await {
+        Err(VariantAuthoringError::AssetFuncNotSchemaVariantDefinition(
+            func_id,
+            backend_kind,
+            backend_response_type,
+        )) => {
+            assert_eq!(asset_func_id, func_id);
+            assert_eq!(FuncBackendKind::JsSchemaVariantDefinition, backend_kind);
+            assert_eq!(FuncBackendResponseType::Json, backend_response_type);
+        }
+        other => panic!("expected the asset func kinds to be rejected, got: {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+async fn execute_asset_func_times_out(ctx: &mut DalContext) -> Result<()> {
+    let name = "Bandit";
+    let category = "Blue Heelers";
+    let color = "#00A19B";
+
+    let schema_variant =
+        VariantAuthoringClient::create_schema_and_variant(ctx, name, None, None, category, color)
+            .await?;
+    let asset_func = "async function main() {
+        await new Promise(() => {});
+    }";
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        schema_variant.id(),
+        name,
+        name,
+        category,
+        None,
+        None,
+        color,
+        ComponentType::Component,
+        Some(asset_func),
+    )
+    .await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    let func = SchemaVariant::get_by_id_or_error(ctx, schema_variant.id())
+        .await?
+        .get_asset_func(ctx)
+        .await?;
+    let timeout = Duration::from_secs(1);
+    let func_run_id = match VariantAuthoringClient::execute_asset_func_with_options(
+        ctx,
+        &func,
+        AssetFuncExecutionOptions {
+            timeout,
+            ..Default::default()
+        },
+    )
+    .await
+    {
+        Err(VariantAuthoringError::FuncExecutionTimeout(func_id, func_run_id, elapsed)) => {
+            assert_eq!(func.id, func_id);
+            assert_eq!(timeout, elapsed);
+            func_run_id
+        }
+        other => panic!("expected the asset func to time out, got: {other:?}"),
+    };
+
+    // The timed out run must not be left looking like it is still running.
+    let func_run = ctx.layer_db().func_run().try_read(func_run_id).await?;
+    assert_eq!(FuncRunState::Failure, func_run.state());
+
+    // The timeout must have killed the execution in veritech, which removes its kill sender, so a
+    // second kill request has nothing left to kill.
+    let second_kill = ctx
+        .veritech()
+        .kill_execution(&KillExecutionRequest {
+            execution_id: func_run_id.to_string(),
+        })
+        .await?;
+    assert!(matches!(second_kill, FunctionResult::Failure(_)));
+
+    Ok(())
+}
+
 #[test]
 async fn update_socket_data_on_regenerate(ctx: &mut DalContext) -> Result<()> {
     let name = "Bandit";
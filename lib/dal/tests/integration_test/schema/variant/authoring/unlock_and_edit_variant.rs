@@ -1,4 +1,4 @@
-use dal::schema::variant::authoring::VariantAuthoringClient;
+use dal::schema::variant::authoring::{VariantAuthoringClient, VariantAuthoringError};
 use dal::{DalContext, Func, SchemaVariant};
 use dal_test::helpers::ChangeSetTestHelpers;
 use dal_test::test;
@@ -154,3 +154,69 @@ async fn create_variant_merge_unlock_and_edit(ctx: &mut DalContext) {
 
     assert!(res.is_ok());
 }
+
+#[test]
+async fn locked_variant_rejects_in_place_edits(ctx: &mut DalContext) {
+    let variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "lockedVariant",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+    )
+    .await
+    .expect("Unable to create new asset");
+    let schema = variant
+        .schema(ctx)
+        .await
+        .expect("Unable to get the schema for the variant");
+    let variant = variant
+        .lock(ctx)
+        .await
+        .expect("unable to lock the schema variant");
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx)
+        .await
+        .expect("unable to commit");
+
+    // Neither editing nor regenerating the locked variant is allowed.
+    let result = VariantAuthoringClient::save_variant_content(
+        ctx,
+        variant.id(),
+        &schema.name,
+        variant.display_name(),
+        variant.category(),
+        variant.description(),
+        variant.link(),
+        variant
+            .get_color(ctx)
+            .await
+            .expect("get color from schema variant"),
+        variant.component_type(),
+        Some("function main() {return new AssetBuilder().build()\n}"),
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(VariantAuthoringError::LockedVariant(id)) if id == variant.id()
+    ));
+    let result = VariantAuthoringClient::regenerate_variant(ctx, variant.id()).await;
+    assert!(matches!(
+        result,
+        Err(VariantAuthoringError::LockedVariant(id)) if id == variant.id()
+    ));
+
+    // Edits go through a new, unlocked version instead.
+    let unlocked_variant = VariantAuthoringClient::create_unlocked_variant_copy(ctx, variant.id())
+        .await
+        .expect("unable to create an unlocked copy of a schema variant");
+    assert_ne!(variant.id(), unlocked_variant.id());
+    VariantAuthoringClient::regenerate_variant(ctx, unlocked_variant.id())
+        .await
+        .expect("unable to regenerate the unlocked variant");
+
+    let variant = SchemaVariant::get_by_id_or_error(ctx, variant.id())
+        .await
+        .expect("unable to get the locked variant");
+    assert!(variant.is_locked());
+}
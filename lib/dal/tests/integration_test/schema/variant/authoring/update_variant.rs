@@ -6,7 +6,7 @@ use dal::func::authoring::FuncAuthoringClient;
 use dal::func::binding::{EventualParent, FuncBinding};
 use dal::prop::PropPath;
 use dal::qualification::QualificationSubCheckStatus;
-use dal::schema::variant::authoring::VariantAuthoringClient;
+use dal::schema::variant::authoring::{VariantAuthoringClient, VariantAuthoringError};
 use dal::schema::variant::leaves::{LeafInputLocation, LeafKind};
 use dal::{
     AttributePrototype, AttributePrototypeId, Component, ComponentType, DalContext, Func, Prop,
@@ -1174,3 +1174,147 @@ async fn update_variant_with_leaf_func(ctx: &mut DalContext) {
         );
     }
 }
+
+#[test]
+async fn list_and_diff_versions(ctx: &mut DalContext) {
+    let first_variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "versionedAsset",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+    )
+    .await
+    .expect("Unable to create new asset");
+    let schema = first_variant
+        .schema(ctx)
+        .await
+        .expect("Unable to get the schema for the variant");
+    let first_variant = first_variant
+        .lock(ctx)
+        .await
+        .expect("unable to lock the schema variant");
+
+    // Create the second version, introducing a prop.
+    let second_variant =
+        VariantAuthoringClient::create_unlocked_variant_copy(ctx, first_variant.id())
+            .await
+            .expect("unable to create an unlocked copy of a schema variant");
+    let new_code = "function main() {\n const myProp = new PropBuilder().setName(\"testProp\").setKind(\"string\").build()\n  return new AssetBuilder().addProp(myProp).build()\n}".to_string();
+    VariantAuthoringClient::save_variant_content(
+        ctx,
+        second_variant.id(),
+        &schema.name,
+        second_variant.display_name(),
+        second_variant.category(),
+        second_variant.description(),
+        second_variant.link(),
+        second_variant
+            .get_color(ctx)
+            .await
+            .expect("get color from schema variant"),
+        second_variant.component_type(),
+        Some(new_code),
+    )
+    .await
+    .expect("save variant contents");
+    let second_variant_id = VariantAuthoringClient::regenerate_variant(ctx, second_variant.id())
+        .await
+        .expect("unable to update asset");
+    commit_and_update_snapshot_to_visibility(ctx).await;
+
+    let versions: Vec<SchemaVariantId> = SchemaVariant::list_versions(ctx, schema.id())
+        .await
+        .expect("unable to list versions")
+        .iter()
+        .map(|variant| variant.id())
+        .collect();
+    assert_eq!(vec![first_variant.id(), second_variant_id], versions);
+
+    let diff = SchemaVariant::diff_versions(ctx, first_variant.id(), second_variant_id)
+        .await
+        .expect("unable to diff versions");
+    assert_eq!(vec!["/root/domain/testProp".to_string()], diff.props.added);
+    assert!(diff.props.removed.is_empty());
+    assert!(diff.props.changed.is_empty());
+    assert!(diff.input_sockets.is_empty());
+    assert!(diff.output_sockets.is_empty());
+
+    // Going the other way, the prop is removed.
+    let reverse_diff = SchemaVariant::diff_versions(ctx, second_variant_id, first_variant.id())
+        .await
+        .expect("unable to diff versions");
+    assert_eq!(
+        vec!["/root/domain/testProp".to_string()],
+        reverse_diff.props.removed
+    );
+}
+
+#[test]
+async fn set_default_version_rolls_back(ctx: &mut DalContext) {
+    let first_variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "rollbackAsset",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+    )
+    .await
+    .expect("Unable to create new asset");
+    let schema = first_variant
+        .schema(ctx)
+        .await
+        .expect("Unable to get the schema for the variant");
+    let first_variant = first_variant
+        .lock(ctx)
+        .await
+        .expect("unable to lock the schema variant");
+    let second_variant =
+        VariantAuthoringClient::create_unlocked_variant_copy(ctx, first_variant.id())
+            .await
+            .expect("unable to create an unlocked copy of a schema variant");
+
+    VariantAuthoringClient::set_default_version(ctx, schema.id(), second_variant.id())
+        .await
+        .expect("unable to set the default version");
+    assert_eq!(
+        Some(second_variant.id()),
+        schema
+            .get_default_schema_variant_id(ctx)
+            .await
+            .expect("unable to get the default schema variant id")
+    );
+
+    VariantAuthoringClient::set_default_version(ctx, schema.id(), first_variant.id())
+        .await
+        .expect("unable to set the default version");
+    commit_and_update_snapshot_to_visibility(ctx).await;
+    assert_eq!(
+        Some(first_variant.id()),
+        schema
+            .get_default_schema_variant_id(ctx)
+            .await
+            .expect("unable to get the default schema variant id")
+    );
+
+    // A variant of another schema cannot become the default.
+    let other_variant = VariantAuthoringClient::create_schema_and_variant(
+        ctx,
+        "otherAsset",
+        None,
+        None,
+        "Integration Tests",
+        "#00b0b0",
+    )
+    .await
+    .expect("Unable to create new asset");
+    let result =
+        VariantAuthoringClient::set_default_version(ctx, schema.id(), other_variant.id()).await;
+    assert!(matches!(
+        result,
+        Err(VariantAuthoringError::SchemaVariantNotVersionOfSchema(variant_id, schema_id))
+            if variant_id == other_variant.id() && schema_id == schema.id()
+    ));
+}
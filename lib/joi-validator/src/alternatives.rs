@@ -1,8 +1,115 @@
 use serde::Deserialize;
 
+use crate::{generic, require};
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct Validator {
-    pub matches: Vec<crate::Validator>,
+    matches: Vec<Match>,
+    #[serde(flatten)]
+    pub base: generic::Validator<serde_json::Value, Flags>,
+}
+
+impl Validator {
+    pub fn validate(self, value: &Option<serde_json::Value>) -> Result<(), (String, String)> {
+        self.base.validate_presence(value)?;
+        let Some(inner_value) = value else {
+            return Ok(());
+        };
+        self.base.validate_value(inner_value)?;
+
+        // Run every alternative so that we can report each one that failed, by position.
+        let mut matched = 0;
+        let mut failures = vec![];
+        for (index, alternative) in self.matches.into_iter().enumerate() {
+            match alternative.schema.validate(value).error {
+                None => matched += 1,
+                Some(error) => failures.push(format!(
+                    "[{index}] {}",
+                    error
+                        .details
+                        .iter()
+                        .map(|details| details.message.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            }
+        }
+
+        match self.base.flags.extra_flags.mode {
+            Mode::All => require(
+                failures.is_empty(),
+                "alternatives.all",
+                format!(
+                    "does not match all of the required types: {}",
+                    failures.join("; ")
+                ),
+            ),
+            Mode::Any => require(
+                matched > 0,
+                "alternatives.any",
+                format!(
+                    "does not match any of the allowed types: {}",
+                    failures.join("; ")
+                ),
+            ),
+            Mode::One => {
+                require(
+                    matched > 0,
+                    "alternatives.any",
+                    format!(
+                        "does not match any of the allowed types: {}",
+                        failures.join("; ")
+                    ),
+                )?;
+                require(
+                    matched == 1,
+                    "alternatives.one",
+                    "matches more than one allowed type",
+                )
+            }
+        }
+    }
+
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        let mut rule_names = self.base.rule_names();
+        rule_names.push(match self.base.flags.extra_flags.mode {
+            Mode::All => "alternatives.all",
+            Mode::Any => "alternatives.any",
+            Mode::One => "alternatives.one",
+        });
+        for alternative in &self.matches {
+            rule_names.extend(alternative.schema.rule_names());
+        }
+        rule_names
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+struct Match {
+    schema: crate::Validator,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct Flags {
+    // How many of the alternatives must match
+    #[serde(default, rename = "match")]
+    mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+enum Mode {
+    // Every alternative must match
+    All,
+    // At least one alternative must match
+    #[default]
+    Any,
+    // Exactly one alternative must match
+    One,
 }
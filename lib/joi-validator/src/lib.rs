@@ -28,8 +28,8 @@
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_with::serde_as;
 
-// These are commented out until we decide to support them in any way.
-// pub mod alternatives;
+pub mod alternatives;
+// This is commented out until we decide to support it in any way.
 // pub mod date;
 mod boolean;
 pub mod generic;
@@ -50,7 +50,7 @@ pub enum Error {
 #[serde(deny_unknown_fields)]
 #[serde(tag = "type")]
 pub enum Validator {
-    // Alternatives(alternatives::Validator),
+    Alternatives(alternatives::Validator),
     Boolean(boolean::Validator),
     // Date(date::Validator),
     Number(number::Validator),
@@ -66,6 +66,7 @@ impl Validator {
     ) -> ValidateResponse<Option<serde_json::Value>> {
         let label = self.take_label().unwrap_or("value".into());
         let result = match self {
+            Validator::Alternatives(validator) => validator.validate(value),
             Validator::Boolean(validator) => validator.validate(value),
             Validator::Number(validator) => validator.validate(value),
             Validator::String(validator) => validator.validate(value),
@@ -100,6 +101,7 @@ impl Validator {
 
     fn take_label(&mut self) -> Option<String> {
         match self {
+            Validator::Alternatives(validator) => validator.base.flags.label.take(),
             Validator::Boolean(validator) => validator.base.flags.label.take(),
             Validator::Number(validator) => validator.base.flags.label.take(),
             Validator::String(validator) => validator.base.flags.label.take(),
@@ -108,6 +110,7 @@ impl Validator {
 
     pub fn rule_names(&self) -> Vec<&'static str> {
         match self {
+            Validator::Alternatives(validator) => validator.rule_names(),
             Validator::Boolean(validator) => validator.rule_names(),
             Validator::Number(validator) => validator.rule_names(),
             Validator::String(validator) => validator.rule_names(),
@@ -1,5 +1,5 @@
 use crate::{generic, require, rule_err, Args};
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -58,7 +58,7 @@ enum Rule {
     Min(Args<LengthLimit>),
     Max(Args<LengthLimit>),
     // Normalize(Args<Normalize>),
-    // Pattern(Args<Pattern>),
+    Pattern(Args<Pattern>),
     // Replace(Args<Replace>),
     // Token,
     // Trim(Args<Enabled>),
@@ -90,6 +90,39 @@ impl Rule {
                     rule.args.limit
                 ),
             ),
+            Self::Pattern(rule) => {
+                let invert = rule.args.options.as_ref().is_some_and(|o| o.invert);
+                let name = rule.args.options.and_then(|o| o.name);
+                let matched = rule.args.regex.regex.is_match(value);
+                match (invert, name) {
+                    (false, None) => require(
+                        matched,
+                        "string.pattern.base",
+                        format!(
+                            "with value {value:?} fails to match the required pattern: {}",
+                            rule.args.regex.source
+                        ),
+                    ),
+                    (false, Some(name)) => require(
+                        matched,
+                        "string.pattern.name",
+                        format!("with value {value:?} fails to match the {name} pattern"),
+                    ),
+                    (true, None) => require(
+                        !matched,
+                        "string.pattern.invert.base",
+                        format!(
+                            "with value {value:?} matches the inverted pattern: {}",
+                            rule.args.regex.source
+                        ),
+                    ),
+                    (true, Some(name)) => require(
+                        !matched,
+                        "string.pattern.invert.name",
+                        format!("with value {value:?} matches the inverted {name} pattern"),
+                    ),
+                }
+            }
         }
     }
 
@@ -98,6 +131,7 @@ impl Rule {
             Self::Length(_) => "string.length",
             Self::Min(_) => "string.min",
             Self::Max(_) => "string.max",
+            Self::Pattern(_) => "string.pattern.base",
         }
     }
 }
@@ -382,11 +416,53 @@ pub struct Flags {
 //     // ???
 // }
 
-// #[derive(Debug, Clone, Deserialize)]
-// #[serde(rename_all = "camelCase")]
-// #[serde(deny_unknown_fields)]
-// struct Pattern {
-//     regex: String,
-//     name: Option<String>,
-//     invert: Option<bool>,
-// }
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+struct Pattern {
+    regex: JsRegex,
+    options: Option<PatternOptions>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+struct PatternOptions {
+    name: Option<String>,
+    #[serde(default)]
+    invert: bool,
+}
+
+// A JavaScript regex literal, as Joi describes it (e.g. "/^[a-z]+$/i"). Patterns the Rust regex
+// engine can't express (lookarounds, backreferences, unknown flags) fail to deserialize, so the
+// validation runs remotely instead.
+#[derive(Debug, Clone)]
+struct JsRegex {
+    source: String,
+    regex: regex::Regex,
+}
+
+impl<'de> Deserialize<'de> for JsRegex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        let (pattern, flags) = source
+            .strip_prefix('/')
+            .and_then(|rest| rest.rsplit_once('/'))
+            .ok_or_else(|| de::Error::custom(format!("not a regex literal: {source}")))?;
+
+        let mut builder = regex::RegexBuilder::new(pattern);
+        for flag in flags.chars() {
+            match flag {
+                'i' => builder.case_insensitive(true),
+                'm' => builder.multi_line(true),
+                's' => builder.dot_matches_new_line(true),
+                // Global, sticky and unicode don't change whether a string matches.
+                'g' | 'y' | 'u' => &mut builder,
+                _ => return Err(de::Error::custom(format!("unsupported regex flag: {flag}"))),
+            };
+        }
+        let regex = builder.build().map_err(de::Error::custom)?;
+
+        Ok(Self { source, regex })
+    }
+}
@@ -664,6 +664,39 @@ mod string {
         Ok(())
     }
 
+    #[test]
+    fn pattern() -> Result<()> {
+        let joi = r#"{ "type": "string", "rules": [
+            { "name": "pattern", "args": { "regex": "/^[a-z]+$/" } }
+        ] }"#;
+        valid(joi, "abc")?;
+        invalid(joi, "")?;
+        invalid(joi, "ABC")?;
+        invalid(joi, "abc1")?;
+
+        let joi = r#"{ "type": "string", "rules": [
+            { "name": "pattern", "args": { "regex": "/^[a-z]+$/i" } }
+        ] }"#;
+        valid(joi, "ABC")?;
+
+        let joi = r#"{ "type": "string", "rules": [
+            { "name": "pattern", "args": { "regex": "/^[a-z]+$/", "options": { "invert": true } } }
+        ] }"#;
+        invalid(joi, "abc")?;
+        valid(joi, "ABC")?;
+
+        unsupported(
+            r#"{ "type": "string", "rules": [ { "name": "pattern", "args": { "regex": "^[a-z]+$" } } ] }"#,
+        )?;
+        unsupported(
+            r#"{ "type": "string", "rules": [ { "name": "pattern", "args": { "regex": "/(?=a)/" } } ] }"#,
+        )?;
+        unsupported(
+            r#"{ "type": "string", "rules": [ { "name": "pattern", "args": { "regex": "/a/x" } } ] }"#,
+        )?;
+        Ok(())
+    }
+
     #[test]
     fn multi() -> Result<()> {
         let joi = r#"{ "type": "string", "flags": { "presence": "required", "only": true }, "rules": [
@@ -861,3 +894,96 @@ mod string {
         Ok(())
     }
 }
+
+mod alternatives {
+    use super::*;
+
+    const NON_EMPTY_AND_LOWERCASE: &str = r#"{ "type": "alternatives", "flags": { "match": "all" }, "matches": [
+        { "schema": { "type": "string", "rules": [ { "name": "min", "args": { "limit": 1 } } ] } },
+        { "schema": { "type": "string", "rules": [ { "name": "pattern", "args": { "regex": "/^[a-z]*$/" } } ] } }
+    ] }"#;
+
+    fn error_details(json: &str, value: impl Into<serde_json::Value>) -> Result<(String, String)> {
+        let validator: Validator = serde_json::from_str(json)?;
+        let error = validator
+            .validate(&Some(value.into()))
+            .error
+            .ok_or(eyre!("expected validation error"))?;
+        let details = error
+            .details
+            .first()
+            .ok_or(eyre!("expected error details"))?;
+        Ok((details.r#type.clone(), details.message.clone()))
+    }
+
+    #[test]
+    fn match_all() -> Result<()> {
+        let joi = NON_EMPTY_AND_LOWERCASE;
+        valid_opt(joi, None)?;
+        valid(joi, "abc")?;
+        invalid(joi, "")?;
+        invalid(joi, "ABC")?;
+        invalid(joi, 10)?;
+
+        // The failing rule is identified by its position.
+        let (r#type, message) = error_details(joi, "")?;
+        assert_eq!("alternatives.all", r#type);
+        assert!(message.contains("[0]"), "{message}");
+        assert!(!message.contains("[1]"), "{message}");
+
+        let (r#type, message) = error_details(joi, "ABC")?;
+        assert_eq!("alternatives.all", r#type);
+        assert!(!message.contains("[0]"), "{message}");
+        assert!(message.contains("[1]"), "{message}");
+        Ok(())
+    }
+
+    #[test]
+    fn match_any() -> Result<()> {
+        let joi = r#"{ "type": "alternatives", "matches": [
+            { "schema": { "type": "string", "rules": [ { "name": "length", "args": { "limit": 2 } } ] } },
+            { "schema": { "type": "number" } }
+        ] }"#;
+        valid(joi, "ab")?;
+        valid(joi, 10)?;
+        invalid(joi, "abc")?;
+        invalid(joi, true)?;
+        let (r#type, _) = error_details(joi, "abc")?;
+        assert_eq!("alternatives.any", r#type);
+        Ok(())
+    }
+
+    #[test]
+    fn match_one() -> Result<()> {
+        let joi = r#"{ "type": "alternatives", "flags": { "match": "one" }, "matches": [
+            { "schema": { "type": "string", "rules": [ { "name": "min", "args": { "limit": 2 } } ] } },
+            { "schema": { "type": "string", "rules": [ { "name": "max", "args": { "limit": 3 } } ] } }
+        ] }"#;
+        valid(joi, "a")?;
+        valid(joi, "abcd")?;
+        invalid(joi, "abc")?;
+        let (r#type, _) = error_details(joi, "abc")?;
+        assert_eq!("alternatives.one", r#type);
+        Ok(())
+    }
+
+    #[test]
+    fn required() -> Result<()> {
+        let joi = r#"{ "type": "alternatives", "flags": { "presence": "required" }, "matches": [
+            { "schema": { "type": "string" } }
+        ] }"#;
+        invalid_opt(joi, None)?;
+        valid(joi, "a")?;
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_alternatives() -> Result<()> {
+        unsupported(
+            r#"{ "type": "alternatives", "matches": [ { "schema": { "type": "any" } } ] }"#,
+        )?;
+        unsupported(r#"{ "type": "alternatives", "flags": { "match": "some" }, "matches": [] }"#)?;
+        unsupported(r#"{ "type": "alternatives", "matches": [ { "type": "string" } ] }"#)?;
+        Ok(())
+    }
+}
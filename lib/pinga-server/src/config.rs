@@ -1,6 +1,7 @@
 use std::{env, path::Path};
 
 use buck2_resources::Buck2Resources;
+use dal::func::runner::DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use si_crypto::{
@@ -58,6 +59,9 @@ pub struct Config {
     #[builder(default = "default_max_deliver()")]
     max_deliver: i64,
 
+    #[builder(default = "default_max_concurrent_func_executions()")]
+    max_concurrent_func_executions: usize,
+
     #[builder(default = "random_instance_id()")]
     instance_id: String,
 
@@ -110,6 +114,11 @@ impl Config {
         self.max_deliver
     }
 
+    /// Gets how many funcs this process may have executing at once.
+    pub fn max_concurrent_func_executions(&self) -> usize {
+        self.max_concurrent_func_executions
+    }
+
     /// Gets the config's instance ID.
     pub fn instance_id(&self) -> &str {
         self.instance_id.as_ref()
@@ -133,6 +142,8 @@ pub struct ConfigFile {
     concurrency_limit: usize,
     #[serde(default = "default_max_deliver")]
     max_deliver: i64,
+    #[serde(default = "default_max_concurrent_func_executions")]
+    max_concurrent_func_executions: usize,
     #[serde(default = "random_instance_id")]
     instance_id: String,
     #[serde(default = "default_layer_db_config")]
@@ -148,6 +159,7 @@ impl Default for ConfigFile {
             nats: Default::default(),
             concurrency_limit: default_concurrency_limit(),
             max_deliver: default_max_deliver(),
+            max_concurrent_func_executions: default_max_concurrent_func_executions(),
             crypto: Default::default(),
             instance_id: random_instance_id(),
             layer_db_config: default_layer_db_config(),
@@ -172,6 +184,7 @@ impl TryFrom<ConfigFile> for Config {
         config.crypto(value.crypto);
         config.concurrency_limit(value.concurrency_limit);
         config.max_deliver(value.max_deliver);
+        config.max_concurrent_func_executions(value.max_concurrent_func_executions);
         config.instance_id(value.instance_id);
         config.symmetric_crypto_service(value.symmetric_crypto_service.try_into()?);
         config.layer_db_config(value.layer_db_config);
@@ -199,6 +212,10 @@ fn default_max_deliver() -> i64 {
     1
 }
 
+fn default_max_concurrent_func_executions() -> usize {
+    DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS
+}
+
 fn default_layer_db_config() -> LayerDbConfig {
     LayerDbConfig::default()
 }
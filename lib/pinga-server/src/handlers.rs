@@ -3,7 +3,9 @@ use std::{result, str::Utf8Error, sync::Arc};
 use dal::{
     job::{
         consumer::{JobConsumer, JobConsumerError, JobInfo},
-        definition::{compute_validation::ComputeValidation, ActionJob, DependentValuesUpdate},
+        definition::{
+            compute_validation::ComputeValidation, ActionJob, DependentValuesUpdate, RefreshJob,
+        },
         producer::BlockingJobError,
     },
     DalContextBuilder, TenancyError, WorkspacePk,
@@ -197,6 +199,9 @@ async fn execute_job_inner(mut ctx_builder: DalContextBuilder, job_info: JobInfo
         }
         stringify!(ComputeValidation) => Box::new(ComputeValidation::try_from(job_info.clone())?)
             as Box<dyn JobConsumer + Send + Sync>,
+        stringify!(RefreshJob) => {
+            Box::new(RefreshJob::try_from(job_info.clone())?) as Box<dyn JobConsumer + Send + Sync>
+        }
         kind => return Err(HandlerError::UnknownJobKind(kind.to_owned())),
     };
 
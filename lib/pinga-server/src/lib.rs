@@ -5,7 +5,10 @@ pub mod server;
 
 use std::io;
 
-use dal::{DedicatedExecutorInitializeError, InitializationError, TransactionsError};
+use dal::{
+    job::consumer::JobConsumerError, DedicatedExecutorInitializeError, InitializationError,
+    TransactionsError,
+};
 use si_data_nats::{async_nats, NatsError};
 use si_data_pg::PgPoolError;
 use thiserror::Error;
@@ -27,6 +30,8 @@ pub enum ServerError {
     DedicatedExecutorInitialize(#[from] DedicatedExecutorInitializeError),
     #[error("initialization error: {0}")]
     Initialization(#[from] InitializationError),
+    #[error("job consumer error: {0}")]
+    JobConsumer(#[from] Box<JobConsumerError>),
     #[error("stream consumer error: {0}")]
     JsConsumer(#[from] async_nats::jetstream::stream::ConsumerError),
     #[error("consumer stream error: {0}")]
@@ -51,6 +56,12 @@ pub enum ServerError {
     VeritechEncryptionKey(#[from] si_crypto::VeritechEncryptionKeyError),
 }
 
+impl From<JobConsumerError> for ServerError {
+    fn from(e: JobConsumerError) -> Self {
+        Self::JobConsumer(Box::new(e))
+    }
+}
+
 impl From<PgPoolError> for ServerError {
     fn from(e: PgPoolError) -> Self {
         Self::PgPool(Box::new(e))
@@ -3,11 +3,13 @@ use std::{
     future::{Future, IntoFuture as _},
     io,
     sync::Arc,
+    time::Duration,
 };
 
 use dal::{
-    feature_flags::FeatureFlagService, DalContext, DedicatedExecutor, JetstreamStreams,
-    JobQueueProcessor, NatsProcessor, ServicesContext,
+    feature_flags::FeatureFlagService, func::runner::set_max_concurrent_func_executions,
+    job::definition::RefreshJob, DalContext, DalContextBuilder, DedicatedExecutor,
+    JetstreamStreams, JobQueueProcessor, NatsProcessor, ServicesContext,
 };
 use naxum::{
     extract::MatchedSubject,
@@ -38,6 +40,9 @@ use crate::{app_state::AppState, handlers, Config, ServerError, ServerResult};
 
 const CONSUMER_NAME: &str = "pinga-server";
 
+/// How often to look for recurring refreshes whose next run is due.
+const REFRESH_SCHEDULE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Server metadata, used with telemetry.
 #[derive(Clone, Debug)]
 pub struct ServerMetadata {
@@ -81,6 +86,9 @@ impl Server {
         layer_db_token: CancellationToken,
     ) -> ServerResult<Self> {
         dal::init()?;
+        if !set_max_concurrent_func_executions(config.max_concurrent_func_executions()) {
+            warn!("max concurrent func executions were already set, ignoring configured limit");
+        }
 
         let encryption_key = Self::load_encryption_key(config.crypto().clone()).await?;
         let nats = Self::connect_to_nats(config.nats()).await?;
@@ -163,6 +171,11 @@ impl Server {
 
         let ctx_builder = DalContext::builder(services_context, false);
 
+        tokio::spawn(poll_refresh_schedules(
+            ctx_builder.clone(),
+            shutdown_token.clone(),
+        ));
+
         let state = AppState::new(metadata.clone(), concurrency_limit, ctx_builder);
 
         let app = ServiceBuilder::new()
@@ -347,3 +360,30 @@ where
         }
     }
 }
+
+/// Enqueues a run for each due recurring refresh until shutdown. Claiming a schedule is atomic, so
+/// every pinga instance can poll without enqueueing the same run twice.
+async fn poll_refresh_schedules(ctx_builder: DalContextBuilder, shutdown_token: CancellationToken) {
+    let mut interval = tokio::time::interval(REFRESH_SCHEDULE_POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = shutdown_token.cancelled() => break,
+            _ = interval.tick() => {
+                if let Err(err) = enqueue_due_refreshes(&ctx_builder).await {
+                    error!(si.error.message = ?err, "failed to enqueue due refreshes");
+                }
+            }
+        }
+    }
+    debug!("refresh schedule poller shutdown complete");
+}
+
+async fn enqueue_due_refreshes(ctx_builder: &DalContextBuilder) -> ServerResult<()> {
+    let ctx = ctx_builder.build_default(None).await?;
+    let enqueued = RefreshJob::enqueue_due(&ctx).await?;
+    if !enqueued.is_empty() {
+        debug!(count = enqueued.len(), "enqueued due refreshes");
+    }
+    ctx.commit_no_rebase().await?;
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use std::{env, path::Path, time::Duration};
 
 use buck2_resources::Buck2Resources;
+use dal::func::runner::DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use si_crypto::{
@@ -69,6 +70,9 @@ pub struct Config {
     #[builder(default = "default_concurrency_limit()")]
     concurrency_limit: Option<usize>,
 
+    #[builder(default = "default_max_concurrent_func_executions()")]
+    max_concurrent_func_executions: usize,
+
     #[builder(default = "default_quiescent_period()")]
     quiescent_period: Duration,
 }
@@ -117,6 +121,11 @@ impl Config {
         self.concurrency_limit
     }
 
+    /// Gets how many funcs this process may have executing at once.
+    pub fn max_concurrent_func_executions(&self) -> usize {
+        self.max_concurrent_func_executions
+    }
+
     /// Gets the config's instance ID.
     pub fn instance_id(&self) -> &str {
         self.instance_id.as_ref()
@@ -143,6 +152,8 @@ pub struct ConfigFile {
     layer_db_config: LayerDbConfig,
     #[serde(default = "default_concurrency_limit")]
     concurrency_limit: Option<usize>,
+    #[serde(default = "default_max_concurrent_func_executions")]
+    max_concurrent_func_executions: usize,
     #[serde(default = "random_instance_id")]
     instance_id: String,
     #[serde(default = "default_quiescent_period_secs")]
@@ -158,6 +169,7 @@ impl Default for ConfigFile {
             symmetric_crypto_service: default_symmetric_crypto_config(),
             layer_db_config: default_layer_db_config(),
             concurrency_limit: default_concurrency_limit(),
+            max_concurrent_func_executions: default_max_concurrent_func_executions(),
             instance_id: random_instance_id(),
             quiescent_period_secs: default_quiescent_period_secs(),
         }
@@ -181,6 +193,7 @@ impl TryFrom<ConfigFile> for Config {
         config.symmetric_crypto_service(value.symmetric_crypto_service.try_into()?);
         config.layer_db_config(value.layer_db_config);
         config.concurrency_limit(value.concurrency_limit);
+        config.max_concurrent_func_executions(value.max_concurrent_func_executions);
         config.instance_id(value.instance_id);
         config.quiescent_period(Duration::from_secs(value.quiescent_period_secs));
         config.build().map_err(Into::into)
@@ -203,6 +216,10 @@ fn default_concurrency_limit() -> Option<usize> {
     DEFAULT_CONCURRENCY_LIMIT
 }
 
+fn default_max_concurrent_func_executions() -> usize {
+    DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS
+}
+
 fn default_layer_db_config() -> LayerDbConfig {
     LayerDbConfig::default()
 }
@@ -218,9 +218,14 @@ pub async fn perform_rebase(
 
     if !updating_head {
         if let Some(source_change_set_id) = request.from_change_set_id {
-            let mut event =
-                WsEvent::change_set_applied(ctx, source_change_set_id, request.change_set_id, None)
-                    .await?;
+            let mut event = WsEvent::change_set_applied(
+                ctx,
+                source_change_set_id,
+                request.change_set_id,
+                None,
+                None,
+            )
+            .await?;
             event.set_workspace_pk(request.workspace_id);
             event.set_change_set_id(Some(request.change_set_id));
             event.publish_immediately(ctx).await?;
@@ -7,8 +7,9 @@ use std::{
 };
 
 use dal::{
-    feature_flags::FeatureFlagService, DalContext, DalLayerDb, DedicatedExecutor, JetstreamStreams,
-    JobQueueProcessor, NatsProcessor, ServicesContext,
+    feature_flags::FeatureFlagService, func::runner::set_max_concurrent_func_executions,
+    DalContext, DalLayerDb, DedicatedExecutor, JetstreamStreams, JobQueueProcessor, NatsProcessor,
+    ServicesContext,
 };
 use naxum::{
     extract::MatchedSubject,
@@ -83,6 +84,9 @@ impl Server {
         layer_db_token: CancellationToken,
     ) -> Result<Self> {
         dal::init()?;
+        if !set_max_concurrent_func_executions(config.max_concurrent_func_executions()) {
+            warn!("max concurrent func executions were already set, ignoring configured limit");
+        }
 
         let encryption_key = Self::load_encryption_key(config.crypto().clone()).await?;
         let nats = Self::connect_to_nats(config.nats()).await?;
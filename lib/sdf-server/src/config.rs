@@ -16,6 +16,7 @@ use ulid::Ulid;
 
 use buck2_resources::Buck2Resources;
 use dal::feature_flags::FeatureFlag;
+use dal::func::runner::DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use si_crypto::{SymmetricCryptoServiceConfig, SymmetricCryptoServiceConfigFile};
@@ -136,6 +137,9 @@ pub struct Config {
     #[builder(default = "default_layer_db_config()")]
     layer_db_config: LayerDbConfig,
 
+    #[builder(default = "default_max_concurrent_func_executions()")]
+    max_concurrent_func_executions: usize,
+
     #[builder(default)]
     spicedb: SpiceDbConfig,
 
@@ -253,6 +257,11 @@ impl Config {
         &self.layer_db_config
     }
 
+    /// Gets how many funcs this process may have executing at once.
+    pub fn max_concurrent_func_executions(&self) -> usize {
+        self.max_concurrent_func_executions
+    }
+
     // The Create Workspace Permissions Mode should be set via an env variable or it will default to Closed
     pub fn create_workspace_permissions(&self) -> &WorkspacePermissionsMode {
         &self.create_workspace_permissions
@@ -314,6 +323,8 @@ pub struct ConfigFile {
     pub posthog: PosthogConfig,
     #[serde(default = "default_layer_db_config")]
     layer_db_config: LayerDbConfig,
+    #[serde(default = "default_max_concurrent_func_executions")]
+    max_concurrent_func_executions: usize,
     #[serde(default)]
     pub module_index_url: String,
     #[serde(default = "default_auth_api_url")]
@@ -349,6 +360,7 @@ impl Default for ConfigFile {
             pkgs_path: default_pkgs_path(),
             posthog: Default::default(),
             layer_db_config: default_layer_db_config(),
+            max_concurrent_func_executions: default_max_concurrent_func_executions(),
             module_index_url: default_module_index_url(),
             auth_api_url: default_auth_api_url(),
             openai: Default::default(),
@@ -391,6 +403,7 @@ impl TryFrom<ConfigFile> for Config {
             asset_sprayer: value.asset_sprayer,
             symmetric_crypto_service: value.symmetric_crypto_service.try_into()?,
             layer_db_config: value.layer_db_config,
+            max_concurrent_func_executions: value.max_concurrent_func_executions,
             boot_feature_flags: value.boot_feature_flags.into_iter().collect::<HashSet<_>>(),
             create_workspace_permissions: value.create_workspace_permissions,
             create_workspace_allowlist: value.create_workspace_allowlist,
@@ -458,6 +471,10 @@ fn default_layer_db_config() -> LayerDbConfig {
     LayerDbConfig::default()
 }
 
+fn default_max_concurrent_func_executions() -> usize {
+    DEFAULT_MAX_CONCURRENT_FUNC_EXECUTIONS
+}
+
 #[allow(clippy::disallowed_methods)] // Used to determine if running in development
 fn detect_and_configure_development(config: &mut ConfigFile) -> Result<()> {
     if env::var("BUCK_RUN_BUILD_ID").is_ok() || env::var("BUCK_BUILD_ID").is_ok() {
@@ -1,8 +1,9 @@
 use std::{path::PathBuf, sync::Arc};
 
 use dal::{
-    feature_flags::FeatureFlagService, DalLayerDb, DedicatedExecutor, JetstreamStreams,
-    JobQueueProcessor, NatsProcessor, ServicesContext,
+    feature_flags::FeatureFlagService, func::runner::set_max_concurrent_func_executions,
+    DalLayerDb, DedicatedExecutor, JetstreamStreams, JobQueueProcessor, NatsProcessor,
+    ServicesContext,
 };
 use rebaser_client::RebaserClient;
 use si_crypto::{
@@ -62,6 +63,9 @@ pub(crate) async fn services_context_from_config(
     helping_tasks_token: CancellationToken,
 ) -> InitResult<(ServicesContext, LayerDbGracefulShutdown)> {
     dal::init()?;
+    if !set_max_concurrent_func_executions(config.max_concurrent_func_executions()) {
+        warn!("max concurrent func executions were already set, ignoring configured limit");
+    }
 
     let encryption_key = load_encryption_key(config.crypto().clone()).await?;
     let nats = connect_to_nats(config.nats()).await?;
@@ -1,3 +1,5 @@
+mod request_cancellation;
 mod workspace_permission;
 
+pub use self::request_cancellation::{cancel_on_disconnect, RequestCancellation, RequestCancelled};
 pub use self::workspace_permission::{WorkspacePermission, WorkspacePermissionLayer};
@@ -0,0 +1,81 @@
+use std::future::Future;
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{request::Parts, Request},
+    middleware::Next,
+    response::Response,
+};
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+
+/// Returned when work is abandoned because the client went away before the request finished.
+#[derive(Debug, Error)]
+#[error("request cancelled: the client disconnected before the request finished")]
+pub struct RequestCancelled;
+
+/// A signal that is cancelled when the request it belongs to is dropped before finishing, which
+/// happens when the client disconnects mid-request.
+///
+/// Work running directly in a handler stops at its next await point on its own (and since an
+/// uncommitted [`DalContext`](dal::DalContext) is rolled back when dropped, nothing is written).
+/// Work that is spawned elsewhere, like on the [`slow_rt`](dal::slow_rt), outlives the handler
+/// and should be wrapped with [`Self::run`] or check [`Self::check`] between units of work.
+#[derive(Clone, Debug, Default)]
+pub struct RequestCancellation(CancellationToken);
+
+impl RequestCancellation {
+    pub fn is_cancelled(&self) -> bool {
+        self.0.is_cancelled()
+    }
+
+    /// Returns an error if the request has been cancelled.
+    pub fn check(&self) -> Result<(), RequestCancelled> {
+        if self.is_cancelled() {
+            return Err(RequestCancelled);
+        }
+        Ok(())
+    }
+
+    /// Drives the future to completion unless the request is cancelled first, in which case the
+    /// future is dropped at its current await point.
+    pub async fn run<F>(&self, future: F) -> Result<F::Output, RequestCancelled>
+    where
+        F: Future,
+    {
+        tokio::select! {
+            biased;
+            _ = self.0.cancelled() => Err(RequestCancelled),
+            output = future => Ok(output),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequestCancellation
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Requests that did not pass through the middleware can never be cancelled.
+        Ok(parts.extensions.get::<Self>().cloned().unwrap_or_default())
+    }
+}
+
+/// Middleware that provides a [`RequestCancellation`] to every request and cancels it if the
+/// request is dropped before a response is produced.
+pub async fn cancel_on_disconnect<B>(mut request: Request<B>, next: Next<B>) -> Response {
+    let token = CancellationToken::new();
+    request
+        .extensions_mut()
+        .insert(RequestCancellation(token.clone()));
+
+    let guard = token.drop_guard();
+    let response = next.run(request).await;
+    guard.disarm();
+
+    response
+}
@@ -50,6 +50,7 @@ pub fn routes(state: AppState) -> Router {
         .nest("/api", v1_routes())
         .nest("/api/public", crate::service::public::routes(state.clone()))
         .nest("/api/v2", crate::service::v2::routes(state.clone()))
+        .nest("/api/health", crate::service::health::routes())
         .nest("/api/whoami", crate::service::whoami::routes())
         .layer(CompressionLayer::new())
         // allows us to be permissive about cors from our owned subdomains
@@ -82,6 +83,7 @@ pub fn routes(state: AppState) -> Router {
             state.clone(),
             app_state_middeware,
         ))
+        .layer(middleware::from_fn(crate::middleware::cancel_on_disconnect))
         // root health route is currently pinged by auth portal to check if backend is up and running so we need permissive CORS headers
         // it is last in the list so that it still services even if we are in maintenance mode
         .nest(
@@ -16,8 +16,10 @@ pub mod component;
 pub mod diagram;
 pub mod force_change_set_response;
 pub mod graphviz;
+pub mod health;
 pub mod module;
 pub mod node_debug;
+pub mod pagination;
 pub mod public;
 pub mod qualification;
 pub mod secret;
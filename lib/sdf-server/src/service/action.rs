@@ -5,21 +5,28 @@ use axum::{routing::get, Router};
 use dal::{FuncError as DalFuncError, WsEventError};
 use si_layer_cache::LayerDbError;
 use thiserror::Error;
+use veritech_client::ResourceStatus;
 
 use dal::{
     action::prototype::ActionPrototypeError, action::ActionId,
     schema::SchemaError as DalSchemaError,
 };
-use dal::{ComponentError, ComponentId, StandardModelError, TransactionsError, UserError, UserPk};
+use dal::{
+    ComponentError, ComponentId, FuncId, StandardModelError, TransactionsError, UserError, UserPk,
+};
 
 use super::ApiError;
-use crate::AppState;
+use crate::{middleware::RequestCancelled, service::pagination::PaginationError, AppState};
 
 mod cancel;
+mod dry_run;
 mod history;
 pub mod list_actions;
 mod put_on_hold;
+mod rerun;
+pub mod resume;
 mod retry;
+pub mod status;
 
 #[remain::sorted]
 #[derive(Error, Debug)]
@@ -38,6 +45,8 @@ pub enum ActionError {
     DalSchema(#[from] DalSchemaError),
     #[error(transparent)]
     Func(#[from] DalFuncError),
+    #[error("func {0} not found")]
+    FuncNotFound(FuncId),
     #[error("Cannot cancel Running or Dispatched actions. ActionId {0}")]
     InvalidActionCancellation(ActionId),
     #[error("Cannot update action state that's not Queued to On Hold. Action with Id {0}")]
@@ -53,6 +62,10 @@ pub enum ActionError {
     #[error("no schema variant found for component {0}")]
     NoSchemaVariantForComponent(ComponentId),
     #[error(transparent)]
+    Pagination(#[from] PaginationError),
+    #[error(transparent)]
+    RequestCancelled(#[from] RequestCancelled),
+    #[error(transparent)]
     StandardModel(#[from] StandardModelError),
     #[error("transactions error: {0}")]
     Transactions(#[from] TransactionsError),
@@ -68,6 +81,17 @@ impl IntoResponse for ActionError {
     fn into_response(self) -> Response {
         let (status_code, error_message) = match self {
             ActionError::InvalidOnHoldTransition(_) => (StatusCode::NOT_MODIFIED, self.to_string()),
+            ActionError::Pagination(PaginationError::InvalidCursor(_)) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            ActionError::Action(dal::action::ActionError::FuncRunNotFound(_)) => {
+                (StatusCode::NOT_FOUND, self.to_string())
+            }
+            ActionError::Action(
+                dal::action::ActionError::ComponentNotFoundForFuncRun(..)
+                | dal::action::ActionError::FuncRunNotForAction(_)
+                | dal::action::ActionError::PrototypeNoLongerApplies(..),
+            ) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
         };
 
@@ -75,11 +99,26 @@ impl IntoResponse for ActionError {
     }
 }
 
+/// The HTTP status [`status::status`] responds with for a rolled-up [`ResourceStatus`]. A warning
+/// is still a success, with the details in the body. An error comes from the provider the actions
+/// talked to rather than from sdf, so it is a bad gateway. Endpoints listing the results of many
+/// runs, such as [`history::history`], always respond with 200.
+pub(crate) fn resource_status_http_status(status: ResourceStatus) -> StatusCode {
+    match status {
+        ResourceStatus::Error => StatusCode::BAD_GATEWAY,
+        ResourceStatus::Ok | ResourceStatus::Warning => StatusCode::OK,
+    }
+}
+
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/list", get(list_actions::list_actions))
         .route("/put_on_hold", post(put_on_hold::put_on_hold))
         .route("/cancel", post(cancel::cancel))
+        .route("/resume", post(resume::resume))
         .route("/retry", post(retry::retry))
+        .route("/rerun", post(rerun::rerun))
+        .route("/dry_run", post(dry_run::dry_run))
         .route("/history", get(history::history))
+        .route("/status", get(status::status))
 }
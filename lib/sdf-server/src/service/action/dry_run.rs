@@ -0,0 +1,38 @@
+use axum::Json;
+use dal::action::prototype::{ActionDryRun, ActionPrototype};
+use dal::action::{Action, ActionId};
+use dal::Visibility;
+use serde::{Deserialize, Serialize};
+
+use super::ActionResult;
+use crate::extract::{v1::AccessBuilder, HandlerContext};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunRequest {
+    ids: Vec<ActionId>,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+pub type DryRunResponse = Vec<ActionDryRun>;
+
+/// Previews what each of the actions would do, without running them.
+pub async fn dry_run(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Json(request): Json<DryRunRequest>,
+) -> ActionResult<Json<DryRunResponse>> {
+    let ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let mut dry_runs = Vec::with_capacity(request.ids.len());
+    for action_id in request.ids {
+        let prototype_id = Action::prototype_id(&ctx, action_id).await?;
+        let component_id = Action::component_id(&ctx, action_id).await?.ok_or(
+            dal::action::ActionError::ComponentNotFoundForAction(action_id),
+        )?;
+        dry_runs.push(ActionPrototype::dry_run(&ctx, prototype_id, component_id).await?);
+    }
+
+    Ok(Json(dry_runs))
+}
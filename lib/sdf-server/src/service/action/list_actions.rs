@@ -1,16 +1,23 @@
+use std::collections::HashMap;
+
 use axum::extract::Query;
 use axum::Json;
 use dal::action::dependency_graph::ActionDependencyGraph;
-use dal::action::prototype::{ActionKind, ActionPrototype};
+use dal::action::prototype::{ActionKind, ActionPrototype, ActionRunFailure};
 use dal::action::{Action, ActionState};
-use dal::Func;
-use dal::{action::ActionId, ActionPrototypeId, ChangeSetId, ComponentId, Visibility};
+use dal::{action::ActionId, ActionPrototypeId, ChangeSetId, ComponentId, DalContext, Visibility};
+use dal::{Func, FuncId};
 use serde::{Deserialize, Serialize};
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
 use si_events::FuncRunId;
 use telemetry::prelude::*;
 
-use super::ActionResult;
-use crate::extract::{v1::AccessBuilder, HandlerContext};
+use super::{ActionError, ActionResult};
+use crate::{
+    extract::{v1::AccessBuilder, HandlerContext},
+    middleware::RequestCancellation,
+    service::pagination::{decode_key_cursor, encode_key_cursor, Page, PageRequest},
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,6 +31,8 @@ pub struct ActionView {
     pub state: ActionState,
     pub originating_change_set_id: ChangeSetId,
     pub func_run_id: Option<FuncRunId>,
+    /// Why the last run failed, when the action is [`Failed`](ActionState::Failed).
+    pub failure: Option<ActionRunFailure>,
     // Actions that will wait until I've successfully completed before running
     pub my_dependencies: Vec<ActionId>,
     // Things that need to finish before I can start
@@ -33,23 +42,119 @@ pub struct ActionView {
     pub hold_status_influenced_by: Vec<ActionId>,
 }
 
+#[serde_as]
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct LoadQueuedRequest {
+    /// When set, only the actions for this component are listed.
+    pub component_id: Option<ComponentId>,
+    /// When not empty, only the actions in one of these states are listed. Provided as a comma
+    /// separated list, e.g. "OnHold,Failed".
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, ActionState>")]
+    #[serde(default)]
+    pub states: Vec<ActionState>,
     #[serde(flatten)]
     pub visibility: Visibility,
 }
 
-pub type LoadQueuedResponse = Vec<ActionView>;
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
+pub enum LoadQueuedResponse {
+    All(Vec<ActionView>),
+    Page(Page<ActionView>),
+}
+
+/// Lists the ids of the actions in topological order, keeping only those for the component and in
+/// one of the states (when provided). Filtering happens after sorting, so the dependency order of
+/// the remaining actions is preserved.
+pub async fn list_action_ids(
+    ctx: &DalContext,
+    component_id: Option<ComponentId>,
+    states: &[ActionState],
+) -> ActionResult<Vec<ActionId>> {
+    let action_ids = match component_id {
+        Some(component_id) => {
+            Action::list_topologically_for_component_id(ctx, component_id).await?
+        }
+        None => Action::list_topologically(ctx).await?,
+    };
+
+    if states.is_empty() {
+        return Ok(action_ids);
+    }
+
+    let mut filtered = Vec::with_capacity(action_ids.len());
+    for action_id in action_ids {
+        if states.contains(&Action::get_by_id(ctx, action_id).await?.state()) {
+            filtered.push(action_id);
+        }
+    }
+    Ok(filtered)
+}
+
+/// The position of a page of actions, encoded as an opaque cursor. Both the last action returned
+/// and the first one held back are kept because either may succeed (and leave the action list)
+/// before the next page is requested.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct ActionPageCursor {
+    last: ActionId,
+    next: ActionId,
+}
+
+/// Selects a page of the topologically sorted action ids, starting after the cursor of the
+/// [`PageRequest`] (if provided), along with the cursor for the next page (if there is one).
+///
+/// Cursors identify actions rather than offsets so that actions finishing between requests do not
+/// shift later pages. When the last action of the previous page has left the list, the page
+/// resumes at the action that would have followed it. If that one is gone as well, the page
+/// starts from the beginning; since actions run in dependency order, the ones that finished are
+/// most likely the earliest, and repeating an action is preferable to skipping one.
+pub fn paginate_action_ids(
+    action_ids: Vec<ActionId>,
+    page_request: &PageRequest,
+) -> ActionResult<Page<ActionId>> {
+    let start = match page_request.cursor() {
+        Some(cursor) => {
+            let cursor: ActionPageCursor = decode_key_cursor(cursor)?;
+            let position_of = |wanted: ActionId| action_ids.iter().position(|&id| id == wanted);
+            position_of(cursor.last)
+                .map(|position| position + 1)
+                .or_else(|| position_of(cursor.next))
+                .unwrap_or(0)
+        }
+        None => 0,
+    };
+
+    let mut remaining = action_ids.into_iter().skip(start);
+    let items: Vec<ActionId> = remaining.by_ref().take(page_request.limit()).collect();
+    let next_cursor = match (items.last(), remaining.next()) {
+        (Some(&last), Some(next)) => Some(encode_key_cursor(&ActionPageCursor { last, next })?),
+        _ => None,
+    };
+
+    Ok(Page { items, next_cursor })
+}
 
 pub async fn list_actions(
     HandlerContext(builder): HandlerContext,
     AccessBuilder(request_ctx): AccessBuilder,
+    cancellation: RequestCancellation,
+    page_request: PageRequest,
     Query(request): Query<LoadQueuedRequest>,
 ) -> ActionResult<Json<LoadQueuedResponse>> {
     let ctx = builder.build(request_ctx.build(request.visibility)).await?;
 
-    let action_ids = Action::list_topologically(&ctx).await?;
+    let action_ids = list_action_ids(&ctx, request.component_id, &request.states).await?;
+
+    // Every action is returned unless the request asked for a page.
+    let paginated = page_request.is_requested();
+    let (action_ids, next_cursor) = if paginated {
+        let page = paginate_action_ids(action_ids, &page_request)?;
+        (page.items, page.next_cursor)
+    } else {
+        (action_ids, None)
+    };
 
     let mut queued = Vec::new();
 
@@ -58,12 +163,34 @@ pub async fn list_actions(
         warn!("action graph for {:?} has a cycle", request.visibility);
     }
 
-    for action_id in action_ids {
-        let action = Action::get_by_id(&ctx, action_id).await?;
-
+    let mut prototype_and_func_ids = Vec::with_capacity(action_ids.len());
+    for &action_id in &action_ids {
+        cancellation.check()?;
         let prototype_id = Action::prototype_id(&ctx, action_id).await?;
         let func_id = ActionPrototype::func_id(&ctx, prototype_id).await?;
-        let func = Func::get_by_id_or_error(&ctx, func_id).await?;
+        prototype_and_func_ids.push((prototype_id, func_id));
+    }
+    let mut func_ids: Vec<FuncId> = prototype_and_func_ids
+        .iter()
+        .map(|(_, func_id)| *func_id)
+        .collect();
+    func_ids.sort();
+    func_ids.dedup();
+    let funcs: HashMap<FuncId, Func> = Func::list_from_ids(&ctx, &func_ids)
+        .await?
+        .into_iter()
+        .map(|func| (func.id, func))
+        .collect();
+
+    for (action_id, (prototype_id, func_id)) in action_ids.into_iter().zip(prototype_and_func_ids) {
+        // Each action costs several lookups, so stop early if nobody is waiting for the result.
+        cancellation.check()?;
+
+        let action = Action::get_by_id(&ctx, action_id).await?;
+
+        let func = funcs
+            .get(&func_id)
+            .ok_or(ActionError::FuncNotFound(func_id))?;
         let prototype = ActionPrototype::get_by_id(&ctx, prototype_id).await?;
         let func_run_id = ctx
             .layer_db()
@@ -71,16 +198,23 @@ pub async fn list_actions(
             .get_last_run_for_action_id(ctx.events_tenancy().workspace_pk, action.id())
             .await?
             .map(|f| f.id());
+        let failure = match func_run_id {
+            Some(func_run_id) if action.state() == ActionState::Failed => {
+                ActionRunFailure::for_func_run(&ctx, func_run_id).await?
+            }
+            _ => None,
+        };
 
         let action_view = ActionView {
             id: action_id,
             prototype_id: prototype.id(),
             name: prototype.name().clone(),
             component_id: Action::component_id(&ctx, action_id).await?,
-            description: func.display_name,
+            description: func.display_name.clone(),
             kind: prototype.kind,
             state: action.state(),
             func_run_id,
+            failure,
             originating_change_set_id: action.originating_changeset_id(),
             my_dependencies: action_graph.get_all_dependencies(action_id),
             dependent_on: action_graph.direct_dependencies_of(action_id),
@@ -94,5 +228,12 @@ pub async fn list_actions(
         queued.push(action_view);
     }
 
-    Ok(Json(queued))
+    if paginated {
+        Ok(Json(LoadQueuedResponse::Page(Page {
+            items: queued,
+            next_cursor,
+        })))
+    } else {
+        Ok(Json(LoadQueuedResponse::All(queued)))
+    }
 }
@@ -0,0 +1,60 @@
+use axum::Json;
+use dal::action::prototype::ActionPrototype;
+use dal::action::{Action, ActionId};
+use dal::{Func, Visibility, WsEvent};
+use serde::{Deserialize, Serialize};
+use si_events::audit_log::AuditLogKind;
+use si_events::FuncRunId;
+
+use super::ActionResult;
+use crate::extract::{v1::AccessBuilder, HandlerContext};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RerunRequest {
+    /// The func run of the action to run again, as found in the action history.
+    func_run_id: FuncRunId,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RerunResponse {
+    pub action_id: ActionId,
+}
+
+/// Enqueues the action from a previous run again.
+pub async fn rerun(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Json(request): Json<RerunRequest>,
+) -> ActionResult<Json<RerunResponse>> {
+    let ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let action_id = Action::rerun_from_func_run(&ctx, request.func_run_id).await?;
+
+    let prototype_id = Action::prototype_id(&ctx, action_id).await?;
+    let prototype = ActionPrototype::get_by_id(&ctx, prototype_id).await?;
+    let func_id = ActionPrototype::func_id(&ctx, prototype_id).await?;
+    let func = Func::get_by_id_or_error(&ctx, func_id).await?;
+    ctx.write_audit_log(
+        AuditLogKind::RetryAction {
+            prototype_id,
+            action_kind: prototype.kind.into(),
+            func_id,
+            func_display_name: func.display_name,
+            func_name: func.name.clone(),
+        },
+        func.name,
+    )
+    .await?;
+    WsEvent::action_list_updated(&ctx)
+        .await?
+        .publish_on_commit(&ctx)
+        .await?;
+
+    ctx.commit().await?;
+
+    Ok(Json(RerunResponse { action_id }))
+}
@@ -0,0 +1,78 @@
+use axum::Json;
+use dal::{
+    action::{prototype::ActionPrototype, Action, ActionId, ActionState},
+    DalContext, Func, Visibility, WsEvent,
+};
+use serde::{Deserialize, Serialize};
+use si_events::audit_log::AuditLogKind;
+
+use super::ActionResult;
+use crate::{
+    extract::{v1::AccessBuilder, HandlerContext},
+    service::action::ActionError,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeRequest {
+    pub ids: Vec<ActionId>,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+/// Moves actions that are on hold back to the queue. Unlike a retry, only actions that are on
+/// hold can be resumed.
+pub async fn resume(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Json(request): Json<ResumeRequest>,
+) -> ActionResult<()> {
+    let ctx = builder.build(request_ctx.build(request.visibility)).await?;
+    for action_id in request.ids {
+        resume_action(&ctx, action_id).await?;
+    }
+    WsEvent::action_list_updated(&ctx)
+        .await?
+        .publish_on_commit(&ctx)
+        .await?;
+
+    ctx.commit().await?;
+
+    Ok(())
+}
+
+/// Moves a single action from [`OnHold`](ActionState::OnHold) to
+/// [`Queued`](ActionState::Queued). The hold status of the actions that depend on it is derived
+/// from the action graph, so it follows along without being updated here.
+pub async fn resume_action(ctx: &DalContext, action_id: ActionId) -> ActionResult<()> {
+    let action = Action::get_by_id(ctx, action_id).await?;
+
+    match action.state() {
+        ActionState::OnHold => {}
+        ActionState::Dispatched
+        | ActionState::Failed
+        | ActionState::Queued
+        | ActionState::Running => return Err(ActionError::InvalidOnHoldTransition(action_id)),
+    }
+
+    Action::set_state(ctx, action.id(), ActionState::Queued).await?;
+
+    let prototype_id = Action::prototype_id(ctx, action.id()).await?;
+    let prototype = ActionPrototype::get_by_id(ctx, prototype_id).await?;
+    let func_id = ActionPrototype::func_id(ctx, prototype_id).await?;
+    let func = Func::get_by_id_or_error(ctx, func_id).await?;
+
+    ctx.write_audit_log(
+        AuditLogKind::ResumeAction {
+            prototype_id: prototype.id(),
+            action_kind: prototype.kind.into(),
+            func_id,
+            func_display_name: func.display_name,
+            func_name: func.name.clone(),
+        },
+        func.name,
+    )
+    .await?;
+
+    Ok(())
+}
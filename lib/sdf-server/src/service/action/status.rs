@@ -0,0 +1,59 @@
+use axum::{
+    extract::Query,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dal::{Component, ComponentId, Visibility};
+use serde::{Deserialize, Serialize};
+use veritech_client::ResourceStatus;
+
+use super::{resource_status_http_status, ActionResult};
+use crate::extract::{v1::AccessBuilder, HandlerContext};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionStatusRequest {
+    pub component_id: ComponentId,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionStatusResponse {
+    pub status: ResourceStatus,
+    pub warning: Option<String>,
+}
+
+impl ActionStatusResponse {
+    pub fn new(status: ResourceStatus) -> Self {
+        let warning = match status {
+            ResourceStatus::Warning => {
+                Some("some actions for this component have not reported a result".to_string())
+            }
+            ResourceStatus::Error | ResourceStatus::Ok => None,
+        };
+        Self { status, warning }
+    }
+}
+
+impl IntoResponse for ActionStatusResponse {
+    fn into_response(self) -> Response {
+        (resource_status_http_status(self.status), Json(self)).into_response()
+    }
+}
+
+/// Rolls the results of the actions run for a component in this change set up into a single
+/// status.
+pub async fn status(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Query(request): Query<ActionStatusRequest>,
+) -> ActionResult<ActionStatusResponse> {
+    let ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let status =
+        Component::aggregate_action_status(&ctx, request.component_id, ctx.change_set_id()).await?;
+
+    Ok(ActionStatusResponse::new(status))
+}
@@ -8,6 +8,7 @@ use axum::{
     routing::{get, post},
     Router,
 };
+use dal::component::refresh::ComponentRefreshError;
 use dal::slow_rt::SlowRuntimeError;
 use dal::validation::ValidationError;
 use dal::{
@@ -35,14 +36,17 @@ pub mod delete_property_editor_value;
 pub mod get_actions;
 pub mod get_code;
 pub mod get_diff;
+pub mod get_property_diff;
 pub mod get_property_editor_schema;
 pub mod get_property_editor_values;
 pub mod get_resource;
 pub mod insert_property_editor_value;
 pub mod json;
 pub mod list_qualifications;
+pub mod list_refresh_summaries;
 mod manage;
 pub mod refresh;
+pub mod rename_property_editor_map_key;
 pub mod restore_default_function;
 pub mod set_name;
 pub mod set_resource_id;
@@ -66,6 +70,8 @@ pub enum ComponentError {
     ChangeSet(#[from] ChangeSetError),
     #[error("component debug view error: {0}")]
     ComponentDebugView(#[from] ComponentDebugViewError),
+    #[error("component refresh error: {0}")]
+    ComponentRefresh(#[from] ComponentRefreshError),
     #[error("dal component error: {0}")]
     DalComponent(#[from] DalComponentError),
     #[error("diagram error: {0}")]
@@ -152,6 +158,12 @@ impl IntoResponse for ComponentError {
             ComponentError::KeyAlreadyExists(_) => {
                 (StatusCode::UNPROCESSABLE_ENTITY, self.to_string())
             }
+            ComponentError::AttributeValue(AttributeValueError::MapKeyAlreadyExists(..)) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, self.to_string())
+            }
+            ComponentError::AttributeValue(AttributeValueError::MapKeyNotFound(..)) => {
+                (StatusCode::NOT_FOUND, self.to_string())
+            }
             ComponentError::DalComponent(err) => match err {
                 DalComponentError::NotFound(_) => (StatusCode::NOT_FOUND, err.to_string()),
                 _ => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
@@ -180,6 +192,10 @@ pub fn routes() -> Router<AppState> {
         )
         .route("/get_code", get(get_code::get_code))
         .route("/get_diff", get(get_diff::get_diff))
+        .route(
+            "/get_property_diff",
+            get(get_property_diff::get_property_diff),
+        )
         .route("/get_resource", get(get_resource::get_resource))
         .route(
             "/update_property_editor_value",
@@ -193,6 +209,10 @@ pub fn routes() -> Router<AppState> {
             "/delete_property_editor_value",
             post(delete_property_editor_value::delete_property_editor_value),
         )
+        .route(
+            "/rename_property_editor_map_key",
+            post(rename_property_editor_map_key::rename_property_editor_map_key),
+        )
         .route(
             "/restore_default_function",
             post(restore_default_function::restore_default_function),
@@ -201,6 +221,10 @@ pub fn routes() -> Router<AppState> {
         .route("/set_name", post(set_name::set_name))
         .route("/set_resource_id", post(set_resource_id::set_resource_id))
         .route("/refresh", post(refresh::refresh))
+        .route(
+            "/list_refresh_summaries",
+            get(list_refresh_summaries::list_refresh_summaries),
+        )
         .route("/debug", get(debug::debug_component))
         .route("/autoconnect", post(autoconnect::autoconnect))
         .route("/json", get(json::json))
@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Host, OriginalUri, Query},
+    Json,
+};
+use dal::{component::diff::ComponentPropertyDiff, Component, ComponentId, Visibility};
+use serde::{Deserialize, Serialize};
+
+use super::ComponentResult;
+use crate::{
+    extract::{v1::AccessBuilder, HandlerContext, PosthogClient},
+    track,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPropertyDiffRequest {
+    pub component_id: ComponentId,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPropertyDiffResponse {
+    pub property_diff: ComponentPropertyDiff,
+}
+
+pub async fn get_property_diff(
+    OriginalUri(original_uri): OriginalUri,
+    Host(host_name): Host,
+    PosthogClient(posthog_client): PosthogClient,
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Query(request): Query<GetPropertyDiffRequest>,
+) -> ComponentResult<Json<GetPropertyDiffResponse>> {
+    let ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let property_diff = Component::get_property_diff(&ctx, request.component_id).await?;
+
+    track(
+        &posthog_client,
+        &ctx,
+        &original_uri,
+        &host_name,
+        "get_property_diff",
+        serde_json::json!({
+            "how": "/component/get_property_diff",
+            "component_id": request.component_id,
+            "change_set_id": ctx.change_set_id(),
+        }),
+    );
+
+    Ok(Json(GetPropertyDiffResponse { property_diff }))
+}
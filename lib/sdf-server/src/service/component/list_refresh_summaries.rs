@@ -0,0 +1,28 @@
+use axum::{extract::Query, Json};
+use dal::{component::refresh::RefreshSummary, ComponentId, Visibility};
+use serde::{Deserialize, Serialize};
+
+use super::ComponentResult;
+use crate::extract::{v1::AccessBuilder, HandlerContext};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRefreshSummariesRequest {
+    pub component_id: ComponentId,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+pub type ListRefreshSummariesResponse = Vec<RefreshSummary>;
+
+pub async fn list_refresh_summaries(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Query(request): Query<ListRefreshSummariesRequest>,
+) -> ComponentResult<Json<ListRefreshSummariesResponse>> {
+    let ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let summaries = RefreshSummary::list_for_component(&ctx, request.component_id).await?;
+
+    Ok(Json(summaries))
+}
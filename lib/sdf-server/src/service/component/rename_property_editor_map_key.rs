@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use axum::Json;
+use dal::{
+    AttributeValue, AttributeValueId, ChangeSet, Component, ComponentId, Visibility, WsEvent,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    extract::{v1::AccessBuilder, HandlerContext},
+    service::{component::ComponentResult, force_change_set_response::ForceChangeSetResponse},
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamePropertyEditorMapKeyRequest {
+    /// The map's attribute value, not the entry being renamed.
+    pub attribute_value_id: AttributeValueId,
+    pub component_id: ComponentId,
+    pub old_key: String,
+    pub new_key: String,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+pub async fn rename_property_editor_map_key(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    Json(request): Json<RenamePropertyEditorMapKeyRequest>,
+) -> ComponentResult<ForceChangeSetResponse<()>> {
+    let mut ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let force_change_set_id = ChangeSet::force_new(&mut ctx).await?;
+
+    AttributeValue::rename_map_key(
+        &ctx,
+        request.attribute_value_id,
+        request.old_key,
+        request.new_key,
+    )
+    .await?;
+
+    let component = Component::get_by_id(&ctx, request.component_id).await?;
+    let mut socket_map = HashMap::new();
+    let payload = component
+        .into_frontend_type(
+            &ctx,
+            None,
+            component.change_status(&ctx).await?,
+            &mut socket_map,
+        )
+        .await?;
+    WsEvent::component_updated(&ctx, payload)
+        .await?
+        .publish_on_commit(&ctx)
+        .await?;
+
+    ctx.commit().await?;
+
+    Ok(ForceChangeSetResponse::empty(force_change_set_id))
+}
@@ -20,6 +20,8 @@ pub struct RestoreDefaultFunctionRequest {
     pub visibility: Visibility,
 }
 
+/// Reverts a value to its default rather than setting it to null. Restoring a value that was never
+/// set is a no-op. See [`AttributeValue::unset`] for details.
 pub async fn restore_default_function(
     OriginalUri(original_uri): OriginalUri,
     Host(host_name): Host,
@@ -32,7 +34,7 @@ pub async fn restore_default_function(
 
     let force_change_set_id = ChangeSet::force_new(&mut ctx).await?;
 
-    AttributeValue::use_default_prototype(&ctx, request.attribute_value_id).await?;
+    AttributeValue::unset(&ctx, request.attribute_value_id).await?;
 
     track(
         &posthog_client,
@@ -24,9 +24,10 @@ use thiserror::Error;
 use tokio::task::JoinError;
 
 use super::ApiError;
-use crate::AppState;
+use crate::{middleware::RequestCancelled, AppState};
 
 pub mod create_component;
+pub mod create_components;
 pub mod create_connection;
 pub mod get_diagram;
 pub mod list_schemas;
@@ -105,6 +106,8 @@ pub enum DiagramError {
     PgPool(#[from] si_data_pg::PgPoolError),
     #[error("pkg error: {0}")]
     Pkg(#[from] PkgError),
+    #[error(transparent)]
+    RequestCancelled(#[from] RequestCancelled),
     #[error("schema error: {0}")]
     Schema(#[from] SchemaError),
     #[error("schema not found")]
@@ -180,6 +183,10 @@ pub fn routes() -> Router<AppState> {
             "/create_component",
             post(create_component::create_component),
         )
+        .route(
+            "/create_components",
+            post(create_components::create_components),
+        )
         .route(
             "/set_component_position",
             post(set_component_position::set_component_position),
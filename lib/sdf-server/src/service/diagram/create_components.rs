@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Host, OriginalUri},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use dal::diagram::view::{View, ViewId};
+use dal::{
+    change_status::ChangeStatus, component::frame::Frame, generate_name, ChangeSet, Component,
+    ComponentId, DalContext, SchemaVariant, SchemaVariantId, Visibility, WsEvent,
+};
+use si_events::audit_log::AuditLogKind;
+
+use crate::{
+    extract::{v1::AccessBuilder, HandlerContext, PosthogClient},
+    service::{diagram::DiagramResult, force_change_set_response::ForceChangeSetResponse},
+    track,
+};
+
+use super::DiagramError;
+
+/// The parent of a component created via [`create_components`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum CreateComponentsParent {
+    /// A component that already exists.
+    ComponentId(ComponentId),
+    /// A component created earlier in the same batch, by its position in the request.
+    Index(usize),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateComponentsItem {
+    pub schema_variant_id: SchemaVariantId,
+    pub parent: Option<CreateComponentsParent>,
+    pub x: String,
+    pub y: String,
+    pub height: Option<String>,
+    pub width: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateComponentsRequest {
+    pub components: Vec<CreateComponentsItem>,
+    #[serde(flatten)]
+    pub visibility: Visibility,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateComponentsResponse {
+    /// The ids of the created components, in the order they were requested.
+    pub component_ids: Vec<ComponentId>,
+}
+
+/// Create many components in a single transaction. Creating change-set if on head.
+pub async fn create_components(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(request_ctx): AccessBuilder,
+    PosthogClient(posthog_client): PosthogClient,
+    OriginalUri(original_uri): OriginalUri,
+    Host(host_name): Host,
+    Json(request): Json<CreateComponentsRequest>,
+) -> DiagramResult<ForceChangeSetResponse<CreateComponentsResponse>> {
+    let mut ctx = builder.build(request_ctx.build(request.visibility)).await?;
+
+    let force_change_set_id = ChangeSet::force_new(&mut ctx).await?;
+
+    let view_id = View::get_id_for_default(&ctx).await?;
+    let components = create_components_in_view(&ctx, view_id, &request.components).await?;
+
+    let mut diagram_sockets = HashMap::new();
+    for component in &components {
+        let payload = component
+            .into_frontend_type_for_default_view(&ctx, ChangeStatus::Added, &mut diagram_sockets)
+            .await?;
+        WsEvent::component_created(&ctx, payload)
+            .await?
+            .publish_on_commit(&ctx)
+            .await?;
+    }
+
+    let component_ids: Vec<ComponentId> = components.iter().map(|c| c.id()).collect();
+
+    track(
+        &posthog_client,
+        &ctx,
+        &original_uri,
+        &host_name,
+        "components_created",
+        serde_json::json!({
+            "how": "/diagram/create_components",
+            "component_ids": component_ids,
+            "change_set_id": ctx.change_set_id(),
+        }),
+    );
+
+    ctx.commit().await?;
+
+    Ok(ForceChangeSetResponse::new(
+        force_change_set_id,
+        CreateComponentsResponse { component_ids },
+    ))
+}
+
+/// Creates a [`Component`] in the given [`View`] for each item, in order. An item may be
+/// parented to a component created before it in the same batch by its index.
+pub async fn create_components_in_view(
+    ctx: &DalContext,
+    view_id: ViewId,
+    items: &[CreateComponentsItem],
+) -> DiagramResult<Vec<Component>> {
+    let mut components: Vec<Component> = Vec::with_capacity(items.len());
+
+    for (index, item) in items.iter().enumerate() {
+        let parent_id = match item.parent {
+            Some(CreateComponentsParent::ComponentId(component_id)) => Some(component_id),
+            Some(CreateComponentsParent::Index(parent_index)) => Some(
+                components
+                    .get(parent_index)
+                    .map(|parent| parent.id())
+                    .ok_or_else(|| {
+                        DiagramError::InvalidRequest(format!(
+                            "component {index} has parent index {parent_index}, which is not an earlier component in the batch"
+                        ))
+                    })?,
+            ),
+            None => None,
+        };
+
+        let (Ok(x), Ok(y), Ok(width), Ok(height)) = (
+            item.x.parse::<isize>(),
+            item.y.parse::<isize>(),
+            item.width.as_ref().map(|w| w.parse::<isize>()).transpose(),
+            item.height.as_ref().map(|h| h.parse::<isize>()).transpose(),
+        ) else {
+            return Err(DiagramError::InvalidRequest(format!(
+                "geometry unable to be parsed for component {index} of create components request"
+            )));
+        };
+
+        let name = generate_name();
+        let variant = SchemaVariant::get_by_id_or_error(ctx, item.schema_variant_id).await?;
+        let mut component = Component::new(ctx, &name, variant.id(), view_id).await?;
+        let initial_geometry = component.geometry(ctx, view_id).await?;
+        ctx.write_audit_log(
+            AuditLogKind::CreateComponent {
+                name: name.to_string(),
+                component_id: component.id(),
+                schema_variant_id: variant.id(),
+                schema_variant_name: variant.display_name().to_owned(),
+            },
+            name.to_string(),
+        )
+        .await?;
+
+        component
+            .set_geometry(
+                ctx,
+                view_id,
+                x,
+                y,
+                width.or_else(|| initial_geometry.width()),
+                height.or_else(|| initial_geometry.height()),
+            )
+            .await?;
+
+        if let Some(parent_id) = parent_id {
+            Frame::upsert_parent(ctx, component.id(), parent_id).await?;
+        }
+
+        components.push(component);
+    }
+
+    Ok(components)
+}
@@ -1,6 +1,7 @@
 use super::{DiagramError, DiagramResult};
 use crate::{
     extract::{v1::AccessBuilder, HandlerContext, PosthogClient},
+    middleware::RequestCancellation,
     track,
 };
 use axum::{
@@ -25,14 +26,21 @@ pub async fn get_diagram(
     PosthogClient(posthog_client): PosthogClient,
     HandlerContext(builder): HandlerContext,
     AccessBuilder(request_ctx): AccessBuilder,
+    cancellation: RequestCancellation,
     Query(request): Query<GetDiagramRequest>,
 ) -> DiagramResult<Json<GetDiagramResponse>> {
     let ctx = builder.build(request_ctx.build(request.visibility)).await?;
     let ctx_clone = ctx.clone();
 
+    // The spawned task outlives this handler if the client disconnects, so it listens for
+    // cancellation itself.
     let response = slow_rt::spawn(async move {
         let ctx = &ctx_clone;
-        Ok::<Diagram, DiagramError>(Diagram::assemble_for_default_view(ctx).await?)
+        Ok::<Diagram, DiagramError>(
+            cancellation
+                .run(Diagram::assemble_for_default_view(ctx))
+                .await??,
+        )
     })?
     .await??;
 
@@ -0,0 +1,129 @@
+//! This module contains the health route, which reports whether sdf can reach each of its
+//! downstream dependencies.
+
+use std::{fmt, future::Future, time::Duration};
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use telemetry::prelude::*;
+
+use crate::AppState;
+
+/// How long a single dependency has to respond before it is considered down.
+pub const DEPENDENCY_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/", get(health))
+}
+
+/// The reachability of a single downstream dependency.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyHealth {
+    pub name: String,
+    /// Whether or not sdf can serve requests without this dependency.
+    pub critical: bool,
+    pub healthy: bool,
+    pub error: Option<String>,
+}
+
+impl DependencyHealth {
+    /// Pings a dependency, treating an error or a response slower than `timeout` as down.
+    pub async fn check<F, E>(
+        name: impl Into<String>,
+        critical: bool,
+        timeout: Duration,
+        ping: F,
+    ) -> Self
+    where
+        F: Future<Output = Result<(), E>>,
+        E: fmt::Display,
+    {
+        let name = name.into();
+        let error = match tokio::time::timeout(timeout, ping).await {
+            Ok(Ok(())) => None,
+            Ok(Err(err)) => Some(err.to_string()),
+            Err(_) => Some(format!("no response after {}ms", timeout.as_millis())),
+        };
+        if let Some(error) = &error {
+            warn!(
+                si.error.message = %error,
+                dependency = %name,
+                "dependency health check failed"
+            );
+        }
+
+        Self {
+            name,
+            critical,
+            healthy: error.is_none(),
+            error,
+        }
+    }
+}
+
+/// The reachability of all downstream dependencies. Responds with a 503 if any critical
+/// dependency is down.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponse {
+    pub ok: bool,
+    pub dependencies: Vec<DependencyHealth>,
+}
+
+impl From<Vec<DependencyHealth>> for HealthResponse {
+    fn from(dependencies: Vec<DependencyHealth>) -> Self {
+        Self {
+            ok: dependencies
+                .iter()
+                .all(|dependency| dependency.healthy || !dependency.critical),
+            dependencies,
+        }
+    }
+}
+
+impl IntoResponse for HealthResponse {
+    fn into_response(self) -> Response {
+        let status_code = if self.ok {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        };
+        (status_code, Json(self)).into_response()
+    }
+}
+
+// Veritech and the rebaser are only reachable over NATS and have no ping of their own, so they are
+// covered by the NATS check.
+async fn health(State(state): State<AppState>) -> HealthResponse {
+    let services_context = state.services_context();
+
+    let (pg, nats, layer_db_pg) = tokio::join!(
+        DependencyHealth::check(
+            "postgres",
+            true,
+            DEPENDENCY_TIMEOUT,
+            services_context.pg_pool().test_connection(),
+        ),
+        DependencyHealth::check(
+            "nats",
+            true,
+            DEPENDENCY_TIMEOUT,
+            services_context.nats_conn().flush(),
+        ),
+        DependencyHealth::check(
+            "layer_db_postgres",
+            true,
+            DEPENDENCY_TIMEOUT,
+            services_context.layer_db().pg_pool().test_connection(),
+        ),
+    );
+
+    HealthResponse::from(vec![pg, nats, layer_db_pg])
+}
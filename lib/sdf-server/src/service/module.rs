@@ -24,7 +24,7 @@ use tokio::fs::read_dir;
 use ulid::Ulid;
 
 use super::ApiError;
-use crate::AppState;
+use crate::{middleware::RequestCancelled, AppState};
 
 const PKG_EXTENSION: &str = "sipkg";
 const MAX_NAME_SEARCH_ATTEMPTS: usize = 100;
@@ -87,6 +87,8 @@ pub enum ModuleError {
     Pg(#[from] si_data_pg::PgError),
     #[error("pg pool error: {0}")]
     PgPool(#[from] si_data_pg::PgPoolError),
+    #[error(transparent)]
+    RequestCancelled(#[from] RequestCancelled),
     #[error("reqwest error: {0}")]
     Reqwest(#[from] reqwest::Error),
     #[error("schema error: {0}")]
@@ -14,6 +14,7 @@ use ulid::Ulid;
 
 use crate::{
     extract::{request::RawAccessToken, v1::AccessBuilder, HandlerContext, PosthogClient},
+    middleware::RequestCancellation,
     service::{force_change_set_response::ForceChangeSetResponse, module::ModuleError},
     track,
 };
@@ -35,6 +36,7 @@ pub async fn install_module(
     PosthogClient(posthog_client): PosthogClient,
     OriginalUri(original_uri): OriginalUri,
     Host(host_name): Host,
+    cancellation: RequestCancellation,
     Json(request): Json<InstallModuleRequest>,
 ) -> Result<ForceChangeSetResponse<Vec<FrontendVariant>>, ModuleError> {
     let mut ctx = builder.build(request_ctx.build(request.visibility)).await?;
@@ -87,6 +89,7 @@ pub async fn install_module(
 
     // After validating that we can install the modules, get on with it.
     for (id, module_details) in ids_with_details {
+        cancellation.check()?;
         let pkg_data = module_index_client.download_module(id).await?;
 
         let pkg = SiPkg::load_from_bytes(&pkg_data)?;
@@ -100,16 +103,17 @@ pub async fn install_module(
             )
         };
         let metadata = pkg.metadata()?;
-        let (_, svs, _) = match import_pkg_from_pkg(
-            &ctx,
-            &pkg,
-            Some(ImportOptions {
-                schema_id,
-                past_module_hashes,
-                ..Default::default()
-            }),
-        )
-        .await
+        let (_, svs, _) = match cancellation
+            .run(import_pkg_from_pkg(
+                &ctx,
+                &pkg,
+                Some(ImportOptions {
+                    schema_id,
+                    past_module_hashes,
+                    ..Default::default()
+                }),
+            ))
+            .await?
         {
             Ok(details) => details,
             Err(err) => {
@@ -151,6 +155,8 @@ pub async fn install_module(
         };
     }
 
+    // Nothing has been written yet, so a client that went away leaves no trace.
+    cancellation.check()?;
     ctx.commit().await?;
 
     Ok(ForceChangeSetResponse::new(force_change_set_id, variants))
@@ -12,6 +12,7 @@ use si_frontend_types::SchemaVariant as FrontendVariant;
 
 use crate::{
     extract::{v1::AccessBuilder, HandlerContext, PosthogClient},
+    middleware::RequestCancellation,
     service::{force_change_set_response::ForceChangeSetResponse, module::ModuleError},
     track,
 };
@@ -32,6 +33,7 @@ pub async fn upgrade_modules(
     PosthogClient(posthog_client): PosthogClient,
     OriginalUri(original_uri): OriginalUri,
     Host(host_name): Host,
+    cancellation: RequestCancellation,
     Json(request): Json<UpgradeModulesRequest>,
 ) -> Result<ForceChangeSetResponse<Vec<FrontendVariant>>, ModuleError> {
     let mut ctx = builder.build(request_ctx.build(request.visibility)).await?;
@@ -41,6 +43,7 @@ pub async fn upgrade_modules(
     let mut variants = Vec::new();
 
     for schema_id in request.schema_ids {
+        cancellation.check()?;
         let schema_exists_locally = Schema::exists_locally(&ctx, schema_id).await?;
         let at_least_one_unlocked_variant = SchemaVariant::get_unlocked_for_schema(&ctx, schema_id)
             .await?
@@ -60,15 +63,16 @@ pub async fn upgrade_modules(
         let si_pkg = cached_module.si_pkg(&ctx).await?;
 
         let metadata = si_pkg.metadata()?;
-        let (_, schema_variant_ids, _) = match import_pkg_from_pkg(
-            &ctx,
-            &si_pkg,
-            Some(ImportOptions {
-                schema_id: Some(schema_id.into()),
-                ..Default::default()
-            }),
-        )
-        .await
+        let (_, schema_variant_ids, _) = match cancellation
+            .run(import_pkg_from_pkg(
+                &ctx,
+                &si_pkg,
+                Some(ImportOptions {
+                    schema_id: Some(schema_id.into()),
+                    ..Default::default()
+                }),
+            ))
+            .await?
         {
             Ok(details) => details,
             Err(err) => {
@@ -110,6 +114,8 @@ pub async fn upgrade_modules(
         };
     }
 
+    // Nothing has been written yet, so a client that went away leaves no trace.
+    cancellation.check()?;
     ctx.commit().await?;
 
     Ok(ForceChangeSetResponse::new(force_change_set_id, variants))
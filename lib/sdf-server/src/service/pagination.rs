@@ -0,0 +1,178 @@
+//! This module contains [`PageRequest`] and [`Page`], which provide consistent pagination for
+//! list endpoints.
+//!
+//! Handlers take a [`PageRequest`] as an extractor (read from the "limit" and "cursor" query
+//! parameters) and wrap their results with [`Page::paginate`]. Cursors are opaque to clients:
+//! the "nextCursor" of one page is passed back as the "cursor" of the next request.
+//!
+//! Offset cursors shift when items before them disappear between requests. Endpoints listing
+//! items that can go away (e.g. actions that succeed) should instead encode a key identifying
+//! their position with [`encode_key_cursor`] and resolve [`PageRequest::cursor`] against the
+//! current items.
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use base64::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::extract::{bad_request, ErrorResponse};
+
+/// The number of items returned when the request does not provide a limit.
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+/// The largest number of items that can be requested in a single page.
+pub const MAX_PAGE_LIMIT: usize = 500;
+
+#[remain::sorted]
+#[derive(Debug, Error)]
+pub enum PaginationError {
+    #[error("could not encode page cursor: {0}")]
+    EncodeCursor(#[source] serde_json::Error),
+    #[error("invalid page cursor: {0}")]
+    InvalidCursor(String),
+}
+
+pub type PaginationResult<T> = Result<T, PaginationError>;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RawPageRequest {
+    limit: Option<usize>,
+    cursor: Option<String>,
+}
+
+/// A request for a single [`Page`] of results.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PageRequest {
+    limit: usize,
+    cursor: Option<String>,
+    requested: bool,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_PAGE_LIMIT,
+            cursor: None,
+            requested: false,
+        }
+    }
+}
+
+impl PageRequest {
+    /// Creates a [`PageRequest`], clamping the limit to [`MAX_PAGE_LIMIT`].
+    pub fn new(limit: Option<usize>, cursor: Option<&str>) -> Self {
+        Self {
+            requested: limit.is_some() || cursor.is_some(),
+            limit: page_limit(limit),
+            cursor: cursor.map(ToOwned::to_owned),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The cursor exactly as the client sent it, for endpoints that use [`encode_key_cursor`].
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    /// Decodes the cursor produced by [`Page::paginate`] into the offset of the first item.
+    pub fn offset(&self) -> PaginationResult<usize> {
+        match &self.cursor {
+            Some(cursor) => decode_cursor(cursor),
+            None => Ok(0),
+        }
+    }
+
+    /// Whether the request asked for a page by providing a limit or a cursor. Endpoints that
+    /// returned every item before they were paginated keep doing so when it did not.
+    pub fn is_requested(&self) -> bool {
+        self.requested
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for PageRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = ErrorResponse;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(raw) = Query::<RawPageRequest>::from_request_parts(parts, state)
+            .await
+            .map_err(bad_request)?;
+        Ok(Self::new(raw.limit, raw.cursor.as_deref()))
+    }
+}
+
+/// A single page of results, along with the cursor for the next page (if there is one).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Selects the items covered by the [`PageRequest`]. The items must be provided in a stable
+    /// order for cursors to be meaningful across requests.
+    pub fn paginate(
+        items: impl IntoIterator<Item = T>,
+        page_request: &PageRequest,
+    ) -> PaginationResult<Self> {
+        let offset = page_request.offset()?;
+        let mut items = items.into_iter().skip(offset);
+        let page_items: Vec<T> = items.by_ref().take(page_request.limit).collect();
+
+        let next_cursor = if items.next().is_some() {
+            Some(encode_cursor(offset + page_items.len()))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            items: page_items,
+            next_cursor,
+        })
+    }
+}
+
+/// Returns the number of items to put in a page, defaulting to [`DEFAULT_PAGE_LIMIT`] and clamping
+/// to [`MAX_PAGE_LIMIT`].
+pub fn page_limit(limit: Option<usize>) -> usize {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT)
+}
+
+/// Encodes a key as an opaque cursor.
+pub fn encode_key_cursor<K: Serialize>(key: &K) -> PaginationResult<String> {
+    let json = serde_json::to_vec(key).map_err(PaginationError::EncodeCursor)?;
+    Ok(BASE64_URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Decodes a cursor produced by [`encode_key_cursor`].
+pub fn decode_key_cursor<K: DeserializeOwned>(cursor: &str) -> PaginationResult<K> {
+    BASE64_URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(|| PaginationError::InvalidCursor(cursor.to_string()))
+}
+
+fn encode_cursor(offset: usize) -> String {
+    BASE64_URL_SAFE_NO_PAD.encode(offset.to_string())
+}
+
+fn decode_cursor(cursor: &str) -> PaginationResult<usize> {
+    BASE64_URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|offset| offset.parse().ok())
+        .ok_or_else(|| PaginationError::InvalidCursor(cursor.to_string()))
+}
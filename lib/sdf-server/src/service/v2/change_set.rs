@@ -19,6 +19,7 @@ use crate::{middleware::WorkspacePermissionLayer, service::ApiError, AppState};
 
 mod apply;
 mod approve;
+mod blast_radius;
 mod cancel_approval_request;
 mod force_apply;
 mod list;
@@ -35,6 +36,8 @@ mod approve_v2;
 #[remain::sorted]
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("blast radius error: {0}")]
+    BlastRadius(#[from] dal::change_set::blast_radius::BlastRadiusError),
     #[error("change set error: {0}")]
     ChangeSet(#[from] dal::ChangeSetError),
     #[error("change set apply error: {0}")]
@@ -158,6 +161,7 @@ pub fn change_set_routes(state: AppState) -> Router<AppState> {
         )
         .route("/rename", post(rename::rename))
         .route("/approval_status", get(approval_status::approval_status))
+        .route("/blast_radius", get(blast_radius::blast_radius))
         .route("/approve_v2", post(approve_v2::approve))
         .route("/apply_v2", post(apply_v2::apply))
 }
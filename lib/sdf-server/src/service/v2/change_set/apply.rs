@@ -1,6 +1,11 @@
-use axum::extract::{Host, OriginalUri, Path};
+use axum::{
+    extract::{Host, OriginalUri, Path},
+    Json,
+};
 use dal::{ChangeSet, ChangeSetId, WorkspacePk};
+use serde::Serialize;
 use si_events::audit_log::AuditLogKind;
+use telemetry::prelude::*;
 
 use super::{post_to_webhook, Result};
 use crate::{
@@ -9,6 +14,14 @@ use crate::{
     track,
 };
 
+/// The response to an apply. Applies are retried, so a change set that was already applied is
+/// still a success, but it is reported so that callers can tell nothing new was applied.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyResponse {
+    pub already_applied: bool,
+}
+
 pub async fn apply(
     HandlerContext(builder): HandlerContext,
     AccessBuilder(request_ctx): AccessBuilder,
@@ -16,11 +29,20 @@ pub async fn apply(
     OriginalUri(original_uri): OriginalUri,
     Host(host_name): Host,
     Path((workspace_pk, change_set_id)): Path<(WorkspacePk, ChangeSetId)>,
-) -> Result<()> {
+) -> Result<Json<ApplyResponse>> {
     let mut ctx = builder
         .build(request_ctx.build(change_set_id.into()))
         .await?;
     let change_set = ChangeSet::get_by_id(&ctx, change_set_id).await?;
+
+    // Retried applies succeed without re-applying, tracking or notifying anyone again, but say so.
+    if change_set.is_applied() {
+        info!(%change_set_id, "change set already applied");
+        return Ok(Json(ApplyResponse {
+            already_applied: true,
+        }));
+    }
+
     ChangeSet::prepare_for_apply(&ctx).await?;
 
     // We need to run a commit before apply so changes get saved
@@ -60,5 +82,7 @@ pub async fn apply(
     // WS Event fires from the dal
     ctx.commit().await?;
 
-    Ok(())
+    Ok(Json(ApplyResponse {
+        already_applied: false,
+    }))
 }
@@ -1,8 +1,12 @@
-use axum::extract::{Host, OriginalUri, Path, State};
+use axum::{
+    extract::{Host, OriginalUri, Path, State},
+    Json,
+};
 use dal::{ChangeSet, ChangeSetId, WorkspacePk};
 use si_events::audit_log::AuditLogKind;
+use telemetry::prelude::*;
 
-use super::{post_to_webhook, ChangeSetAPIError, Result};
+use super::{apply::ApplyResponse, post_to_webhook, ChangeSetAPIError, Result};
 use crate::{
     dal_wrapper,
     extract::{HandlerContext, PosthogClient},
@@ -18,7 +22,7 @@ pub async fn apply(
     Host(host_name): Host,
     Path((workspace_pk, change_set_id)): Path<(WorkspacePk, ChangeSetId)>,
     State(mut state): State<AppState>,
-) -> Result<()> {
+) -> Result<Json<ApplyResponse>> {
     let mut ctx = builder
         .build(request_ctx.build(change_set_id.into()))
         .await?;
@@ -26,6 +30,17 @@ pub async fn apply(
         .spicedb_client()
         .ok_or(ChangeSetAPIError::SpiceDBClientNotFound)?;
 
+    // Retried applies succeed without re-applying, tracking or notifying anyone again, but say so.
+    if ChangeSet::get_by_id(&ctx, change_set_id)
+        .await?
+        .is_applied()
+    {
+        info!(%change_set_id, "change set already applied");
+        return Ok(Json(ApplyResponse {
+            already_applied: true,
+        }));
+    }
+
     // Perform the protected apply flow.
     dal_wrapper::change_set::protected_apply_to_base_change_set(&mut ctx, spicedb_client).await?;
 
@@ -65,5 +80,7 @@ pub async fn apply(
         ctx.commit().await?;
     }
 
-    Ok(())
+    Ok(Json(ApplyResponse {
+        already_applied: false,
+    }))
 }
@@ -0,0 +1,19 @@
+use axum::{extract::Path, Json};
+use dal::{change_set::blast_radius::ChangeSetBlastRadius, ChangeSetId, WorkspacePk};
+
+use super::Result;
+use crate::{extract::HandlerContext, service::v2::AccessBuilder};
+
+/// Previews the components and actions that applying the change set would affect, without
+/// applying it.
+pub async fn blast_radius(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(access_builder): AccessBuilder,
+    Path((_workspace_pk, change_set_id)): Path<(WorkspacePk, ChangeSetId)>,
+) -> Result<Json<ChangeSetBlastRadius>> {
+    let ctx = builder
+        .build(access_builder.build(change_set_id.into()))
+        .await?;
+
+    Ok(Json(ChangeSetBlastRadius::compute(&ctx).await?))
+}
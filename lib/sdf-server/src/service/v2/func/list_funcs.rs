@@ -63,9 +63,7 @@ async fn treat_single_function(
                 continue;
             };
 
-            let maybe_existing_components =
-                SchemaVariant::list_component_ids(ctx, schema_variant_id).await?;
-            if !maybe_existing_components.is_empty() {
+            if SchemaVariant::has_components(ctx, schema_variant_id).await? {
                 should_hide = false;
             }
 
@@ -9,6 +9,7 @@ use thiserror::Error;
 use crate::{service::ApiError, AppState};
 
 pub mod get_integrations;
+pub mod list_integrations;
 pub mod update_integration;
 
 #[remain::sorted]
@@ -26,7 +27,22 @@ pub type IntegrationsResult<T> = Result<T, IntegrationsError>;
 
 impl IntoResponse for IntegrationsError {
     fn into_response(self) -> Response {
-        let (status_code, error_message) = (StatusCode::INTERNAL_SERVER_ERROR, self.to_string());
+        let status_code = match &self {
+            IntegrationsError::IntegrationNotFound(_)
+            | IntegrationsError::WorkspaceIntegrations(
+                dal::workspace_integrations::WorkspaceIntegrationsError::NotFound(_),
+            ) => StatusCode::NOT_FOUND,
+            IntegrationsError::WorkspaceIntegrations(
+                dal::workspace_integrations::WorkspaceIntegrationsError::InvalidWebhookUrl(_),
+            ) => StatusCode::BAD_REQUEST,
+            IntegrationsError::WorkspaceIntegrations(
+                dal::workspace_integrations::WorkspaceIntegrationsError::StaleVersion(..),
+            ) => StatusCode::CONFLICT,
+            IntegrationsError::Transactions(_) | IntegrationsError::WorkspaceIntegrations(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+        let error_message = self.to_string();
 
         ApiError::new(status_code, error_message).into_response()
     }
@@ -39,4 +55,5 @@ pub fn v2_routes() -> Router<AppState> {
             post(update_integration::update_integration),
         )
         .route("/", get(get_integrations::get_integration))
+        .route("/list", get(list_integrations::list_integrations))
 }
@@ -0,0 +1,92 @@
+use crate::extract::{HandlerContext, PosthogClient};
+use crate::service::v2::AccessBuilder;
+use axum::extract::{Host, OriginalUri};
+use axum::Json;
+use dal::workspace_integrations::{WorkspaceIntegration, WorkspaceIntegrationId};
+use dal::DalContext;
+use serde::{Deserialize, Serialize};
+
+use super::IntegrationsResult;
+
+/// How many trailing characters of a webhook url's path are left unmasked.
+const VISIBLE_SUFFIX_LEN: usize = 4;
+
+/// A [`WorkspaceIntegration`] with its webhook url masked, safe to show to any workspace admin.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationSummary {
+    pub pk: WorkspaceIntegrationId,
+    pub slack_webhook_url: Option<String>,
+    pub version: i64,
+}
+
+impl From<WorkspaceIntegration> for IntegrationSummary {
+    fn from(value: WorkspaceIntegration) -> Self {
+        Self {
+            pk: *value.pk(),
+            version: value.version(),
+            slack_webhook_url: value
+                .slack_webhook_url()
+                .filter(|url| !url.is_empty())
+                .map(|url| mask_webhook_url(&url)),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListIntegrationsResponse {
+    pub integrations: Vec<IntegrationSummary>,
+}
+
+pub async fn list_integrations(
+    HandlerContext(builder): HandlerContext,
+    AccessBuilder(access_builder): AccessBuilder,
+    PosthogClient(_posthog_client): PosthogClient,
+    OriginalUri(_original_uri): OriginalUri,
+    Host(_host_name): Host,
+) -> IntegrationsResult<Json<ListIntegrationsResponse>> {
+    let ctx = builder.build_head(access_builder).await?;
+
+    let integrations = list_integration_summaries(&ctx).await?;
+
+    Ok(Json(ListIntegrationsResponse { integrations }))
+}
+
+/// Lists the [`IntegrationSummaries`](IntegrationSummary) for the current workspace.
+pub async fn list_integration_summaries(
+    ctx: &DalContext,
+) -> IntegrationsResult<Vec<IntegrationSummary>> {
+    Ok(WorkspaceIntegration::list_for_workspace_pk(ctx)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Masks a webhook url down to its scheme, host and the last few characters of its path, since
+/// the path is what carries the webhook's secret.
+pub fn mask_webhook_url(webhook_url: &str) -> String {
+    let Some((origin, secret)) = url::Url::parse(webhook_url).ok().and_then(|url| {
+        let host = url.host_str()?.to_owned();
+        let secret = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_owned(),
+        };
+        Some((format!("{}://{host}", url.scheme()), secret))
+    }) else {
+        return "****".to_owned();
+    };
+
+    // Short paths are masked entirely, otherwise the suffix would give most of it away.
+    let secret_chars: Vec<char> = secret.chars().collect();
+    let suffix: String = if secret_chars.len() > VISIBLE_SUFFIX_LEN * 2 {
+        secret_chars[secret_chars.len() - VISIBLE_SUFFIX_LEN..]
+            .iter()
+            .collect()
+    } else {
+        String::new()
+    };
+
+    format!("{origin}/****{suffix}")
+}
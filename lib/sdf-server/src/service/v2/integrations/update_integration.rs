@@ -4,7 +4,7 @@ use crate::service::v2::AccessBuilder;
 use axum::extract::{Host, OriginalUri, Path};
 use axum::Json;
 use dal::workspace_integrations::{WorkspaceIntegration, WorkspaceIntegrationId};
-use dal::WorkspacePk;
+use dal::{DalContext, WorkspacePk};
 use serde::{Deserialize, Serialize};
 
 use super::{IntegrationsError, IntegrationsResult};
@@ -12,7 +12,16 @@ use super::{IntegrationsError, IntegrationsResult};
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateIntegrationRequest {
-    slack_webhook_url: Option<String>,
+    /// Absent leaves the webhook url unchanged, `null` clears it and a string replaces it.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    slack_webhook_url: Option<Option<String>>,
+    /// The version of the integration the update was based on. When provided, the update is
+    /// rejected if the integration has changed since.
+    expected_version: Option<i64>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -38,10 +47,51 @@ pub async fn update_integration(
             workspace_integration_id,
         ))?;
 
-    if let Some(webhook_url) = request.slack_webhook_url {
-        integration.update_webhook_url(&ctx, webhook_url).await?;
-    }
+    apply_integration_update(&ctx, &mut integration, request).await?;
     ctx.commit().await?;
 
     Ok(Json(UpdateIntegrationResponse { integration }))
 }
+
+/// Applies the fields set on the [`UpdateIntegrationRequest`] to the integration, leaving absent
+/// fields unchanged.
+pub async fn apply_integration_update(
+    ctx: &DalContext,
+    integration: &mut WorkspaceIntegration,
+    request: UpdateIntegrationRequest,
+) -> IntegrationsResult<()> {
+    match request.slack_webhook_url {
+        None => {}
+        Some(None) => clear_webhook_url(ctx, integration, request.expected_version).await?,
+        // An empty url also clears the webhook, for clients that predate sending `null`.
+        Some(Some(webhook_url)) if webhook_url.is_empty() => {
+            clear_webhook_url(ctx, integration, request.expected_version).await?
+        }
+        Some(Some(webhook_url)) => match request.expected_version {
+            Some(expected_version) => {
+                integration
+                    .update_webhook_url_if_unchanged(ctx, webhook_url, expected_version)
+                    .await?
+            }
+            None => integration.update_webhook_url(ctx, webhook_url).await?,
+        },
+    }
+
+    Ok(())
+}
+
+async fn clear_webhook_url(
+    ctx: &DalContext,
+    integration: &mut WorkspaceIntegration,
+    expected_version: Option<i64>,
+) -> IntegrationsResult<()> {
+    match expected_version {
+        Some(expected_version) => {
+            integration
+                .clear_webhook_url_if_unchanged(ctx, expected_version)
+                .await?
+        }
+        None => integration.clear_webhook_url(ctx).await?,
+    }
+    Ok(())
+}
@@ -1,4 +1,8 @@
-use crate::{service::ApiError, AppState};
+use crate::{
+    middleware::RequestCancelled,
+    service::{pagination::PaginationError, ApiError},
+    AppState,
+};
 use axum::extract::multipart::MultipartError;
 use axum::{
     http::StatusCode,
@@ -9,14 +13,14 @@ use axum::{
 use dal::pkg::PkgError;
 use dal::{ChangeSetError, FuncError, UserError, WsEventError};
 use si_frontend_types as frontend_types;
-use si_pkg::SiPkgError;
+use si_pkg::{SiPkgError, SpecError};
 use telemetry::prelude::*;
 use thiserror::Error;
 
 mod builtins;
 mod contribute;
 mod install_from_file;
-mod list;
+pub mod list;
 mod module_by_hash;
 mod module_by_id;
 mod sync;
@@ -44,16 +48,22 @@ pub enum ModulesAPIError {
     ModuleIndexNotConfigured,
     #[error("multipart error: {0}")]
     Multipart(#[from] MultipartError),
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
     #[error("pkg error: {0:?}")]
     Pkg(#[from] PkgError),
     #[error("pkg file error: {0}")]
     PkgFileError(&'static str),
+    #[error(transparent)]
+    RequestCancelled(#[from] RequestCancelled),
     #[error("schema error: {0}")]
     SchemaVariant(#[from] dal::SchemaVariantError),
     #[error("changeset error: {0:?}")]
     Serde(#[from] serde_json::Error),
     #[error("si pkg error: {0}")]
     SiPkg(#[from] SiPkgError),
+    #[error("spec error: {0}")]
+    Spec(#[from] SpecError),
     #[error("transactions error: {0}")]
     Transactions(#[from] dal::TransactionsError),
     #[error("url parse error: {0}")]
@@ -76,7 +86,9 @@ impl IntoResponse for ModulesAPIError {
             }
             Self::Module(dal::module::ModuleError::EmptyMetadata(_, _)) => StatusCode::BAD_REQUEST,
             Self::ContributionFailure(_) => StatusCode::BAD_REQUEST,
+            Self::Spec(SpecError::InvalidVersion(_, _)) => StatusCode::BAD_REQUEST,
             Self::ModuleHashNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Pagination(PaginationError::InvalidCursor(_)) => StatusCode::BAD_REQUEST,
             _ => ApiError::DEFAULT_ERROR_STATUS_CODE,
         };
 
@@ -7,6 +7,7 @@ use dal::{module::Module, ChangeSetId, WorkspacePk};
 use module_index_client::ModuleIndexClient;
 use si_events::audit_log::AuditLogKind;
 use si_frontend_types as frontend_types;
+use si_pkg::{PkgVersionMode, SiPkg};
 
 use super::ModulesAPIError;
 use crate::{
@@ -54,6 +55,12 @@ pub async fn contribute(
     )
     .await?;
 
+    // Contributed modules must carry a version that can be compared with other versions.
+    SiPkg::load_from_bytes(&payload)?
+        .to_spec()
+        .await?
+        .parse_version(PkgVersionMode::Lenient)?;
+
     let response = index_client
         .upload_module(
             name.as_str(),
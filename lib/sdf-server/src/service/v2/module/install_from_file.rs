@@ -1,4 +1,5 @@
 use crate::extract::change_set::ChangeSetDalContext;
+use crate::middleware::RequestCancellation;
 use crate::service::force_change_set_response::ForceChangeSetResponse;
 use crate::service::v2::module::ModulesAPIError;
 use axum::extract::Multipart;
@@ -10,6 +11,7 @@ use telemetry::prelude::*;
 
 pub async fn install_module_from_file(
     ChangeSetDalContext(ref mut ctx): ChangeSetDalContext,
+    cancellation: RequestCancellation,
     mut multipart: Multipart,
 ) -> Result<ForceChangeSetResponse<Vec<FrontendVariant>>, ModulesAPIError> {
     let force_change_set_id = ChangeSet::force_new(ctx).await?;
@@ -42,16 +44,18 @@ pub async fn install_module_from_file(
         ));
     }
 
-    let (_, variant_ids, _) = import_pkg_from_pkg(
-        ctx,
-        &pkg,
-        Some(ImportOptions {
-            schema_id: None,
-            past_module_hashes: None,
-            ..Default::default()
-        }),
-    )
-    .await?;
+    cancellation.check()?;
+    let (_, variant_ids, _) = cancellation
+        .run(import_pkg_from_pkg(
+            ctx,
+            &pkg,
+            Some(ImportOptions {
+                schema_id: None,
+                past_module_hashes: None,
+                ..Default::default()
+            }),
+        ))
+        .await??;
 
     if let Some(schema_variant_id) = variant_ids.first() {
         let variant = SchemaVariant::get_by_id_or_error(ctx, *schema_variant_id).await?;
@@ -74,6 +78,8 @@ pub async fn install_module_from_file(
         return Err(ModulesAPIError::PkgFileError("Pkg has no variants"));
     };
 
+    // Nothing has been written yet, so a client that went away leaves no trace.
+    cancellation.check()?;
     ctx.commit().await?;
 
     Ok(ForceChangeSetResponse::new(force_change_set_id, variants))
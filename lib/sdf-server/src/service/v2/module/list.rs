@@ -2,13 +2,23 @@ use axum::{
     extract::{Host, OriginalUri, Path},
     Json,
 };
-use dal::{module::Module, ChangeSetId, WorkspacePk};
+use dal::{module::Module, ChangeSetId, DalContext, WorkspacePk};
+use serde::{Deserialize, Serialize};
 use si_frontend_types::ModuleSummary;
 
 use super::ModuleAPIResult;
 use crate::extract::{request::RawAccessToken, HandlerContext, PosthogClient};
+use crate::service::pagination::{Page, PageRequest};
 use crate::service::v2::AccessBuilder;
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ListModulesResponse {
+    All(Vec<ModuleSummary>),
+    Page(Page<ModuleSummary>),
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn list(
     HandlerContext(builder): HandlerContext,
     AccessBuilder(access_builder): AccessBuilder,
@@ -17,20 +27,36 @@ pub async fn list(
     OriginalUri(_original_uri): OriginalUri,
     Host(_host_name): Host,
     Path((_workspace_pk, change_set_id)): Path<(WorkspacePk, ChangeSetId)>,
-) -> ModuleAPIResult<Json<Vec<ModuleSummary>>> {
+    page_request: PageRequest,
+) -> ModuleAPIResult<Json<ListModulesResponse>> {
     let ctx = builder
         .build(access_builder.build(change_set_id.into()))
         .await?;
 
-    let installed_modules = Module::list(&ctx).await?;
+    Ok(Json(list_modules(&ctx, page_request).await?))
+}
+
+/// Lists the installed modules ordered by name. Only the requested page is returned when the
+/// request provided a limit or a cursor.
+pub async fn list_modules(
+    ctx: &DalContext,
+    page_request: PageRequest,
+) -> ModuleAPIResult<ListModulesResponse> {
+    let installed_modules = Module::list(ctx).await?;
 
-    let modules: Vec<ModuleSummary> = installed_modules
+    let mut modules: Vec<ModuleSummary> = installed_modules
         .iter()
         .map(|module| ModuleSummary {
             name: module.name().to_owned(),
             hash: module.root_hash().to_string(),
         })
         .collect();
+    // Pages are only meaningful across requests when the modules come back in a stable order.
+    modules.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.hash.cmp(&b.hash)));
 
-    Ok(Json(modules))
+    Ok(if page_request.is_requested() {
+        ListModulesResponse::Page(Page::paginate(modules, &page_request)?)
+    } else {
+        ListModulesResponse::All(modules)
+    })
 }
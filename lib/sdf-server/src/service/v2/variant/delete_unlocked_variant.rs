@@ -29,8 +29,7 @@ pub async fn delete_unlocked_variant(
     let schema_variant = SchemaVariant::get_by_id_or_error(&ctx, schema_variant_id).await?;
     let schema = schema_variant.schema(&ctx).await?;
 
-    let connected_components = SchemaVariant::list_component_ids(&ctx, schema_variant_id).await?;
-    if !connected_components.is_empty() {
+    if SchemaVariant::has_components(&ctx, schema_variant_id).await? {
         return Err(SchemaVariantsAPIError::CannotDeleteVariantWithComponents);
     }
 
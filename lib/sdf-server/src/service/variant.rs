@@ -84,6 +84,9 @@ impl IntoResponse for SchemaVariantError {
             SchemaVariantError::VariantAuthoring(VariantAuthoringError::DuplicatedSchemaName(
                 _,
             )) => (StatusCode::CONFLICT, self.to_string()),
+            SchemaVariantError::VariantAuthoring(VariantAuthoringError::InvalidHexColor(_)) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
             SchemaVariantError::VariantAuthoring(
                 VariantAuthoringError::AssetTypeNotReturnedForAssetFunc(_, _),
             ) => (
@@ -93,6 +96,27 @@ impl IntoResponse for SchemaVariantError {
             SchemaVariantError::VariantAuthoring(VariantAuthoringError::FuncExecutionFailure(
                 message,
             )) => (StatusCode::UNPROCESSABLE_ENTITY, message),
+            SchemaVariantError::VariantAuthoring(
+                VariantAuthoringError::FuncExecutionFailureDetailed {
+                    message,
+                    line,
+                    column,
+                    ..
+                },
+            ) => match (line, column) {
+                (Some(line), Some(column)) => (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!("{message} (line {line}, column {column})"),
+                ),
+                (Some(line), None) => (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!("{message} (line {line})"),
+                ),
+                _ => (StatusCode::UNPROCESSABLE_ENTITY, message),
+            },
+            SchemaVariantError::VariantAuthoring(VariantAuthoringError::FuncExecutionTimeout(
+                ..,
+            )) => (StatusCode::GATEWAY_TIMEOUT, self.to_string()),
             SchemaVariantError::SchemaNameAlreadyTaken(name) => (
                 StatusCode::CONFLICT,
                 format!("Schema name {name} already in use"),
@@ -0,0 +1,38 @@
+use dal::action::{Action, ActionState};
+use dal::DalContext;
+use dal_test::helpers::create_component_for_default_schema_name_in_default_view;
+use dal_test::prelude::ChangeSetTestHelpers;
+use dal_test::sdf_test;
+use dal_test::Result;
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::action::{resume::resume_action, ActionError};
+
+#[sdf_test]
+async fn resume_held_action(ctx: &mut DalContext) -> Result<()> {
+    let component =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "resumed").await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+    let action_id = *Action::find_for_component_id(ctx, component.id())
+        .await?
+        .first()
+        .expect("no action queued for component");
+
+    // Only held actions can be resumed.
+    assert!(matches!(
+        resume_action(ctx, action_id).await,
+        Err(ActionError::InvalidOnHoldTransition(id)) if id == action_id
+    ));
+
+    Action::set_state(ctx, action_id, ActionState::OnHold).await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    resume_action(ctx, action_id).await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    assert_eq!(
+        ActionState::Queued,
+        Action::get_by_id(ctx, action_id).await?.state()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use axum::{http::StatusCode, response::IntoResponse};
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::action::status::ActionStatusResponse;
+use veritech_client::ResourceStatus;
+
+#[test]
+fn ok_is_ok() {
+    let response = ActionStatusResponse::new(ResourceStatus::Ok).into_response();
+    assert_eq!(StatusCode::OK, response.status());
+}
+
+#[test]
+fn warning_is_ok_with_a_warning() {
+    let body = ActionStatusResponse::new(ResourceStatus::Warning);
+    assert!(body.warning.is_some());
+    let response = body.into_response();
+    assert_eq!(StatusCode::OK, response.status());
+}
+
+#[test]
+fn error_is_bad_gateway() {
+    let response = ActionStatusResponse::new(ResourceStatus::Error).into_response();
+    assert_eq!(StatusCode::BAD_GATEWAY, response.status());
+}
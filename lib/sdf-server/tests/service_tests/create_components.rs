@@ -0,0 +1,80 @@
+use dal::diagram::view::View;
+use dal::{Component, DalContext, SchemaVariant, SchemaVariantId};
+use dal_test::prelude::ChangeSetTestHelpers;
+use dal_test::sdf_test;
+use dal_test::Result;
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::diagram::create_components::{
+    create_components_in_view, CreateComponentsItem, CreateComponentsParent,
+};
+
+fn item(
+    schema_variant_id: SchemaVariantId,
+    parent: Option<CreateComponentsParent>,
+) -> CreateComponentsItem {
+    CreateComponentsItem {
+        schema_variant_id,
+        parent,
+        x: "0".to_string(),
+        y: "0".to_string(),
+        height: None,
+        width: None,
+    }
+}
+
+#[sdf_test]
+async fn create_frame_with_children_in_one_batch(ctx: &mut DalContext) -> Result<()> {
+    let frame_variant_id =
+        SchemaVariant::default_id_for_schema_name(ctx, "medium even lego").await?;
+    let child_variant_id = SchemaVariant::default_id_for_schema_name(ctx, "small odd lego").await?;
+    let view_id = View::get_id_for_default(ctx).await?;
+
+    let components = create_components_in_view(
+        ctx,
+        view_id,
+        &[
+            item(frame_variant_id, None),
+            item(child_variant_id, Some(CreateComponentsParent::Index(0))),
+            item(child_variant_id, Some(CreateComponentsParent::Index(0))),
+        ],
+    )
+    .await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    assert_eq!(3, components.len());
+    let frame_id = components[0].id();
+    assert_eq!(None, Component::get_parent_by_id(ctx, frame_id).await?);
+    for child in &components[1..] {
+        assert_eq!(
+            Some(frame_id),
+            Component::get_parent_by_id(ctx, child.id()).await?
+        );
+    }
+
+    let mut children = Component::get_children_for_id(ctx, frame_id).await?;
+    children.sort();
+    let mut expected_children = vec![components[1].id(), components[2].id()];
+    expected_children.sort();
+    assert_eq!(expected_children, children);
+
+    Ok(())
+}
+
+#[sdf_test]
+async fn create_components_rejects_forward_parent_index(ctx: &mut DalContext) -> Result<()> {
+    let child_variant_id = SchemaVariant::default_id_for_schema_name(ctx, "small odd lego").await?;
+    let view_id = View::get_id_for_default(ctx).await?;
+
+    let result = create_components_in_view(
+        ctx,
+        view_id,
+        &[item(
+            child_variant_id,
+            Some(CreateComponentsParent::Index(1)),
+        )],
+    )
+    .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
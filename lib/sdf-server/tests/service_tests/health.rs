@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use axum::{http::StatusCode, response::IntoResponse};
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::health::{DependencyHealth, HealthResponse};
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+async fn up(name: &str, critical: bool) -> DependencyHealth {
+    DependencyHealth::check(name, critical, TIMEOUT, async { Ok::<(), String>(()) }).await
+}
+
+#[tokio::test]
+async fn healthy_when_all_dependencies_are_up() {
+    let response = HealthResponse::from(vec![up("postgres", true).await, up("nats", true).await]);
+
+    assert!(response.ok);
+    assert_eq!(StatusCode::OK, response.into_response().status());
+}
+
+#[tokio::test]
+async fn unavailable_when_a_critical_dependency_is_down() {
+    let down = DependencyHealth::check("nats", true, TIMEOUT, async {
+        Err::<(), _>("connection refused")
+    })
+    .await;
+    assert!(!down.healthy);
+    assert_eq!(Some("connection refused".to_string()), down.error);
+
+    let response = HealthResponse::from(vec![up("postgres", true).await, down]);
+    assert!(!response.ok);
+    assert_eq!(
+        StatusCode::SERVICE_UNAVAILABLE,
+        response.into_response().status()
+    );
+}
+
+#[tokio::test]
+async fn unresponsive_dependency_times_out() {
+    let hung = DependencyHealth::check("postgres", true, TIMEOUT, async {
+        std::future::pending::<Result<(), String>>().await
+    })
+    .await;
+    assert!(!hung.healthy);
+
+    assert!(!HealthResponse::from(vec![hung]).ok);
+}
+
+#[tokio::test]
+async fn non_critical_dependency_does_not_fail_health() {
+    let down = DependencyHealth::check("optional", false, TIMEOUT, async {
+        Err::<(), _>("unreachable")
+    })
+    .await;
+
+    let response = HealthResponse::from(vec![up("postgres", true).await, down]);
+    assert!(response.ok);
+    assert_eq!(StatusCode::OK, response.into_response().status());
+}
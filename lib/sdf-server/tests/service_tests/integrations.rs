@@ -0,0 +1,211 @@
+use axum::{http::StatusCode, response::IntoResponse};
+use dal::workspace_integrations::{
+    WorkspaceIntegration, WorkspaceIntegrationId, WorkspaceIntegrationsError,
+};
+use dal::DalContext;
+use dal_test::sdf_test;
+use dal_test::Result;
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::v2::integrations::{
+    list_integrations::{list_integration_summaries, mask_webhook_url},
+    update_integration::{apply_integration_update, UpdateIntegrationRequest},
+    IntegrationsError,
+};
+
+#[test]
+fn integration_not_found_is_not_found() {
+    let response =
+        IntegrationsError::IntegrationNotFound(WorkspaceIntegrationId::new()).into_response();
+    assert_eq!(StatusCode::NOT_FOUND, response.status());
+}
+
+#[test]
+fn invalid_webhook_url_is_bad_request() {
+    let response = IntegrationsError::WorkspaceIntegrations(
+        WorkspaceIntegrationsError::InvalidWebhookUrl("not a url".to_string()),
+    )
+    .into_response();
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+}
+
+#[test]
+fn transactions_error_is_internal_server_error() {
+    let response =
+        IntegrationsError::Transactions(dal::TransactionsError::BadWorkspaceAndChangeSet)
+            .into_response();
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+}
+
+#[test]
+fn mask_webhook_url_hides_the_path() {
+    assert_eq!(
+        "https://hooks.slack.com/****wxyz",
+        mask_webhook_url("https://hooks.slack.com/services/T0000/B0000/abcdwxyz")
+    );
+    assert_eq!(
+        "https://hooks.slack.com/****",
+        mask_webhook_url("https://hooks.slack.com/abc")
+    );
+    assert_eq!("****", mask_webhook_url("not a url"));
+}
+
+#[sdf_test]
+async fn list_integrations_masks_webhook_urls(ctx: &mut DalContext) -> Result<()> {
+    let mut integration = WorkspaceIntegration::get_integrations_for_workspace_pk(ctx)
+        .await?
+        .expect("workspaces are created with an integration");
+    integration
+        .update_webhook_url(
+            ctx,
+            "https://hooks.slack.com/services/T0000/B0000/abcdwxyz".to_string(),
+        )
+        .await?;
+
+    let summaries = list_integration_summaries(ctx).await?;
+    assert_eq!(1, summaries.len());
+    assert_eq!(*integration.pk(), summaries[0].pk);
+    assert_eq!(
+        Some("https://hooks.slack.com/****wxyz".to_string()),
+        summaries[0].slack_webhook_url
+    );
+
+    Ok(())
+}
+
+#[sdf_test]
+async fn stale_integration_update_is_rejected(ctx: &mut DalContext) -> Result<()> {
+    let integration = WorkspaceIntegration::get_integrations_for_workspace_pk(ctx)
+        .await?
+        .expect("workspaces are created with an integration");
+    let read_version = integration.version();
+
+    // Two admins read the same integration, and the first one saves.
+    let mut first_admin_copy = integration.clone();
+    let mut second_admin_copy = integration;
+    first_admin_copy
+        .update_webhook_url_if_unchanged(
+            ctx,
+            "https://hooks.slack.com/services/first".to_string(),
+            read_version,
+        )
+        .await?;
+    assert_eq!(read_version + 1, first_admin_copy.version());
+
+    // The second admin's write is based on what they read, which is now stale.
+    let error = second_admin_copy
+        .update_webhook_url_if_unchanged(
+            ctx,
+            "https://hooks.slack.com/services/second".to_string(),
+            read_version,
+        )
+        .await
+        .expect_err("stale update should be rejected");
+    assert!(matches!(
+        error,
+        WorkspaceIntegrationsError::StaleVersion(_, expected, found)
+            if expected == read_version && found == read_version + 1
+    ));
+    assert_eq!(
+        StatusCode::CONFLICT,
+        IntegrationsError::WorkspaceIntegrations(error)
+            .into_response()
+            .status()
+    );
+
+    // Once they re-read, their update goes through.
+    second_admin_copy
+        .update_webhook_url_if_unchanged(
+            ctx,
+            "https://hooks.slack.com/services/second".to_string(),
+            first_admin_copy.version(),
+        )
+        .await?;
+    let stored = WorkspaceIntegration::get_by_pk(ctx, *second_admin_copy.pk())
+        .await?
+        .expect("integration should exist");
+    assert_eq!(
+        Some("https://hooks.slack.com/services/second".to_string()),
+        stored.slack_webhook_url()
+    );
+    assert_eq!(read_version + 2, stored.version());
+
+    Ok(())
+}
+
+#[sdf_test]
+async fn update_of_missing_integration_is_not_found(ctx: &mut DalContext) -> Result<()> {
+    let mut integration = WorkspaceIntegration::get_integrations_for_workspace_pk(ctx)
+        .await?
+        .expect("workspaces are created with an integration");
+    let read_version = integration.version();
+    ctx.txns()
+        .await?
+        .pg()
+        .execute(
+            "DELETE FROM workspace_integrations WHERE pk = $1",
+            &[integration.pk()],
+        )
+        .await?;
+
+    let error = integration
+        .update_webhook_url_if_unchanged(
+            ctx,
+            "https://hooks.slack.com/services/T0000/B0000/abcdwxyz".to_string(),
+            read_version,
+        )
+        .await
+        .expect_err("update of a missing integration should fail");
+    assert!(matches!(
+        error,
+        WorkspaceIntegrationsError::NotFound(pk) if pk == *integration.pk()
+    ));
+    assert_eq!(
+        StatusCode::NOT_FOUND,
+        IntegrationsError::WorkspaceIntegrations(error)
+            .into_response()
+            .status()
+    );
+
+    Ok(())
+}
+
+#[sdf_test]
+async fn update_integration_distinguishes_absent_from_null(ctx: &mut DalContext) -> Result<()> {
+    let mut integration = WorkspaceIntegration::get_integrations_for_workspace_pk(ctx)
+        .await?
+        .expect("workspaces are created with an integration");
+    let webhook_url = "https://hooks.slack.com/services/T0000/B0000/abcdwxyz";
+
+    // Set
+    let request: UpdateIntegrationRequest =
+        serde_json::from_value(serde_json::json!({ "slackWebhookUrl": webhook_url }))?;
+    apply_integration_update(ctx, &mut integration, request).await?;
+    assert_eq!(
+        Some(webhook_url.to_string()),
+        integration.slack_webhook_url()
+    );
+
+    // Leave unchanged
+    let version = integration.version();
+    let request: UpdateIntegrationRequest = serde_json::from_value(serde_json::json!({}))?;
+    apply_integration_update(ctx, &mut integration, request).await?;
+    assert_eq!(
+        Some(webhook_url.to_string()),
+        integration.slack_webhook_url()
+    );
+    assert_eq!(version, integration.version());
+
+    // Clear
+    let request: UpdateIntegrationRequest =
+        serde_json::from_value(serde_json::json!({ "slackWebhookUrl": null }))?;
+    apply_integration_update(ctx, &mut integration, request).await?;
+    assert_eq!(None, integration.slack_webhook_url());
+
+    let stored = WorkspaceIntegration::get_by_pk(ctx, *integration.pk())
+        .await?
+        .expect("integration should exist");
+    assert_eq!(None, stored.slack_webhook_url());
+    assert_eq!(version + 1, stored.version());
+
+    Ok(())
+}
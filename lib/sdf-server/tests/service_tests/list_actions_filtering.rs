@@ -0,0 +1,41 @@
+use dal::action::{Action, ActionState};
+use dal::DalContext;
+use dal_test::helpers::create_component_for_default_schema_name_in_default_view;
+use dal_test::prelude::ChangeSetTestHelpers;
+use dal_test::sdf_test;
+use dal_test::Result;
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::action::list_actions::list_action_ids;
+
+#[sdf_test]
+async fn filter_by_state_and_component(ctx: &mut DalContext) -> Result<()> {
+    let held =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "held").await?;
+    let queued =
+        create_component_for_default_schema_name_in_default_view(ctx, "swifty", "queued").await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    let held_action_ids = Action::find_for_component_id(ctx, held.id()).await?;
+    let queued_action_ids = Action::find_for_component_id(ctx, queued.id()).await?;
+    assert_eq!(1, held_action_ids.len());
+    assert_eq!(1, queued_action_ids.len());
+    Action::set_state(ctx, held_action_ids[0], ActionState::OnHold).await?;
+    ChangeSetTestHelpers::commit_and_update_snapshot_to_visibility(ctx).await?;
+
+    assert_eq!(
+        held_action_ids,
+        list_action_ids(ctx, None, &[ActionState::OnHold]).await?
+    );
+    assert_eq!(
+        queued_action_ids,
+        list_action_ids(ctx, Some(queued.id()), &[]).await?
+    );
+    assert!(
+        list_action_ids(ctx, Some(queued.id()), &[ActionState::OnHold])
+            .await?
+            .is_empty()
+    );
+    assert_eq!(2, list_action_ids(ctx, None, &[]).await?.len());
+
+    Ok(())
+}
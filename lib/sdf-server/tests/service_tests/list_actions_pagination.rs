@@ -0,0 +1,96 @@
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::{Request, Uri},
+};
+use dal::{action::ActionId, ChangeSetId};
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::{
+    action::list_actions::{paginate_action_ids, LoadQueuedRequest},
+    pagination::PageRequest,
+};
+
+#[test]
+fn paging_yields_every_action_once_in_order() {
+    let action_ids: Vec<ActionId> = (0..7).map(|_| ActionId::new()).collect();
+
+    let mut paged = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = paginate_action_ids(
+            action_ids.clone(),
+            &PageRequest::new(Some(3), cursor.as_deref()),
+        )
+        .expect("could not paginate action ids");
+        assert!(page.items.len() <= 3);
+        paged.extend(page.items);
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(action_ids, paged);
+}
+
+#[test]
+fn paging_resumes_after_the_cursor_action_succeeds() {
+    let mut action_ids: Vec<ActionId> = (0..6).map(|_| ActionId::new()).collect();
+
+    let page = paginate_action_ids(action_ids.clone(), &PageRequest::new(Some(2), None))
+        .expect("could not paginate action ids");
+    assert_eq!(&action_ids[..2], page.items.as_slice());
+    let cursor = page
+        .next_cursor
+        .expect("first page should have a next cursor");
+
+    // The last action of the first page succeeds and leaves the list.
+    action_ids.remove(1);
+    let page = paginate_action_ids(
+        action_ids.clone(),
+        &PageRequest::new(Some(2), Some(&cursor)),
+    )
+    .expect("could not paginate action ids");
+    assert_eq!(&action_ids[1..3], page.items.as_slice());
+
+    // The action that followed it succeeds as well.
+    action_ids.remove(1);
+    let page = paginate_action_ids(
+        action_ids.clone(),
+        &PageRequest::new(Some(2), Some(&cursor)),
+    )
+    .expect("could not paginate action ids");
+    assert_eq!(&action_ids[..2], page.items.as_slice());
+}
+
+#[test]
+fn paging_with_malformed_cursor_fails() {
+    let action_ids: Vec<ActionId> = (0..2).map(|_| ActionId::new()).collect();
+
+    assert!(
+        paginate_action_ids(action_ids, &PageRequest::new(Some(1), Some("not a cursor"))).is_err()
+    );
+}
+
+#[tokio::test]
+async fn paging_params_parse_alongside_visibility() {
+    let cursor = "opaque-cursor";
+    let uri: Uri = format!(
+        "/list?visibility_change_set_pk={}&limit=5&cursor={cursor}",
+        ChangeSetId::new()
+    )
+    .parse()
+    .expect("could not parse uri");
+
+    Query::<LoadQueuedRequest>::try_from_uri(&uri).expect("could not parse query");
+
+    let (mut parts, _) = Request::builder()
+        .uri(uri)
+        .body(())
+        .expect("could not build request")
+        .into_parts();
+    let page_request = PageRequest::from_request_parts(&mut parts, &())
+        .await
+        .expect("could not extract page request");
+    assert_eq!(5, page_request.limit());
+    assert_eq!(Some(cursor), page_request.cursor());
+}
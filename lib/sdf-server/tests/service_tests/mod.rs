@@ -1,3 +1,12 @@
+mod action_resume;
+mod action_status;
 mod change_set_apply;
 mod change_set_approval;
 mod crdt;
+mod create_components;
+mod health;
+mod integrations;
+mod list_actions_filtering;
+mod list_actions_pagination;
+mod pagination;
+mod request_cancellation;
@@ -0,0 +1,102 @@
+use axum::{extract::FromRequestParts, http::Request};
+use dal::DalContext;
+use dal_test::{sdf_test, Result};
+use pretty_assertions_sorted::assert_eq;
+use sdf_server::service::{
+    pagination::{Page, PageRequest, PaginationError, MAX_PAGE_LIMIT},
+    v2::module::list::{list_modules, ListModulesResponse},
+};
+
+async fn extract(uri: &str) -> Option<PageRequest> {
+    let (mut parts, _) = Request::builder()
+        .uri(uri)
+        .body(())
+        .expect("could not build request")
+        .into_parts();
+    PageRequest::from_request_parts(&mut parts, &()).await.ok()
+}
+
+#[tokio::test]
+async fn cursor_round_trips_across_pages() {
+    let items: Vec<u32> = (0..5).collect();
+
+    let first = Page::paginate(
+        items.clone(),
+        &extract("/list?limit=2").await.expect("no page request"),
+    )
+    .expect("could not paginate");
+    assert_eq!(vec![0, 1], first.items);
+    let cursor = first.next_cursor.expect("first page has no next cursor");
+
+    let second = Page::paginate(
+        items.clone(),
+        &extract(&format!("/list?limit=2&cursor={cursor}"))
+            .await
+            .expect("no page request"),
+    )
+    .expect("could not paginate");
+    assert_eq!(vec![2, 3], second.items);
+    let cursor = second.next_cursor.expect("second page has no next cursor");
+
+    let last = Page::paginate(
+        items,
+        &extract(&format!("/list?limit=2&cursor={cursor}"))
+            .await
+            .expect("no page request"),
+    )
+    .expect("could not paginate");
+    assert_eq!(vec![4], last.items);
+    assert_eq!(None, last.next_cursor);
+}
+
+#[tokio::test]
+async fn page_request_defaults_and_rejections() {
+    assert_eq!(
+        PageRequest::default(),
+        extract("/list").await.expect("no page request")
+    );
+    assert_eq!(
+        MAX_PAGE_LIMIT,
+        extract("/list?limit=100000")
+            .await
+            .expect("no page request")
+            .limit()
+    );
+    assert_eq!(None, extract("/list?limit=lots").await);
+
+    let bad_cursor = extract("/list?cursor=not-a-cursor")
+        .await
+        .expect("no page request");
+    assert!(matches!(
+        Page::paginate(vec![0], &bad_cursor),
+        Err(PaginationError::InvalidCursor(_))
+    ));
+}
+
+#[sdf_test]
+async fn module_list_pages_cover_every_module(ctx: &DalContext) -> Result<()> {
+    let all = match list_modules(ctx, PageRequest::default()).await? {
+        ListModulesResponse::All(modules) => modules,
+        ListModulesResponse::Page(_) => panic!("modules were paginated without a page request"),
+    };
+    assert!(all.len() > 2);
+
+    let mut paged = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page_request = PageRequest::new(Some(2), cursor.as_deref());
+        let page = match list_modules(ctx, page_request).await? {
+            ListModulesResponse::Page(page) => page,
+            ListModulesResponse::All(_) => panic!("modules were not paginated"),
+        };
+        assert!(page.items.len() <= 2);
+        paged.extend(page.items);
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(all, paged);
+    Ok(())
+}
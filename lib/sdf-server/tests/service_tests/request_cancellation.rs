@@ -0,0 +1,87 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use axum::{body::Body, http::Request, middleware, routing::get, Router};
+use sdf_server::middleware::{cancel_on_disconnect, RequestCancellation};
+use tokio::sync::{mpsc, Notify};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn disconnect_abandons_spawned_work_without_committing() {
+    let started = Arc::new(Notify::new());
+    let committed = Arc::new(AtomicBool::new(false));
+    let (cancelled_tx, mut cancelled_rx) = mpsc::unbounded_channel();
+
+    let handler = {
+        let started = started.clone();
+        let committed = committed.clone();
+        move |cancellation: RequestCancellation| async move {
+            // Like the handlers that use the slow runtime, hand the work to another task, which
+            // keeps running when the handler itself is dropped.
+            let work = tokio::spawn(async move {
+                let result = cancellation
+                    .run(async {
+                        started.notify_one();
+                        std::future::pending::<()>().await;
+                        committed.store(true, Ordering::SeqCst);
+                    })
+                    .await;
+                let _ = cancelled_tx.send(result.is_err());
+            });
+            let _ = work.await;
+        }
+    };
+    let router = Router::new()
+        .route("/work", get(handler))
+        .layer(middleware::from_fn(cancel_on_disconnect));
+
+    let request = router.oneshot(
+        Request::get("/work")
+            .body(Body::empty())
+            .expect("could not build request"),
+    );
+
+    // Drop the in-flight request once the work has started, which is what happens when the
+    // client disconnects.
+    tokio::select! {
+        _ = request => panic!("request should not finish on its own"),
+        _ = started.notified() => {}
+    }
+
+    let cancelled = tokio::time::timeout(Duration::from_secs(5), cancelled_rx.recv())
+        .await
+        .expect("timed out waiting for the work to be abandoned")
+        .expect("work finished without reporting");
+    assert!(cancelled);
+    assert!(!committed.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn finished_request_is_not_cancelled() {
+    let router = Router::new()
+        .route(
+            "/work",
+            get(|cancellation: RequestCancellation| async move {
+                cancellation.run(async { "done" }).await.is_ok().to_string()
+            }),
+        )
+        .layer(middleware::from_fn(cancel_on_disconnect));
+
+    let response = router
+        .oneshot(
+            Request::get("/work")
+                .body(Body::empty())
+                .expect("could not build request"),
+        )
+        .await
+        .expect("request failed");
+    let body = hyper::body::to_bytes(response.into_body())
+        .await
+        .expect("could not read body");
+    assert_eq!(b"true".as_slice(), body.as_ref());
+}
@@ -268,6 +268,13 @@ pub enum AuditLogKindV1 {
     RequestChangeSetApproval {
         from_status: ChangeSetStatus,
     },
+    ResumeAction {
+        prototype_id: ActionPrototypeId,
+        action_kind: ActionKind,
+        func_id: FuncId,
+        func_display_name: Option<String>,
+        func_name: String,
+    },
     RetryAction {
         prototype_id: ActionPrototypeId,
         action_kind: ActionKind,
@@ -693,6 +700,14 @@ pub enum AuditLogMetadataV1 {
     #[serde(rename_all = "camelCase")]
     RequestChangeSetApproval { from_status: ChangeSetStatus },
     #[serde(rename_all = "camelCase")]
+    ResumeAction {
+        prototype_id: ActionPrototypeId,
+        action_kind: ActionKind,
+        func_id: FuncId,
+        func_display_name: Option<String>,
+        func_name: String,
+    },
+    #[serde(rename_all = "camelCase")]
     RetryAction {
         prototype_id: ActionPrototypeId,
         action_kind: ActionKind,
@@ -915,6 +930,7 @@ impl AuditLogMetadataV1 {
             }
             MetadataDiscrim::ReopenChangeSet => ("Reopened", Some("Change Set")),
             MetadataDiscrim::RequestChangeSetApproval => ("Requested to Apply", Some("Change Set")),
+            MetadataDiscrim::ResumeAction => ("Resumed", Some("Action")),
             MetadataDiscrim::RetryAction => ("Retried", Some("Action")),
             MetadataDiscrim::RunAction => ("Ran", Some("Action")),
             MetadataDiscrim::TestFunction => ("Tested", Some("Function")),
@@ -1327,6 +1343,19 @@ impl From<Kind> for Metadata {
             Kind::RequestChangeSetApproval { from_status } => {
                 Self::RequestChangeSetApproval { from_status }
             }
+            Kind::ResumeAction {
+                prototype_id,
+                action_kind,
+                func_id,
+                func_display_name,
+                func_name,
+            } => Self::ResumeAction {
+                prototype_id,
+                action_kind,
+                func_id,
+                func_display_name,
+                func_name,
+            },
             Kind::RetryAction {
                 prototype_id,
                 action_kind,
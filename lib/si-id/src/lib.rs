@@ -73,6 +73,8 @@ id_with_pg_types!(ChangeSetApprovalId);
 id_with_pg_types!(ComponentId);
 id_with_pg_types!(FuncId);
 id_with_pg_types!(FuncRunId);
+id_with_pg_types!(RefreshScheduleId);
+id_with_pg_types!(RefreshSummaryId);
 id_with_pg_types!(UserPk);
 id_with_pg_types!(WorkspaceIntegrationId);
 
@@ -22,6 +22,15 @@ use super::serialize;
 pub const DBNAME: &str = "func_runs";
 pub const CACHE_NAME: &str = DBNAME;
 pub const PARTITION_KEY: &str = "workspace_id";
+const REQUESTS_TABLE_NAME: &str = "func_run_requests";
+const ACTION_FAILURES_TABLE_NAME: &str = "func_run_action_failures";
+
+/// Why the action run by a [`FuncRun`] failed. See [`FuncRunDb::record_action_failure`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuncRunActionFailure {
+    pub reason: String,
+    pub message: Option<String>,
+}
 
 #[derive(Debug, Clone)]
 pub struct FuncRunDb {
@@ -31,6 +40,7 @@ pub struct FuncRunDb {
     get_last_qualification_for_attribute_value_id: String,
     list_action_history: String,
     get_last_action_by_action_id: String,
+    list_latest_action_runs_for_component: String,
     list_management_history: String,
     get_last_management_by_func_and_component_id: String,
 }
@@ -61,6 +71,15 @@ impl FuncRunDb {
                   ORDER BY updated_at DESC
                   LIMIT 1",
             ),
+            list_latest_action_runs_for_component: format!(
+                "SELECT DISTINCT ON (action_id) value FROM {DBNAME}
+                   WHERE function_kind = 'Action'
+                     AND workspace_id = $1
+                     AND component_id = $2
+                     AND action_originating_change_set_id = $3
+                     AND action_id IS NOT NULL
+                   ORDER BY action_id, updated_at DESC",
+            ),
             list_management_history: format!(
                 r#"
                 SELECT value FROM {DBNAME}
@@ -127,6 +146,36 @@ impl FuncRunDb {
         Ok(maybe_func)
     }
 
+    /// Returns the latest [`FuncRun`] of each action run for a component, counting only the
+    /// actions that originated in the given change set.
+    pub async fn list_latest_action_runs_for_component(
+        &self,
+        workspace_pk: WorkspacePk,
+        component_id: ComponentId,
+        action_originating_change_set_id: ChangeSetId,
+    ) -> LayerDbResult<Vec<FuncRun>> {
+        let rows = self
+            .cache
+            .pg()
+            .query(
+                &self.list_latest_action_runs_for_component,
+                &[
+                    &workspace_pk,
+                    &component_id,
+                    &action_originating_change_set_id,
+                ],
+            )
+            .await?
+            .unwrap_or_default();
+
+        let mut func_runs = Vec::with_capacity(rows.len());
+        for row in rows.into_iter() {
+            let postcard_bytes: Vec<u8> = row.get("value");
+            func_runs.push(serialize::from_bytes(&postcard_bytes[..])?);
+        }
+        Ok(func_runs)
+    }
+
     pub async fn list_management_history(
         &self,
         workspace_pk: WorkspacePk,
@@ -243,6 +292,101 @@ impl FuncRunDb {
         Ok(())
     }
 
+    /// Records the id of the request that caused a [`FuncRun`]. Request ids are kept out of the
+    /// [`FuncRun`] itself because it is stored with postcard, which is not self-describing, so
+    /// adding a field would make every previously stored run unreadable.
+    pub async fn record_request_ulid(
+        &self,
+        func_run_id: FuncRunId,
+        workspace_pk: WorkspacePk,
+        request_ulid: ulid::Ulid,
+    ) -> LayerDbResult<()> {
+        self.cache
+            .pg()
+            .insert_raw(
+                &format!(
+                    "INSERT INTO {REQUESTS_TABLE_NAME} (func_run_id, workspace_id, request_ulid)
+                       VALUES ($1, $2, $3)
+                       ON CONFLICT (func_run_id) DO NOTHING"
+                ),
+                &[
+                    &func_run_id.to_string(),
+                    &workspace_pk.to_string(),
+                    &request_ulid.to_string(),
+                ],
+            )
+            .await
+    }
+
+    /// Returns the id of the request that caused a [`FuncRun`], if one was recorded.
+    pub async fn get_request_ulid(
+        &self,
+        func_run_id: FuncRunId,
+    ) -> LayerDbResult<Option<ulid::Ulid>> {
+        let maybe_row = self
+            .cache
+            .pg()
+            .query_opt(
+                &format!("SELECT request_ulid FROM {REQUESTS_TABLE_NAME} WHERE func_run_id = $1"),
+                &[&func_run_id.to_string()],
+            )
+            .await?;
+
+        Ok(maybe_row
+            .map(|row| ulid::Ulid::from_string(row.get("request_ulid")))
+            .transpose()?)
+    }
+
+    /// Records why the action run by a [`FuncRun`] failed, replacing any failure recorded for it
+    /// before. Like request ids, failures are kept out of the [`FuncRun`] itself so that its
+    /// postcard layout does not change.
+    pub async fn record_action_failure(
+        &self,
+        func_run_id: FuncRunId,
+        workspace_pk: WorkspacePk,
+        failure: &FuncRunActionFailure,
+    ) -> LayerDbResult<()> {
+        self.cache
+            .pg()
+            .insert_raw(
+                &format!(
+                    "INSERT INTO {ACTION_FAILURES_TABLE_NAME} (func_run_id, workspace_id, reason, message)
+                       VALUES ($1, $2, $3, $4)
+                       ON CONFLICT (func_run_id) DO UPDATE
+                       SET reason = EXCLUDED.reason, message = EXCLUDED.message"
+                ),
+                &[
+                    &func_run_id.to_string(),
+                    &workspace_pk.to_string(),
+                    &failure.reason,
+                    &failure.message,
+                ],
+            )
+            .await
+    }
+
+    /// Returns why the action run by a [`FuncRun`] failed, if a failure was recorded.
+    pub async fn get_action_failure(
+        &self,
+        func_run_id: FuncRunId,
+    ) -> LayerDbResult<Option<FuncRunActionFailure>> {
+        let maybe_row = self
+            .cache
+            .pg()
+            .query_opt(
+                &format!(
+                    "SELECT reason, message FROM {ACTION_FAILURES_TABLE_NAME} WHERE func_run_id = $1"
+                ),
+                &[&func_run_id.to_string()],
+            )
+            .await?;
+
+        Ok(maybe_row.map(|row| FuncRunActionFailure {
+            reason: row.get("reason"),
+            message: row.get("message"),
+        }))
+    }
+
     pub async fn set_values_and_set_state_to_success(
         &self,
         func_run_id: FuncRunId,
@@ -301,6 +445,22 @@ impl FuncRunDb {
         Ok(())
     }
 
+    pub async fn set_state_to_failure(
+        &self,
+        func_run_id: FuncRunId,
+        tenancy: Tenancy,
+        actor: Actor,
+    ) -> LayerDbResult<()> {
+        let func_run_old = self.try_read(func_run_id).await?;
+        let mut func_run_new = Arc::unwrap_or_clone(func_run_old);
+        func_run_new.set_state_to_failure();
+
+        self.write(Arc::new(func_run_new), None, tenancy, actor)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_state_to_killed(
         &self,
         func_run_id: FuncRunId,
@@ -100,6 +100,8 @@ pub enum LayerDbError {
     SerdeJson(#[from] serde_json::Error),
     #[error("tokio oneshot recv error: {0}")]
     TokioOneShotRecv(#[from] tokio::sync::oneshot::error::RecvError),
+    #[error("ulid decode error: {0}")]
+    UlidDecode(#[from] ulid::DecodeError),
     #[error("unexpected activity variant; expected={0}, actual={1}")]
     UnexpectedActivityVariant(String, String),
 }
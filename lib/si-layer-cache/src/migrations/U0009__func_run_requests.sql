@@ -0,0 +1,9 @@
+CREATE TABLE func_run_requests
+(
+    func_run_id  text                      NOT NULL PRIMARY KEY,
+    workspace_id text                      NOT NULL,
+    request_ulid text                      NOT NULL,
+    created_at   timestamp with time zone  NOT NULL DEFAULT CLOCK_TIMESTAMP()
+);
+
+CREATE INDEX IF NOT EXISTS func_run_requests_by_request_ulid ON func_run_requests (request_ulid, workspace_id);
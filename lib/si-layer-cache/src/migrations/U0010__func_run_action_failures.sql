@@ -0,0 +1,8 @@
+CREATE TABLE func_run_action_failures
+(
+    func_run_id  text                      NOT NULL PRIMARY KEY,
+    workspace_id text                      NOT NULL,
+    reason       text                      NOT NULL,
+    message      text,
+    created_at   timestamp with time zone  NOT NULL DEFAULT CLOCK_TIMESTAMP()
+);
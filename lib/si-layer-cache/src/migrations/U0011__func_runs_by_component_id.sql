@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS func_runs_by_component_id ON func_runs (component_id, workspace_id, action_originating_change_set_id, action_id, updated_at DESC);
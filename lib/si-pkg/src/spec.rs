@@ -1,4 +1,4 @@
-use std::time::SystemTime;
+use std::{cmp::Ordering, time::SystemTime};
 
 use chrono::{DateTime, Utc};
 use derive_builder::{Builder, UninitializedFieldError};
@@ -89,6 +89,12 @@ impl PkgSpec {
             .find(|func_spec| func_spec.name.as_str() == name)
     }
 
+    /// Parses the package version as a semver version, so that it can be compared with the
+    /// versions of other packages. See [`PkgVersionMode`] for what is accepted.
+    pub fn parse_version(&self, mode: PkgVersionMode) -> Result<semver::Version, SpecError> {
+        parse_version(&self.version, mode)
+    }
+
     /// used only to create diffable specs
     pub fn anonymize(mut self) -> PkgSpec {
         self.created_at = SystemTime::UNIX_EPOCH.into();
@@ -101,6 +107,94 @@ impl PkgSpec {
     }
 }
 
+/// How strictly [`PkgSpec::parse_version`] interprets a package version.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PkgVersionMode {
+    /// Only full semver versions, such as `1.2.3`, are accepted.
+    #[default]
+    Strict,
+    /// Also accepts the other version formats found in existing packages:
+    ///
+    /// - truncated versions, such as `1.2`, which are padded with zeros
+    /// - dates, such as `2023-05-24`, which become `2023.5.24`
+    /// - timestamps, such as `20240301221413027574` (`%Y%m%d%H%M%S%f`), which become
+    ///   `20240301.221413.27574` so that later timestamps have higher precedence
+    ///
+    /// A bare number that is not a timestamp, such as `0`, is still rejected.
+    Lenient,
+}
+
+/// The number of digits in the `%Y%m%d%H%M%S` prefix of a timestamp version.
+const TIMESTAMP_VERSION_SECONDS_LEN: usize = 14;
+
+fn parse_version(version: &str, mode: PkgVersionMode) -> Result<semver::Version, SpecError> {
+    let strict_err = match semver::Version::parse(version) {
+        Ok(version) => return Ok(version),
+        Err(err) => err,
+    };
+
+    if mode == PkgVersionMode::Lenient {
+        if let Some(version) = parse_legacy_version(version.trim()) {
+            return Ok(version);
+        }
+    }
+
+    Err(SpecError::InvalidVersion(version.to_owned(), strict_err))
+}
+
+fn parse_legacy_version(version: &str) -> Option<semver::Version> {
+    if !version.is_empty() && version.bytes().all(|b| b.is_ascii_digit()) {
+        if version.len() >= TIMESTAMP_VERSION_SECONDS_LEN {
+            let (date, rest) = version.split_at(8);
+            let (time, fraction) = rest.split_at(6);
+            let fraction = if fraction.is_empty() {
+                0
+            } else {
+                fraction.parse().ok()?
+            };
+            return Some(semver::Version::new(
+                date.parse().ok()?,
+                time.parse().ok()?,
+                fraction,
+            ));
+        }
+        return None;
+    }
+
+    let parse_parts = |separator: char| -> Option<Vec<u64>> {
+        version
+            .split(separator)
+            .map(|part| {
+                if !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()) {
+                    part.parse().ok()
+                } else {
+                    None
+                }
+            })
+            .collect()
+    };
+
+    if let Some(parts) = parse_parts('.') {
+        return match parts.as_slice() {
+            [major, minor] => Some(semver::Version::new(*major, *minor, 0)),
+            [major, minor, patch] => Some(semver::Version::new(*major, *minor, *patch)),
+            _ => None,
+        };
+    }
+    match parse_parts('-')?.as_slice() {
+        [year, month, day] => Some(semver::Version::new(*year, *month, *day)),
+        _ => None,
+    }
+}
+
+/// Compares two package versions using semver precedence, so `1.2.0` is older than `1.10.0`.
+/// Both versions must be full semver versions.
+pub fn compare_versions(a: &str, b: &str) -> Result<Ordering, SpecError> {
+    let a = parse_version(a, PkgVersionMode::Strict)?;
+    let b = parse_version(b, PkgVersionMode::Strict)?;
+    Ok(a.cmp_precedence(&b))
+}
+
 impl PkgSpecBuilder {
     #[allow(unused_mut)]
     pub fn try_schema<I>(&mut self, item: I) -> Result<&mut Self, I::Error>
@@ -132,6 +226,8 @@ impl TryFrom<PkgSpecBuilder> for PkgSpec {
 #[remain::sorted]
 #[derive(Debug, Error)]
 pub enum SpecError {
+    #[error("package version {0} is not a valid semver version: {1}")]
+    InvalidVersion(String, semver::Error),
     #[error("Can't convert {0} to LeafInputLocation")]
     LeafInputLocationConversionError(String),
     #[error(transparent)]
@@ -155,3 +251,93 @@ impl From<String> for SpecError {
         Self::ValidationError(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec_with_version(version: &str) -> PkgSpec {
+        PkgSpec::builder()
+            .name("taylor")
+            .version(version)
+            .created_by("sally@systeminit.com")
+            .build()
+            .expect("build pkg spec")
+    }
+
+    #[test]
+    fn strict_version_must_be_semver() {
+        assert_eq!(
+            semver::Version::new(1, 2, 3),
+            spec_with_version("1.2.3")
+                .parse_version(PkgVersionMode::Strict)
+                .expect("1.2.3 is semver")
+        );
+
+        for version in ["0", "1.2", "v1.2.3", "latest"] {
+            let err = spec_with_version(version)
+                .parse_version(PkgVersionMode::Strict)
+                .expect_err("not semver");
+            assert!(matches!(err, SpecError::InvalidVersion(ref invalid, _) if invalid == version));
+        }
+    }
+
+    #[test]
+    fn lenient_version_pads_legacy_versions() {
+        assert_eq!(
+            semver::Version::new(1, 2, 0),
+            spec_with_version("1.2")
+                .parse_version(PkgVersionMode::Lenient)
+                .expect("1.2 is accepted when lenient")
+        );
+        assert_eq!(
+            semver::Version::new(2023, 5, 24),
+            spec_with_version("2023-05-24")
+                .parse_version(PkgVersionMode::Lenient)
+                .expect("dates are accepted when lenient")
+        );
+
+        for version in ["0", "42", "latest"] {
+            let err = spec_with_version(version)
+                .parse_version(PkgVersionMode::Lenient)
+                .expect_err("not a version even when lenient");
+            assert!(matches!(err, SpecError::InvalidVersion(ref invalid, _) if invalid == version));
+        }
+    }
+
+    #[test]
+    fn lenient_version_orders_timestamps() {
+        let earlier = spec_with_version("20240301221413027574")
+            .parse_version(PkgVersionMode::Lenient)
+            .expect("timestamps are accepted when lenient");
+        let later = spec_with_version("20240301221413127574")
+            .parse_version(PkgVersionMode::Lenient)
+            .expect("timestamps are accepted when lenient");
+
+        assert_eq!(semver::Version::new(20240301, 221413, 27574), earlier);
+        assert!(earlier < later);
+        assert!(spec_with_version("20240301221413027574")
+            .parse_version(PkgVersionMode::Strict)
+            .is_err());
+    }
+
+    #[test]
+    fn compare_versions_is_numeric() {
+        assert_eq!(
+            Ordering::Less,
+            compare_versions("1.2.0", "1.10.0").expect("compare versions")
+        );
+        assert_eq!(
+            Ordering::Greater,
+            compare_versions("2.0.0", "1.10.0").expect("compare versions")
+        );
+        assert_eq!(
+            Ordering::Equal,
+            compare_versions("1.2.3", "1.2.3").expect("compare versions")
+        );
+        assert!(matches!(
+            compare_versions("1.2.3", "latest"),
+            Err(SpecError::InvalidVersion(ref invalid, _)) if invalid == "latest"
+        ));
+    }
+}
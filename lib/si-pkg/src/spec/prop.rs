@@ -154,6 +154,9 @@ pub enum MergeSkip {
     OutputSocketMissing {
         socket_name: String,
     },
+    /// A default value set on the existing variant's prop that the regenerated variant does not
+    /// have, because the asset func does not set it.
+    PropDefaultValueDropped(String),
     PropKindMismatch {
         path: String,
         other_kind: PropSpecKind,
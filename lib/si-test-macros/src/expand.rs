@@ -25,6 +25,10 @@ pub(crate) fn expand_test(item: ItemFn, _args: Args, fn_setup: impl FnSetup) ->
         ReturnType::Type(_, typeness) => (true, quote! {-> #typeness}),
     };
     let test_attr = quote! {#[::core::prelude::v1::test]};
+    // A `#[should_panic]` test must return unit, so the result is unwrapped instead of returned.
+    let should_panic = attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("should_panic"));
 
     let thread_stack_size = RT_DEFAULT_THREAD_STACK_SIZE;
 
@@ -39,10 +43,7 @@ pub(crate) fn expand_test(item: ItemFn, _args: Args, fn_setup: impl FnSetup) ->
     let tracing_init = expand_tracing_init();
     let rt = expand_default_runtime();
 
-    quote! {
-        #test_attr
-        #(#attrs)*
-        fn #test_name() -> ::dal_test::Result<()> {
+    let test_body = quote! {
             use ::dal_test::WrapErr;
             use ::std::io::Write;
             use ::dal_test::telemetry::tracing;
@@ -85,6 +86,28 @@ pub(crate) fn expand_test(item: ItemFn, _args: Args, fn_setup: impl FnSetup) ->
                         secs
                     )?;
             Ok(())
+    };
+
+    if should_panic {
+        quote! {
+            #test_attr
+            #(#attrs)*
+            fn #test_name() {
+                fn run_test() -> ::dal_test::Result<()> {
+                    #test_body
+                }
+
+                #[allow(clippy::expect_used)]
+                run_test().expect("test returned an error");
+            }
+        }
+    } else {
+        quote! {
+            #test_attr
+            #(#attrs)*
+            fn #test_name() -> ::dal_test::Result<()> {
+                #test_body
+            }
         }
     }
 }